@@ -0,0 +1,11 @@
+-- This file should undo anything in `up.sql`
+
+ALTER TABLE tournaments ADD COLUMN joueurs json NOT NULL DEFAULT '[]';
+UPDATE tournaments
+SET joueurs = joueurs_snapshots.players
+FROM joueurs_snapshots
+WHERE joueurs_snapshots.id = tournaments.joueurs_snapshot_id;
+ALTER TABLE tournaments ALTER COLUMN joueurs DROP DEFAULT;
+ALTER TABLE tournaments DROP COLUMN joueurs_snapshot_id;
+
+DROP TABLE joueurs_snapshots;
@@ -0,0 +1,25 @@
+-- Your SQL goes here
+
+CREATE TABLE joueurs_snapshots
+(
+    id                   SERIAL PRIMARY KEY,
+    source               VARCHAR   NOT NULL,
+    fetched_at           TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    players              json      NOT NULL,
+    legacy_tournament_id INTEGER
+);
+
+-- Legacy snapshots are dated at the epoch so that any fetched list is newer than them.
+INSERT INTO joueurs_snapshots (source, fetched_at, players, legacy_tournament_id)
+SELECT 'legacy', TIMESTAMP 'epoch', joueurs, id
+FROM tournaments;
+
+ALTER TABLE tournaments ADD COLUMN joueurs_snapshot_id INTEGER REFERENCES joueurs_snapshots (id) ON DELETE NO ACTION;
+UPDATE tournaments
+SET joueurs_snapshot_id = joueurs_snapshots.id
+FROM joueurs_snapshots
+WHERE joueurs_snapshots.legacy_tournament_id = tournaments.id;
+ALTER TABLE tournaments ALTER COLUMN joueurs_snapshot_id SET NOT NULL;
+ALTER TABLE tournaments DROP COLUMN joueurs;
+
+ALTER TABLE joueurs_snapshots DROP COLUMN legacy_tournament_id;
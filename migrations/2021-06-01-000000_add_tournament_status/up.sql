@@ -0,0 +1,6 @@
+-- Your SQL goes here
+
+ALTER TABLE tournaments ADD COLUMN status VARCHAR NOT NULL DEFAULT 'draft';
+UPDATE tournaments
+SET status = 'in_progress'
+WHERE EXISTS(SELECT 1 FROM rounds WHERE rounds.tournament_id = tournaments.id);
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+
+CREATE TABLE teams
+(
+    id            SERIAL PRIMARY KEY,
+    tournament_id INT     NOT NULL REFERENCES tournaments (id) ON DELETE NO ACTION,
+    name          VARCHAR NOT NULL,
+    meta_data     json    NOT NULL,
+    UNIQUE (tournament_id, name)
+);
@@ -39,7 +39,11 @@ impl Account {
     pub fn login_from_jwt(jwt: &String, connection: &PgConnection) -> Result<Account, ErrorType> {
         let username = JWTMediator::get_username_from_jwt(jwt)?;
 
-        let user = UserRowModel::get(&username, connection)?;
+        let user = match UserRowModel::get(&username, connection) {
+            Ok(user) => user,
+            Err(ErrorType::NotFound(_, _)) => return Err(ErrorType::AuthenticationFailed),
+            Err(error) => return Err(error),
+        };
         Account::get_account_from_user(user)
     }
 
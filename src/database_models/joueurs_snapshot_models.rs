@@ -0,0 +1,179 @@
+use chrono::{NaiveDateTime, Utc};
+use diesel::prelude::*;
+use diesel::result::Error;
+use serde_json::Value;
+
+use crate::errors::ErrorType;
+use crate::schema::joueurs_snapshots;
+use crate::tournament_manager::Player;
+
+#[derive(PartialEq, Debug, Queryable, Identifiable)]
+#[table_name = "joueurs_snapshots"]
+pub struct JoueursSnapshotRowModel {
+    pub id: i32,
+    pub source: String,
+    pub fetched_at: NaiveDateTime,
+    pub players: Value,
+}
+
+#[derive(Insertable)]
+#[table_name = "joueurs_snapshots"]
+struct NewJoueursSnapshotRowModel<'a> {
+    pub source: &'a String,
+    pub fetched_at: &'a NaiveDateTime,
+    pub players: &'a Value,
+}
+
+impl JoueursSnapshotRowModel {
+    pub fn create(
+        source: &String,
+        players: Vec<Player>,
+        connection: &PgConnection,
+    ) -> Result<JoueursSnapshotRowModel, ErrorType> {
+        let players_to_store = Value::Array(
+            players
+                .iter()
+                .map(|player| Value::Object(player.to_serdemap()))
+                .collect(),
+        );
+
+        let new_snapshot = NewJoueursSnapshotRowModel {
+            source,
+            fetched_at: &Utc::now().naive_utc(),
+            players: &players_to_store,
+        };
+        let result = diesel::insert_into(joueurs_snapshots::table)
+            .values(new_snapshot)
+            .get_result::<JoueursSnapshotRowModel>(connection);
+        match result {
+            Ok(snapshot) => {
+                info!(
+                    "Joueurs snapshot {} from {} is created.",
+                    &snapshot.id, &snapshot.source
+                );
+                Ok(snapshot)
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(ErrorType::DatabaseError)
+            }
+        }
+    }
+
+    pub fn get(id: &i32, connection: &PgConnection) -> Result<JoueursSnapshotRowModel, ErrorType> {
+        let result = joueurs_snapshots::table.find(id).first(connection);
+
+        match result {
+            Ok(snapshot) => Ok(snapshot),
+            Err(Error::NotFound) => {
+                warn!("Joueurs snapshot {} is not found", id);
+                Err(ErrorType::NotFound(
+                    String::from("Joueurs snapshot"),
+                    id.to_string(),
+                ))
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(ErrorType::DatabaseError)
+            }
+        }
+    }
+
+    pub fn get_latest(
+        connection: &PgConnection,
+    ) -> Result<Option<JoueursSnapshotRowModel>, ErrorType> {
+        let result = joueurs_snapshots::table
+            .order((
+                joueurs_snapshots::fetched_at.desc(),
+                joueurs_snapshots::id.desc(),
+            ))
+            .first(connection)
+            .optional();
+
+        match result {
+            Ok(snapshot) => Ok(snapshot),
+            Err(e) => {
+                error!("{}", e);
+                Err(ErrorType::DatabaseError)
+            }
+        }
+    }
+
+    pub fn get_all_summaries(
+        connection: &PgConnection,
+    ) -> Result<Vec<(i32, String, NaiveDateTime)>, ErrorType> {
+        let result = joueurs_snapshots::table
+            .select((
+                joueurs_snapshots::id,
+                joueurs_snapshots::source,
+                joueurs_snapshots::fetched_at,
+            ))
+            .order(joueurs_snapshots::fetched_at.desc())
+            .load::<(i32, String, NaiveDateTime)>(connection);
+
+        match result {
+            Ok(summaries) => Ok(summaries),
+            Err(e) => {
+                error!("{}", e);
+                Err(ErrorType::DatabaseError)
+            }
+        }
+    }
+
+    pub fn get_players(&self) -> Vec<Player> {
+        match self.players.as_array() {
+            Some(players) => players
+                .iter()
+                .filter_map(|player_json| player_json.as_object())
+                .filter_map(|player_data| Player::from_serdemap(player_data.clone()).ok())
+                .collect(),
+            None => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod crud {
+        use crate::database_models::JoueursSnapshotRowModel;
+        use crate::tournament_manager::Player;
+        use crate::utils;
+
+        #[test]
+        fn test_create_snapshot() {
+            let test_connection = utils::get_test_connection();
+            let joueurs_id = utils::generate_random_string(10);
+            let player = Player {
+                joueurs_id: joueurs_id.clone(),
+                first_name: utils::generate_random_string(5),
+                last_name: utils::generate_random_string(5),
+                country: utils::generate_random_string(3),
+                rating: 1200,
+            };
+
+            let snapshot = JoueursSnapshotRowModel::create(
+                &String::from("upload"),
+                vec![player],
+                &test_connection,
+            )
+            .unwrap();
+            let snapshot_from_get =
+                JoueursSnapshotRowModel::get(&snapshot.id, &test_connection).unwrap();
+            assert_eq!(snapshot_from_get.source, String::from("upload"));
+
+            let players = snapshot_from_get.get_players();
+            assert_eq!(players.len(), 1);
+            assert_eq!(players[0].joueurs_id, joueurs_id);
+        }
+
+        #[test]
+        fn test_get_latest() {
+            let test_connection = utils::get_test_connection();
+            let _ =
+                JoueursSnapshotRowModel::create(&String::from("upload"), vec![], &test_connection)
+                    .unwrap();
+            let latest_snapshot = JoueursSnapshotRowModel::get_latest(&test_connection).unwrap();
+            assert_eq!(latest_snapshot.is_some(), true);
+        }
+    }
+}
@@ -124,6 +124,23 @@ impl MatchRowModel {
             }
         }
     }
+
+    pub fn get_transcript(&self) -> Option<String> {
+        self.meta_data
+            .get("transcript")
+            .and_then(|transcript| transcript.as_str())
+            .map(|transcript| String::from(transcript))
+    }
+
+    pub fn set_transcript(&mut self, transcript: &String) {
+        if !self.meta_data.is_object() {
+            self.meta_data = Value::Object(Map::new());
+        }
+        self.meta_data
+            .as_object_mut()
+            .unwrap()
+            .insert(String::from("transcript"), Value::from(transcript.clone()));
+    }
 }
 
 impl MatchDAO for MatchRowModel {
@@ -193,6 +210,10 @@ impl MatchDAO for MatchRowModel {
 
         match result {
             Ok(game_match) => Ok(game_match),
+            Err(Error::NotFound) => {
+                warn!("Match {} is not found", id);
+                Err(ErrorType::NotFound(String::from("Match"), id.to_string()))
+            }
             Err(e) => {
                 error!("{}", e);
                 Err(ErrorType::DatabaseError)
@@ -282,6 +303,7 @@ mod tests {
         use serde_json::{Map, Value};
 
         use crate::database_models::{MatchDAO, MatchRowModel};
+        use crate::errors::ErrorType;
         use crate::game_match::GameMatchTransformer;
         use crate::utils;
         use crate::utils::{
@@ -480,5 +502,18 @@ mod tests {
             let matches = MatchRowModel::get_all_from_round(&round.id, &test_connection).unwrap();
             assert_eq!(matches, vec![]);
         }
+
+        #[test]
+        fn test_get_missing_match() {
+            let test_connection = utils::get_test_connection();
+            let result = MatchRowModel::get(&-1, &test_connection);
+            assert_eq!(
+                result,
+                Err(ErrorType::NotFound(
+                    String::from("Match"),
+                    String::from("-1")
+                ))
+            );
+        }
     }
 }
@@ -1,12 +1,16 @@
+pub use joueurs_snapshot_models::JoueursSnapshotRowModel;
 pub use match_models::{MatchDAO, MatchRowModel};
 pub use player_models::PlayerRowModel;
 pub use round_models::{RoundDAO, RoundRowModel};
+pub use team_models::TeamRowModel;
 pub use tournament_models::TournamentRowModel;
 pub use user_models::UserRowModel;
 
+mod joueurs_snapshot_models;
 mod match_models;
 mod player_models;
 mod round_models;
+mod team_models;
 mod tournament_admin_models;
 mod tournament_models;
 mod user_models;
@@ -1,10 +1,11 @@
 use diesel::dsl::{exists, select};
 use diesel::prelude::*;
+use diesel::result::Error;
 use serde_json::{Map, Value};
 
 use crate::errors::ErrorType;
 use crate::schema::players;
-use crate::tournament_manager::Player;
+use crate::tournament_manager::{Player, RequestedBye};
 
 use super::TournamentRowModel;
 
@@ -83,6 +84,10 @@ impl PlayerRowModel {
 
         match result {
             Ok(player) => Ok(player),
+            Err(Error::NotFound) => {
+                warn!("Player {} is not found", id);
+                Err(ErrorType::NotFound(String::from("Player"), id.to_string()))
+            }
             Err(e) => {
                 error!("{}", e);
                 Err(ErrorType::DatabaseError)
@@ -109,6 +114,24 @@ impl PlayerRowModel {
         }
     }
 
+    pub fn update(&self, connection: &PgConnection) -> Result<PlayerRowModel, ErrorType> {
+        let result = diesel::update(self).set(self).get_result(connection);
+
+        match result {
+            Ok(player) => {
+                info!(
+                    "Player id {} with joueurs_id {} is updated in tournament {}",
+                    &self.id, &self.joueurs_id, &self.tournament_id
+                );
+                Ok(player)
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(ErrorType::DatabaseError)
+            }
+        }
+    }
+
     pub fn delete(&self, connection: &PgConnection) -> Result<(), ErrorType> {
         let result = diesel::delete(self).execute(connection);
         match result {
@@ -126,6 +149,80 @@ impl PlayerRowModel {
         }
     }
 
+    pub fn get_team(&self) -> Option<String> {
+        self.meta_data
+            .get("team")
+            .and_then(|team| team.as_str())
+            .map(|team| String::from(team))
+    }
+
+    pub fn set_team(&mut self, team: &Option<String>) {
+        self.set_meta_data_value("team", Value::from(team.clone()));
+    }
+
+    pub fn get_section(&self) -> Option<String> {
+        self.meta_data
+            .get("section")
+            .and_then(|section| section.as_str())
+            .map(|section| String::from(section))
+    }
+
+    pub fn set_section(&mut self, section: &Option<String>) {
+        self.set_meta_data_value("section", Value::from(section.clone()));
+    }
+
+    pub fn is_withdrawn(&self) -> bool {
+        self.meta_data
+            .get("withdrawn")
+            .and_then(|withdrawn| withdrawn.as_bool())
+            .unwrap_or(false)
+    }
+
+    pub fn set_withdrawn(&mut self, withdrawn: &bool) {
+        self.set_meta_data_value("withdrawn", Value::from(withdrawn.clone()));
+    }
+
+    pub fn get_requested_byes(&self) -> Vec<RequestedBye> {
+        let requested_byes = self
+            .meta_data
+            .get("requested_byes")
+            .and_then(|requested_byes| requested_byes.as_array());
+        match requested_byes {
+            Some(requested_byes) => requested_byes
+                .iter()
+                .filter_map(|requested_bye| requested_bye.as_object())
+                .filter_map(|requested_bye| RequestedBye::from_serdemap(requested_bye))
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    pub fn get_requested_bye(&self, round_number: &i32) -> Option<RequestedBye> {
+        self.get_requested_byes()
+            .into_iter()
+            .find(|requested_bye| &requested_bye.round_number == round_number)
+    }
+
+    pub fn set_requested_byes(&mut self, requested_byes: &Vec<RequestedBye>) {
+        let requested_byes: Vec<Value> = requested_byes
+            .iter()
+            .map(|requested_bye| Value::from(requested_bye.to_serdemap()))
+            .collect();
+        self.set_meta_data_value("requested_byes", Value::from(requested_byes));
+    }
+
+    // A null value removes the key instead of storing it.
+    fn set_meta_data_value(&mut self, key: &str, value: Value) {
+        if !self.meta_data.is_object() {
+            self.meta_data = Value::Object(Map::new());
+        }
+        let meta_data = self.meta_data.as_object_mut().unwrap();
+        match value {
+            Value::Null => meta_data.remove(key),
+            value => meta_data.insert(String::from(key), value),
+        };
+    }
+
     pub fn to_player(&self) -> Player {
         Player {
             joueurs_id: self.joueurs_id.clone(),
@@ -190,6 +287,7 @@ mod tests {
         use serde_json::Map;
 
         use crate::database_models::PlayerRowModel;
+        use crate::errors::ErrorType;
         use crate::tournament_manager::Player;
         use crate::utils;
         use crate::utils::{create_mock_tournament_with_creator, create_mock_user};
@@ -396,5 +494,18 @@ mod tests {
             let get_result = PlayerRowModel::get(&player.id, &test_connection);
             assert_eq!(get_result.is_err(), true);
         }
+
+        #[test]
+        fn test_get_missing_player() {
+            let test_connection = utils::get_test_connection();
+            let result = PlayerRowModel::get(&-1, &test_connection);
+            assert_eq!(
+                result,
+                Err(ErrorType::NotFound(
+                    String::from("Player"),
+                    String::from("-1")
+                ))
+            );
+        }
     }
 }
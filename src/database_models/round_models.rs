@@ -49,6 +49,13 @@ where
 }
 
 impl RoundRowModel {
+    pub fn get_section(&self) -> Option<String> {
+        self.meta_data
+            .get("section")
+            .and_then(|section| section.as_str())
+            .map(|section| String::from(section))
+    }
+
     fn insert_to_database(
         new_round: NewRoundRowModel,
         connection: &PgConnection,
@@ -100,6 +107,10 @@ impl RoundDAO for RoundRowModel {
 
         match result {
             Ok(round) => Ok(round),
+            Err(Error::NotFound) => {
+                warn!("Round {} is not found", id);
+                Err(ErrorType::NotFound(String::from("Round"), id.to_string()))
+            }
             Err(e) => {
                 error!("{}", e);
                 Err(ErrorType::DatabaseError)
@@ -164,6 +175,7 @@ mod tests {
         use serde_json::Map;
 
         use crate::database_models::{RoundDAO, RoundRowModel};
+        use crate::errors::ErrorType;
         use crate::properties::RoundType;
         use crate::utils;
         use crate::utils::{
@@ -246,5 +258,18 @@ mod tests {
                 RoundRowModel::get_all_from_tournament(&tournament.id, &test_connection).unwrap();
             assert_eq!(tournament_rounds, vec![]);
         }
+
+        #[test]
+        fn test_get_missing_round() {
+            let test_connection = utils::get_test_connection();
+            let result = RoundRowModel::get(&-1, &test_connection);
+            assert_eq!(
+                result,
+                Err(ErrorType::NotFound(
+                    String::from("Round"),
+                    String::from("-1")
+                ))
+            );
+        }
     }
 }
@@ -0,0 +1,278 @@
+use diesel::dsl::{exists, select};
+use diesel::prelude::*;
+use diesel::result::Error;
+use serde_json::{Map, Value};
+
+use crate::errors::ErrorType;
+use crate::schema::teams;
+
+use super::TournamentRowModel;
+
+#[derive(AsChangeset, PartialEq, Debug, Queryable, Associations, Identifiable)]
+#[belongs_to(TournamentRowModel, foreign_key = "tournament_id")]
+#[table_name = "teams"]
+pub struct TeamRowModel {
+    pub id: i32,
+    pub tournament_id: i32,
+    pub name: String,
+    pub meta_data: Value,
+}
+
+#[derive(Insertable)]
+#[table_name = "teams"]
+struct NewTeamRowModel<'a> {
+    pub tournament_id: &'a i32,
+    pub name: &'a String,
+    pub meta_data: &'a Value,
+}
+
+impl TeamRowModel {
+    pub fn get_all_from_tournament(
+        tournament_id: &i32,
+        connection: &PgConnection,
+    ) -> Result<Vec<TeamRowModel>, ErrorType> {
+        let result = teams::table
+            .filter(teams::tournament_id.eq(tournament_id))
+            .order(teams::id)
+            .load::<TeamRowModel>(connection);
+        match result {
+            Ok(teams) => Ok(teams),
+            Err(e) => {
+                error!("{}", e);
+                Err(ErrorType::DatabaseError)
+            }
+        }
+    }
+
+    pub fn create(
+        tournament_id: &i32,
+        name: &String,
+        board_player_ids: &Vec<i32>,
+        connection: &PgConnection,
+    ) -> Result<TeamRowModel, ErrorType> {
+        if TeamRowModel::is_team_exists(name, tournament_id, connection)? {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Team exists in the tournament.",
+            )));
+        }
+
+        let mut meta_data = Map::new();
+        meta_data.insert(
+            String::from("board_player_ids"),
+            Value::from(board_player_ids.clone()),
+        );
+        let meta_data_json = Value::from(meta_data);
+        let new_team = NewTeamRowModel {
+            tournament_id,
+            name,
+            meta_data: &meta_data_json,
+        };
+
+        TeamRowModel::insert_to_database(new_team, connection)
+    }
+
+    pub fn get(id: &i32, connection: &PgConnection) -> Result<TeamRowModel, ErrorType> {
+        let result = teams::table.find(id).first(connection);
+
+        match result {
+            Ok(team) => Ok(team),
+            Err(Error::NotFound) => {
+                warn!("Team {} is not found", id);
+                Err(ErrorType::NotFound(String::from("Team"), id.to_string()))
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(ErrorType::DatabaseError)
+            }
+        }
+    }
+
+    pub fn update(&self, connection: &PgConnection) -> Result<TeamRowModel, ErrorType> {
+        let result = diesel::update(self).set(self).get_result(connection);
+
+        match result {
+            Ok(team) => {
+                info!(
+                    "Team id {} ({}) is updated in tournament {}",
+                    &self.id, &self.name, &self.tournament_id
+                );
+                Ok(team)
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(ErrorType::DatabaseError)
+            }
+        }
+    }
+
+    pub fn delete(&self, connection: &PgConnection) -> Result<(), ErrorType> {
+        let result = diesel::delete(self).execute(connection);
+        match result {
+            Ok(_) => {
+                info!(
+                    "Team id {} ({}) is deleted from tournament {}",
+                    &self.id, &self.name, &self.tournament_id
+                );
+                Ok(())
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(ErrorType::DatabaseError)
+            }
+        }
+    }
+
+    // Board order follows the list, the first player plays on board 1.
+    pub fn get_board_player_ids(&self) -> Vec<i32> {
+        let board_player_ids = self
+            .meta_data
+            .get("board_player_ids")
+            .and_then(|board_player_ids| board_player_ids.as_array());
+        match board_player_ids {
+            Some(board_player_ids) => board_player_ids
+                .iter()
+                .filter_map(|player_id| player_id.as_i64())
+                .map(|player_id| player_id as i32)
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    pub fn set_board_player_ids(&mut self, board_player_ids: &Vec<i32>) {
+        if !self.meta_data.is_object() {
+            self.meta_data = Value::Object(Map::new());
+        }
+        self.meta_data.as_object_mut().unwrap().insert(
+            String::from("board_player_ids"),
+            Value::from(board_player_ids.clone()),
+        );
+    }
+
+    fn is_team_exists(
+        name: &String,
+        tournament_id: &i32,
+        connection: &PgConnection,
+    ) -> Result<bool, ErrorType> {
+        let result = select(exists(
+            teams::table
+                .filter(teams::tournament_id.eq(tournament_id))
+                .filter(teams::name.eq(name)),
+        ))
+        .get_result(connection);
+
+        match result {
+            Ok(is_exists) => Ok(is_exists),
+            Err(e) => {
+                error!("{}", e);
+                Err(ErrorType::DatabaseError)
+            }
+        }
+    }
+
+    fn insert_to_database(
+        new_team: NewTeamRowModel,
+        connection: &PgConnection,
+    ) -> Result<TeamRowModel, ErrorType> {
+        let tournament_id = new_team.tournament_id.clone();
+        let team_name = new_team.name.clone();
+
+        let result = diesel::insert_into(teams::table)
+            .values(new_team)
+            .get_result(connection);
+        match result {
+            Ok(team) => {
+                info!(
+                    "Team {} is added to tournament {}",
+                    team_name, tournament_id
+                );
+                Ok(team)
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(ErrorType::DatabaseError)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod crud {
+        use crate::database_models::TeamRowModel;
+        use crate::utils;
+        use crate::utils::{
+            create_mock_player_from_tournament, create_mock_tournament_with_creator,
+            create_mock_user,
+        };
+
+        #[test]
+        fn test_create_team() {
+            let test_connection = utils::get_test_connection();
+            let user = create_mock_user(&test_connection);
+            let tournament = create_mock_tournament_with_creator(&user.username, &test_connection);
+            let player_1 = create_mock_player_from_tournament(&tournament.id, &test_connection);
+            let player_2 = create_mock_player_from_tournament(&tournament.id, &test_connection);
+
+            let name = utils::generate_random_string(10);
+            let board_player_ids = vec![player_2.id, player_1.id];
+            let team =
+                TeamRowModel::create(&tournament.id, &name, &board_player_ids, &test_connection)
+                    .unwrap();
+
+            assert_eq!(team.name, name);
+            assert_eq!(team.get_board_player_ids(), board_player_ids);
+        }
+
+        #[test]
+        fn test_create_existed_team() {
+            let test_connection = utils::get_test_connection();
+            let user = create_mock_user(&test_connection);
+            let tournament = create_mock_tournament_with_creator(&user.username, &test_connection);
+
+            let name = utils::generate_random_string(10);
+            TeamRowModel::create(&tournament.id, &name, &vec![], &test_connection).unwrap();
+
+            let result = TeamRowModel::create(&tournament.id, &name, &vec![], &test_connection);
+            assert_eq!(result.is_err(), true);
+        }
+
+        #[test]
+        fn test_update_board_order() {
+            let test_connection = utils::get_test_connection();
+            let user = create_mock_user(&test_connection);
+            let tournament = create_mock_tournament_with_creator(&user.username, &test_connection);
+            let player_1 = create_mock_player_from_tournament(&tournament.id, &test_connection);
+            let player_2 = create_mock_player_from_tournament(&tournament.id, &test_connection);
+
+            let name = utils::generate_random_string(10);
+            let mut team = TeamRowModel::create(
+                &tournament.id,
+                &name,
+                &vec![player_1.id, player_2.id],
+                &test_connection,
+            )
+            .unwrap();
+            team.set_board_player_ids(&vec![player_2.id, player_1.id]);
+            let updated_team = team.update(&test_connection).unwrap();
+            assert_eq!(
+                updated_team.get_board_player_ids(),
+                vec![player_2.id, player_1.id]
+            );
+        }
+
+        #[test]
+        fn test_delete() {
+            let test_connection = utils::get_test_connection();
+            let user = create_mock_user(&test_connection);
+            let tournament = create_mock_tournament_with_creator(&user.username, &test_connection);
+
+            let name = utils::generate_random_string(10);
+            let team =
+                TeamRowModel::create(&tournament.id, &name, &vec![], &test_connection).unwrap();
+            team.delete(&test_connection).unwrap();
+
+            let result = TeamRowModel::get(&team.id, &test_connection);
+            assert_eq!(result.is_err(), true);
+        }
+    }
+}
@@ -1,16 +1,20 @@
 use chrono::NaiveDate;
 use diesel::prelude::*;
+use diesel::result::Error;
 use serde_json::{Map, Value};
 
 use crate::errors::ErrorType;
-use crate::properties::TournamentType;
+use crate::properties::{
+    PairingConstraint, TeamTieBreaker, TieBreaker, TournamentStatus, TournamentType,
+};
 use crate::schema::tournaments;
-use crate::tournament_manager::Player;
+use crate::tournament_manager::{get_default_brightwell_constant, Player};
 
-use super::UserRowModel;
+use super::{JoueursSnapshotRowModel, UserRowModel};
 
 #[derive(AsChangeset, PartialEq, Debug, Queryable, Associations, Identifiable)]
 #[belongs_to(UserRowModel, foreign_key = "creator")]
+#[belongs_to(JoueursSnapshotRowModel, foreign_key = "joueurs_snapshot_id")]
 #[table_name = "tournaments"]
 pub struct TournamentRowModel {
     pub id: i32,
@@ -18,10 +22,11 @@ pub struct TournamentRowModel {
     pub tournament_type: String,
     pub country: String,
     pub creator: String,
-    pub joueurs: Value,
     pub meta_data: Value,
     pub start_date: NaiveDate,
     pub end_date: NaiveDate,
+    pub joueurs_snapshot_id: i32,
+    pub status: String,
 }
 
 #[derive(Insertable)]
@@ -31,10 +36,10 @@ struct NewTournamentRowModel<'a> {
     pub tournament_type: &'a String,
     pub country: &'a String,
     pub creator: &'a String,
-    pub joueurs: &'a Value,
     pub meta_data: &'a Value,
     pub start_date: &'a NaiveDate,
     pub end_date: &'a NaiveDate,
+    pub joueurs_snapshot_id: &'a i32,
 }
 
 impl TournamentRowModel {
@@ -44,27 +49,20 @@ impl TournamentRowModel {
         start_date: &NaiveDate,
         end_date: &NaiveDate,
         creator_username: &String,
-        joueurs: Vec<Player>,
+        joueurs_snapshot_id: &i32,
         tournament_type: TournamentType,
         meta_data: Map<String, Value>,
         connection: &PgConnection,
     ) -> Result<TournamentRowModel, ErrorType> {
-        let joueurs_to_store = Value::Array(
-            joueurs
-                .iter()
-                .map(|x| Value::Object(x.to_serdemap()))
-                .collect(),
-        );
-
         let new_tournament = NewTournamentRowModel {
             name,
             tournament_type: &tournament_type.to_string(),
             country,
             creator: creator_username,
-            joueurs: &joueurs_to_store,
             meta_data: &Value::Object(meta_data),
             start_date,
             end_date,
+            joueurs_snapshot_id,
         };
         TournamentRowModel::insert_to_database(new_tournament, connection)
     }
@@ -94,6 +92,13 @@ impl TournamentRowModel {
 
         match result {
             Ok(tournament) => Ok(tournament),
+            Err(Error::NotFound) => {
+                warn!("Tournament {} is not found", id);
+                Err(ErrorType::NotFound(
+                    String::from("Tournament"),
+                    id.to_string(),
+                ))
+            }
             Err(e) => {
                 error!("{}", e);
                 Err(ErrorType::DatabaseError)
@@ -162,41 +167,283 @@ impl TournamentRowModel {
         }
     }
 
-    pub fn get_player_with_joueurs_id(&self, joueurs_id: &String) -> Result<Player, ErrorType> {
-        let players = self.get_players_from_joueurs()?;
-        let player = match players
-            .iter()
-            .find(|&player| &player.joueurs_id == joueurs_id)
+    pub fn get_status(&self) -> TournamentStatus {
+        TournamentStatus::from_string(self.status.clone())
+    }
+
+    pub fn set_status(&mut self, status: &TournamentStatus) {
+        self.status = status.to_string();
+    }
+
+    pub fn get_brightwell_constant(&self) -> f64 {
+        match self
+            .meta_data
+            .get("brightwell_constant")
+            .and_then(|brightwell_constant| brightwell_constant.as_f64())
+        {
+            Some(brightwell_constant) => brightwell_constant,
+            None => get_default_brightwell_constant(),
+        }
+    }
+
+    pub fn set_brightwell_constant(&mut self, brightwell_constant: &f64) {
+        self.set_meta_data_value(
+            "brightwell_constant",
+            Value::from(brightwell_constant.clone()),
+        );
+    }
+
+    pub fn get_tie_breakers(&self) -> Vec<TieBreaker> {
+        let tie_breakers = self
+            .meta_data
+            .get("tie_breakers")
+            .and_then(|tie_breakers| tie_breakers.as_array());
+        match tie_breakers {
+            Some(tie_breakers) => tie_breakers
+                .iter()
+                .filter_map(|tie_breaker| tie_breaker.as_str())
+                .map(|tie_breaker| TieBreaker::from_string(String::from(tie_breaker)))
+                .filter(|tie_breaker| tie_breaker != &TieBreaker::Unidentified)
+                .collect(),
+            None => vec![TieBreaker::Brightwell],
+        }
+    }
+
+    pub fn set_tie_breakers(&mut self, tie_breakers: &Vec<TieBreaker>) {
+        let tie_breakers_to_store = Value::Array(
+            tie_breakers
+                .iter()
+                .map(|tie_breaker| Value::from(tie_breaker.to_string()))
+                .collect(),
+        );
+        self.set_meta_data_value("tie_breakers", tie_breakers_to_store);
+    }
+
+    pub fn get_pairing_constraints(&self) -> Vec<PairingConstraint> {
+        let pairing_constraints = self
+            .meta_data
+            .get("pairing_constraints")
+            .and_then(|pairing_constraints| pairing_constraints.as_array());
+        match pairing_constraints {
+            Some(pairing_constraints) => pairing_constraints
+                .iter()
+                .filter_map(|pairing_constraint| pairing_constraint.as_str())
+                .map(|pairing_constraint| {
+                    PairingConstraint::from_string(String::from(pairing_constraint))
+                })
+                .filter(|pairing_constraint| pairing_constraint != &PairingConstraint::Unidentified)
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    pub fn set_pairing_constraints(&mut self, pairing_constraints: &Vec<PairingConstraint>) {
+        let pairing_constraints_to_store = Value::Array(
+            pairing_constraints
+                .iter()
+                .map(|pairing_constraint| Value::from(pairing_constraint.to_string()))
+                .collect(),
+        );
+        self.set_meta_data_value("pairing_constraints", pairing_constraints_to_store);
+    }
+
+    pub fn get_pairing_constraint_rounds(&self) -> i32 {
+        self.meta_data
+            .get("pairing_constraint_rounds")
+            .and_then(|pairing_constraint_rounds| pairing_constraint_rounds.as_i64())
+            .unwrap_or(0) as i32
+    }
+
+    pub fn set_pairing_constraint_rounds(&mut self, pairing_constraint_rounds: &i32) {
+        self.set_meta_data_value(
+            "pairing_constraint_rounds",
+            Value::from(pairing_constraint_rounds.clone()),
+        );
+    }
+
+    // Pairing constraints only apply to the first few rounds, round numbers start from 1.
+    pub fn get_active_pairing_constraints(&self, round_number: &i32) -> Vec<PairingConstraint> {
+        if round_number > &self.get_pairing_constraint_rounds() {
+            return vec![];
+        }
+        self.get_pairing_constraints()
+    }
+
+    pub fn get_accelerated_rounds(&self) -> i32 {
+        self.meta_data
+            .get("accelerated_rounds")
+            .and_then(|accelerated_rounds| accelerated_rounds.as_i64())
+            .unwrap_or(0) as i32
+    }
+
+    pub fn set_accelerated_rounds(&mut self, accelerated_rounds: &i32) {
+        self.set_meta_data_value(
+            "accelerated_rounds",
+            Value::from(accelerated_rounds.clone()),
+        );
+    }
+
+    // Accelerated pairing only applies to the first few rounds, round numbers start from 1.
+    pub fn is_accelerated_round(&self, round_number: &i32) -> bool {
+        round_number <= &self.get_accelerated_rounds()
+    }
+
+    pub fn get_round_robin_cycles(&self) -> i32 {
+        self.meta_data
+            .get("round_robin_cycles")
+            .and_then(|round_robin_cycles| round_robin_cycles.as_i64())
+            .unwrap_or(1) as i32
+    }
+
+    pub fn set_round_robin_cycles(&mut self, round_robin_cycles: &i32) {
+        self.set_meta_data_value(
+            "round_robin_cycles",
+            Value::from(round_robin_cycles.clone()),
+        );
+    }
+
+    pub fn get_knockout_seeds(&self) -> Vec<i32> {
+        let knockout_seeds = self
+            .meta_data
+            .get("knockout_seeds")
+            .and_then(|knockout_seeds| knockout_seeds.as_array());
+        match knockout_seeds {
+            Some(knockout_seeds) => knockout_seeds
+                .iter()
+                .filter_map(|player_id| player_id.as_i64())
+                .map(|player_id| player_id as i32)
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    pub fn set_knockout_seeds(&mut self, knockout_seeds: &Vec<i32>) {
+        self.set_meta_data_value("knockout_seeds", Value::from(knockout_seeds.clone()));
+    }
+
+    pub fn get_knockout_best_of(&self) -> i32 {
+        self.meta_data
+            .get("knockout_best_of")
+            .and_then(|knockout_best_of| knockout_best_of.as_i64())
+            .unwrap_or(1) as i32
+    }
+
+    pub fn set_knockout_best_of(&mut self, knockout_best_of: &i32) {
+        self.set_meta_data_value("knockout_best_of", Value::from(knockout_best_of.clone()));
+    }
+
+    pub fn has_knockout_third_place_match(&self) -> bool {
+        self.meta_data
+            .get("knockout_third_place_match")
+            .and_then(|knockout_third_place_match| knockout_third_place_match.as_bool())
+            .unwrap_or(false)
+    }
+
+    pub fn set_knockout_third_place_match(&mut self, knockout_third_place_match: &bool) {
+        self.set_meta_data_value(
+            "knockout_third_place_match",
+            Value::from(knockout_third_place_match.clone()),
+        );
+    }
+
+    pub fn get_sections(&self) -> Vec<String> {
+        let sections = self
+            .meta_data
+            .get("sections")
+            .and_then(|sections| sections.as_array());
+        match sections {
+            Some(sections) => sections
+                .iter()
+                .filter_map(|section| section.as_str())
+                .map(|section| String::from(section))
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    pub fn set_sections(&mut self, sections: &Vec<String>) {
+        self.set_meta_data_value("sections", Value::from(sections.clone()));
+    }
+
+    pub fn has_sections(&self) -> bool {
+        !self.get_sections().is_empty()
+    }
+
+    pub fn get_team_tie_breakers(&self) -> Vec<TeamTieBreaker> {
+        let team_tie_breakers = self
+            .meta_data
+            .get("team_tie_breakers")
+            .and_then(|team_tie_breakers| team_tie_breakers.as_array());
+        match team_tie_breakers {
+            Some(team_tie_breakers) => team_tie_breakers
+                .iter()
+                .filter_map(|tie_breaker| tie_breaker.as_str())
+                .map(|tie_breaker| TeamTieBreaker::from_string(String::from(tie_breaker)))
+                .filter(|tie_breaker| tie_breaker != &TeamTieBreaker::Unidentified)
+                .collect(),
+            None => vec![TeamTieBreaker::GamePoints, TeamTieBreaker::Buchholz],
+        }
+    }
+
+    pub fn set_team_tie_breakers(&mut self, team_tie_breakers: &Vec<TeamTieBreaker>) {
+        let team_tie_breakers_to_store = Value::Array(
+            team_tie_breakers
+                .iter()
+                .map(|tie_breaker| Value::from(tie_breaker.to_string()))
+                .collect(),
+        );
+        self.set_meta_data_value("team_tie_breakers", team_tie_breakers_to_store);
+    }
+
+    // Teams are paired by one of the individual pairing systems.
+    pub fn get_team_pairing_type(&self) -> TournamentType {
+        self.meta_data
+            .get("team_pairing_type")
+            .and_then(|team_pairing_type| team_pairing_type.as_str())
+            .map(|team_pairing_type| TournamentType::from_string(String::from(team_pairing_type)))
+            .unwrap_or(TournamentType::SwissPairing)
+    }
+
+    pub fn set_team_pairing_type(&mut self, team_pairing_type: &TournamentType) {
+        self.set_meta_data_value(
+            "team_pairing_type",
+            Value::from(team_pairing_type.to_string()),
+        );
+    }
+
+    fn set_meta_data_value(&mut self, key: &str, value: Value) {
+        if !self.meta_data.is_object() {
+            self.meta_data = Value::Object(Map::new());
+        }
+        self.meta_data
+            .as_object_mut()
+            .unwrap()
+            .insert(String::from(key), value);
+    }
+
+    pub fn get_player_with_joueurs_id(
+        &self,
+        joueurs_id: &String,
+        connection: &PgConnection,
+    ) -> Result<Player, ErrorType> {
+        let players = self.get_players_from_joueurs(connection)?;
+        match players
+            .into_iter()
+            .find(|player| &player.joueurs_id == joueurs_id)
         {
             Some(player) => Ok(player),
             None => Err(ErrorType::BadRequestError(String::from(
                 "Invalid joueurs id",
             ))),
-        }?;
-        Ok(Player {
-            joueurs_id: player.joueurs_id.clone(),
-            first_name: player.first_name.clone(),
-            last_name: player.last_name.clone(),
-            country: player.country.clone(),
-            rating: player.rating.clone(),
-        })
-    }
-
-    pub fn get_players_from_joueurs(&self) -> Result<Vec<Player>, ErrorType> {
-        let joueurs = self.joueurs.as_array().unwrap();
-        let players: Vec<Player> = joueurs
-            .iter()
-            .map(|player_json| {
-                let player_data = player_json.as_object().unwrap().clone();
-                match Player::from_serdemap(player_data) {
-                    Ok(player) => Some(player),
-                    Err(_err) => None,
-                }
-            })
-            .filter(|player| player.is_some())
-            .map(|player| player.unwrap())
-            .collect();
-        Ok(players)
+        }
+    }
+
+    pub fn get_players_from_joueurs(
+        &self,
+        connection: &PgConnection,
+    ) -> Result<Vec<Player>, ErrorType> {
+        let snapshot = JoueursSnapshotRowModel::get(&self.joueurs_snapshot_id, connection)?;
+        Ok(snapshot.get_players())
     }
 }
 
@@ -205,9 +452,9 @@ mod tests {
     mod crud {
         use serde_json::Map;
 
-        use crate::database_models::TournamentRowModel;
+        use crate::database_models::{JoueursSnapshotRowModel, TournamentRowModel};
+        use crate::errors::ErrorType;
         use crate::properties::TournamentType;
-        use crate::tournament_manager::Player;
         use crate::utils;
         use crate::utils::{create_mock_tournament_with_creator, create_mock_user};
 
@@ -219,7 +466,9 @@ mod tests {
 
             let name = utils::generate_random_string(20);
             let country = utils::generate_random_string(10);
-            let joueurs: Vec<Player> = Vec::new();
+            let joueurs_snapshot =
+                JoueursSnapshotRowModel::create(&String::from("upload"), vec![], &test_connection)
+                    .unwrap();
             let tournament_type = TournamentType::RoundRobin;
             let date = utils::create_date_format(2020, 1, 1);
 
@@ -229,7 +478,7 @@ mod tests {
                 &date,
                 &date,
                 &user.username,
-                joueurs,
+                &joueurs_snapshot.id,
                 tournament_type,
                 Map::new(),
                 &test_connection,
@@ -307,6 +556,232 @@ mod tests {
             let updated_all_tournaments = TournamentRowModel::get_all(&test_connection).unwrap();
             assert_eq!(updated_all_tournaments.len() - initial_count, 0);
         }
+
+        #[test]
+        fn test_get_missing_tournament() {
+            let test_connection = utils::get_test_connection();
+            let result = TournamentRowModel::get(&-1, &test_connection);
+            assert_eq!(
+                result,
+                Err(ErrorType::NotFound(
+                    String::from("Tournament"),
+                    String::from("-1")
+                ))
+            );
+        }
+    }
+
+    mod status {
+        use crate::properties::TournamentStatus;
+        use crate::utils;
+        use crate::utils::{create_mock_tournament_with_creator, create_mock_user};
+
+        #[test]
+        fn test_update_status() {
+            let test_connection = utils::get_test_connection();
+            let user = create_mock_user(&test_connection);
+            let mut tournament =
+                create_mock_tournament_with_creator(&user.username, &test_connection);
+            assert_eq!(tournament.get_status(), TournamentStatus::Draft);
+
+            tournament.set_status(&TournamentStatus::RegistrationOpen);
+            let updated_tournament = tournament.update(&test_connection).unwrap();
+            assert_eq!(
+                updated_tournament.get_status(),
+                TournamentStatus::RegistrationOpen
+            );
+        }
+    }
+
+    mod tie_breakers {
+        use crate::properties::TieBreaker;
+        use crate::utils;
+        use crate::utils::{create_mock_tournament_with_creator, create_mock_user};
+
+        #[test]
+        fn test_default_tie_breakers() {
+            let test_connection = utils::get_test_connection();
+            let user = create_mock_user(&test_connection);
+            let tournament = create_mock_tournament_with_creator(&user.username, &test_connection);
+            assert_eq!(tournament.get_tie_breakers(), vec![TieBreaker::Brightwell]);
+        }
+
+        #[test]
+        fn test_update_tie_breakers() {
+            let test_connection = utils::get_test_connection();
+            let user = create_mock_user(&test_connection);
+            let mut tournament =
+                create_mock_tournament_with_creator(&user.username, &test_connection);
+
+            let tie_breakers = vec![
+                TieBreaker::Buchholz,
+                TieBreaker::HeadToHead,
+                TieBreaker::DiscCount,
+            ];
+            tournament.set_tie_breakers(&tie_breakers);
+            let updated_tournament = tournament.update(&test_connection).unwrap();
+            assert_eq!(updated_tournament.get_tie_breakers(), tie_breakers);
+        }
+    }
+
+    mod brightwell_constant {
+        use crate::utils;
+        use crate::utils::{create_mock_tournament_with_creator, create_mock_user};
+
+        #[test]
+        fn test_update_brightwell_constant() {
+            let test_connection = utils::get_test_connection();
+            let user = create_mock_user(&test_connection);
+            let mut tournament =
+                create_mock_tournament_with_creator(&user.username, &test_connection);
+
+            tournament.set_brightwell_constant(&3.5);
+            let updated_tournament = tournament.update(&test_connection).unwrap();
+            assert_eq!(updated_tournament.get_brightwell_constant(), 3.5);
+        }
+    }
+
+    mod pairing_constraints {
+        use crate::properties::PairingConstraint;
+        use crate::utils;
+        use crate::utils::{create_mock_tournament_with_creator, create_mock_user};
+
+        #[test]
+        fn test_update_pairing_constraints() {
+            let test_connection = utils::get_test_connection();
+            let user = create_mock_user(&test_connection);
+            let mut tournament =
+                create_mock_tournament_with_creator(&user.username, &test_connection);
+            assert_eq!(tournament.get_active_pairing_constraints(&1), vec![]);
+
+            let pairing_constraints = vec![PairingConstraint::SameCountry];
+            tournament.set_pairing_constraints(&pairing_constraints);
+            tournament.set_pairing_constraint_rounds(&2);
+            let updated_tournament = tournament.update(&test_connection).unwrap();
+            assert_eq!(
+                updated_tournament.get_active_pairing_constraints(&2),
+                pairing_constraints
+            );
+            assert_eq!(
+                updated_tournament.get_active_pairing_constraints(&3),
+                vec![]
+            );
+        }
+    }
+
+    mod accelerated_rounds {
+        use crate::utils;
+        use crate::utils::{create_mock_tournament_with_creator, create_mock_user};
+
+        #[test]
+        fn test_update_accelerated_rounds() {
+            let test_connection = utils::get_test_connection();
+            let user = create_mock_user(&test_connection);
+            let mut tournament =
+                create_mock_tournament_with_creator(&user.username, &test_connection);
+            assert_eq!(tournament.is_accelerated_round(&1), false);
+
+            tournament.set_accelerated_rounds(&2);
+            let updated_tournament = tournament.update(&test_connection).unwrap();
+            assert_eq!(updated_tournament.is_accelerated_round(&2), true);
+            assert_eq!(updated_tournament.is_accelerated_round(&3), false);
+        }
+    }
+
+    mod round_robin_cycles {
+        use crate::utils;
+        use crate::utils::{create_mock_tournament_with_creator, create_mock_user};
+
+        #[test]
+        fn test_update_round_robin_cycles() {
+            let test_connection = utils::get_test_connection();
+            let user = create_mock_user(&test_connection);
+            let mut tournament =
+                create_mock_tournament_with_creator(&user.username, &test_connection);
+            assert_eq!(tournament.get_round_robin_cycles(), 1);
+
+            tournament.set_round_robin_cycles(&2);
+            let updated_tournament = tournament.update(&test_connection).unwrap();
+            assert_eq!(updated_tournament.get_round_robin_cycles(), 2);
+        }
+    }
+
+    mod knockout {
+        use crate::utils;
+        use crate::utils::{create_mock_tournament_with_creator, create_mock_user};
+
+        #[test]
+        fn test_update_knockout_config() {
+            let test_connection = utils::get_test_connection();
+            let user = create_mock_user(&test_connection);
+            let mut tournament =
+                create_mock_tournament_with_creator(&user.username, &test_connection);
+            assert_eq!(tournament.get_knockout_seeds().is_empty(), true);
+            assert_eq!(tournament.get_knockout_best_of(), 1);
+            assert_eq!(tournament.has_knockout_third_place_match(), false);
+
+            tournament.set_knockout_seeds(&vec![3, 1, 2]);
+            tournament.set_knockout_best_of(&3);
+            tournament.set_knockout_third_place_match(&true);
+            let updated_tournament = tournament.update(&test_connection).unwrap();
+            assert_eq!(updated_tournament.get_knockout_seeds(), vec![3, 1, 2]);
+            assert_eq!(updated_tournament.get_knockout_best_of(), 3);
+            assert_eq!(updated_tournament.has_knockout_third_place_match(), true);
+        }
+    }
+
+    mod teams {
+        use crate::properties::{TeamTieBreaker, TournamentType};
+        use crate::utils;
+        use crate::utils::{create_mock_tournament_with_creator, create_mock_user};
+
+        #[test]
+        fn test_update_team_config() {
+            let test_connection = utils::get_test_connection();
+            let user = create_mock_user(&test_connection);
+            let mut tournament =
+                create_mock_tournament_with_creator(&user.username, &test_connection);
+            assert_eq!(
+                tournament.get_team_tie_breakers(),
+                vec![TeamTieBreaker::GamePoints, TeamTieBreaker::Buchholz]
+            );
+            assert_eq!(
+                tournament.get_team_pairing_type(),
+                TournamentType::SwissPairing
+            );
+
+            tournament.set_team_tie_breakers(&vec![TeamTieBreaker::HeadToHead]);
+            tournament.set_team_pairing_type(&TournamentType::RoundRobin);
+            let updated_tournament = tournament.update(&test_connection).unwrap();
+            assert_eq!(
+                updated_tournament.get_team_tie_breakers(),
+                vec![TeamTieBreaker::HeadToHead]
+            );
+            assert_eq!(
+                updated_tournament.get_team_pairing_type(),
+                TournamentType::RoundRobin
+            );
+        }
+    }
+
+    mod sections {
+        use crate::utils;
+        use crate::utils::{create_mock_tournament_with_creator, create_mock_user};
+
+        #[test]
+        fn test_update_sections() {
+            let test_connection = utils::get_test_connection();
+            let user = create_mock_user(&test_connection);
+            let mut tournament =
+                create_mock_tournament_with_creator(&user.username, &test_connection);
+            assert_eq!(tournament.has_sections(), false);
+
+            let sections = vec![String::from("Open"), String::from("Junior")];
+            tournament.set_sections(&sections);
+            let updated_tournament = tournament.update(&test_connection).unwrap();
+            assert_eq!(updated_tournament.has_sections(), true);
+            assert_eq!(updated_tournament.get_sections(), sections);
+        }
     }
 
     mod players {
@@ -337,7 +812,9 @@ mod tests {
                 vec![player],
                 &test_connection,
             );
-            let player = tournament.get_player_with_joueurs_id(&joueurs_id).unwrap();
+            let player = tournament
+                .get_player_with_joueurs_id(&joueurs_id, &test_connection)
+                .unwrap();
 
             assert_eq!(player.joueurs_id, joueurs_id);
             assert_eq!(player.first_name, first_name);
@@ -357,7 +834,7 @@ mod tests {
                 vec![],
                 &test_connection,
             );
-            let player = tournament.get_player_with_joueurs_id(&joueurs_id);
+            let player = tournament.get_player_with_joueurs_id(&joueurs_id, &test_connection);
             assert_eq!(player.is_err(), true);
         }
     }
@@ -1,4 +1,5 @@
 use diesel::prelude::*;
+use diesel::result::Error;
 
 use crate::errors::ErrorType;
 use crate::properties::UserRole;
@@ -81,9 +82,13 @@ impl UserRowModel {
 
         match result {
             Ok(user) => Ok(user),
+            Err(Error::NotFound) => {
+                warn!("User {} is not found", username);
+                Err(ErrorType::NotFound(String::from("User"), username.clone()))
+            }
             Err(e) => {
                 error!("{}", e);
-                Err(ErrorType::BadRequestError(String::from("User not found")))
+                Err(ErrorType::DatabaseError)
             }
         }
     }
@@ -1,3 +1,5 @@
+use rocket::http::Status;
+
 #[derive(Debug, PartialEq)]
 pub enum ErrorType {
     AuthenticationFailed,
@@ -5,6 +7,10 @@ pub enum ErrorType {
     TokenExpired,
     PermissionDenied,
     AutomaticPairingError,
+    InvalidMatchResult(String),
+    InvalidTranscript(String),
+    NotFound(String, String),
+    InvalidTournamentStatus(String),
     BadRequestError(String),
     ExternalConnectionError(String),
     UnknownError(String),
@@ -21,6 +27,25 @@ impl ErrorType {
             ErrorType::DatabaseError => 6,
             ErrorType::ExternalConnectionError(_) => 7,
             ErrorType::AutomaticPairingError => 8,
+            ErrorType::InvalidMatchResult(_) => 9,
+            ErrorType::InvalidTranscript(_) => 10,
+            ErrorType::NotFound(_, _) => 11,
+            ErrorType::InvalidTournamentStatus(_) => 12,
+        }
+    }
+
+    pub fn to_http_status(&self) -> Status {
+        match self {
+            ErrorType::AuthenticationFailed | ErrorType::TokenExpired => Status::Unauthorized,
+            ErrorType::PermissionDenied => Status::Forbidden,
+            ErrorType::NotFound(_, _) => Status::NotFound,
+            ErrorType::InvalidTournamentStatus(_) => Status::Conflict,
+            ErrorType::BadRequestError(_)
+            | ErrorType::InvalidMatchResult(_)
+            | ErrorType::InvalidTranscript(_) => Status::BadRequest,
+            ErrorType::ExternalConnectionError(_) => Status::BadGateway,
+            ErrorType::AutomaticPairingError => Status::UnprocessableEntity,
+            ErrorType::DatabaseError | ErrorType::UnknownError(_) => Status::InternalServerError,
         }
     }
 
@@ -30,6 +55,19 @@ impl ErrorType {
             ErrorType::BadRequestError(message) => {
                 String::from(format!("Bad request: {}", message))
             }
+            ErrorType::InvalidMatchResult(message) => {
+                String::from(format!("Invalid match result: {}", message))
+            }
+            ErrorType::InvalidTranscript(message) => {
+                String::from(format!("Invalid transcript: {}", message))
+            }
+            ErrorType::NotFound(entity, id) => {
+                String::from(format!("{} {} is not found.", entity, id))
+            }
+            ErrorType::InvalidTournamentStatus(status) => String::from(format!(
+                "This action is not allowed while the tournament is {}.",
+                status
+            )),
             ErrorType::ExternalConnectionError(message) => String::from(format!(
                 "Cannot connect to external source ({}), please try again.",
                 message
@@ -49,3 +87,70 @@ impl ErrorType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    mod test_http_status {
+        use rocket::http::Status;
+
+        use crate::errors::ErrorType;
+
+        #[test]
+        fn test_to_http_status() {
+            assert_eq!(
+                ErrorType::AuthenticationFailed.to_http_status(),
+                Status::Unauthorized
+            );
+            assert_eq!(
+                ErrorType::TokenExpired.to_http_status(),
+                Status::Unauthorized
+            );
+            assert_eq!(
+                ErrorType::PermissionDenied.to_http_status(),
+                Status::Forbidden
+            );
+            assert_eq!(
+                ErrorType::BadRequestError(String::from("")).to_http_status(),
+                Status::BadRequest
+            );
+            assert_eq!(
+                ErrorType::NotFound(String::from("Tournament"), String::from("1")).to_http_status(),
+                Status::NotFound
+            );
+            assert_eq!(
+                ErrorType::InvalidTournamentStatus(String::from("finished")).to_http_status(),
+                Status::Conflict
+            );
+            assert_eq!(
+                ErrorType::ExternalConnectionError(String::from("")).to_http_status(),
+                Status::BadGateway
+            );
+            assert_eq!(
+                ErrorType::AutomaticPairingError.to_http_status(),
+                Status::UnprocessableEntity
+            );
+            assert_eq!(
+                ErrorType::DatabaseError.to_http_status(),
+                Status::InternalServerError
+            );
+            assert_eq!(
+                ErrorType::UnknownError(String::from("")).to_http_status(),
+                Status::InternalServerError
+            );
+        }
+    }
+
+    mod test_not_found {
+        use rocket::http::Status;
+
+        use crate::errors::ErrorType;
+
+        #[test]
+        fn test_not_found() {
+            let error = ErrorType::NotFound(String::from("Round"), String::from("12"));
+            assert_eq!(error.to_error_code(), 11);
+            assert_eq!(error.to_http_status(), Status::NotFound);
+            assert_eq!(error.to_error_message(), "Round 12 is not found.");
+        }
+    }
+}
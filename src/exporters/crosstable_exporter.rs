@@ -0,0 +1,409 @@
+use std::collections::HashMap;
+
+use crate::database_models::{PlayerRowModel, RoundRowModel, TournamentRowModel};
+use crate::properties::{GameResult, PlayerColor};
+use crate::tournament_manager::{CrosstableEntry, CrosstableRow};
+use crate::utils::date_to_string;
+
+// Entries are written as "<opponent rank><colour><result> <discs>-<opponent discs>", e.g.
+// "3B+ 40-24", so that no cell starts with a character spreadsheets read as a formula. Forfeits
+// show "F" instead of the disc score.
+fn format_crosstable_entry(entry: &Option<CrosstableEntry>) -> String {
+    let entry = match entry {
+        Some(entry) => entry,
+        None => return String::new(),
+    };
+    if entry.result == GameResult::Bye {
+        return String::from("BYE");
+    }
+
+    let result = match entry.result {
+        GameResult::Win => "+",
+        GameResult::Draw => "=",
+        GameResult::Loss => "-",
+        _ => "?",
+    };
+    let opponent_rank = entry
+        .opponent_rank
+        .map(|rank| rank.to_string())
+        .unwrap_or_default();
+    let color = match entry.color {
+        Some(PlayerColor::Black) => "B",
+        Some(PlayerColor::White) => "W",
+        None => "",
+    };
+    let score = if entry.result == GameResult::Unfinished {
+        String::new()
+    } else if entry.is_forfeited {
+        String::from(" F")
+    } else {
+        format!(" {}-{}", entry.disc_count, entry.opponent_disc_count)
+    };
+    format!("{}{}{}{}", opponent_rank, color, result, score)
+}
+
+fn format_running_total(entry: &Option<CrosstableEntry>) -> String {
+    match entry {
+        Some(entry) => entry.running_major_score.to_string(),
+        None => String::new(),
+    }
+}
+
+fn get_player_name(players_by_id: &HashMap<&i32, &PlayerRowModel>, player_id: &i32) -> String {
+    match players_by_id.get(player_id) {
+        Some(player) => format!("{} {}", player.first_name, player.last_name),
+        None => player_id.to_string(),
+    }
+}
+
+fn get_player_country(players_by_id: &HashMap<&i32, &PlayerRowModel>, player_id: &i32) -> String {
+    match players_by_id.get(player_id) {
+        Some(player) => player.country.clone(),
+        None => String::new(),
+    }
+}
+
+fn get_tie_breaker_names(rows: &Vec<CrosstableRow>) -> Vec<String> {
+    match rows.first() {
+        Some(row) => row
+            .standing
+            .tie_breaker_scores
+            .iter()
+            .map(|(tie_breaker, _)| tie_breaker.to_string())
+            .collect(),
+        None => vec![],
+    }
+}
+
+// Text starting with a formula character is prefixed with a quote so spreadsheets keep it as
+// text. Numbers are left as they are, a negative score is not a formula.
+fn escape_csv_field(field: &String) -> String {
+    let field = if field.starts_with(&['=', '+', '-', '@'][..]) && field.parse::<f64>().is_err() {
+        format!("'{}", field)
+    } else {
+        field.clone()
+    };
+    if field.contains(',') || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+// Every round takes two columns, the game itself and the player's score after the round.
+pub fn generate_crosstable_csv(
+    players: &Vec<PlayerRowModel>,
+    rounds: &Vec<RoundRowModel>,
+    rows: &Vec<CrosstableRow>,
+) -> String {
+    let players_by_id: HashMap<&i32, &PlayerRowModel> =
+        players.iter().map(|player| (&player.id, player)).collect();
+
+    let mut header = vec![
+        String::from("Rank"),
+        String::from("Name"),
+        String::from("Country"),
+    ];
+    rounds.iter().for_each(|round| {
+        header.push(round.name.clone());
+        header.push(format!("{} Total", round.name));
+    });
+    header.push(String::from("Score"));
+    header.extend(get_tie_breaker_names(rows));
+
+    let mut lines = vec![header];
+    rows.iter().for_each(|row| {
+        let mut line = vec![
+            row.rank.to_string(),
+            get_player_name(&players_by_id, &row.standing.player_id),
+            get_player_country(&players_by_id, &row.standing.player_id),
+        ];
+        row.entries.iter().for_each(|entry| {
+            line.push(format_crosstable_entry(entry));
+            line.push(format_running_total(entry));
+        });
+        line.push(row.standing.major_score.to_string());
+        line.extend(
+            row.standing
+                .tie_breaker_scores
+                .iter()
+                .map(|(_, score)| score.to_string()),
+        );
+        lines.push(line);
+    });
+
+    let mut content: Vec<String> = lines
+        .iter()
+        .map(|line| {
+            line.iter()
+                .map(escape_csv_field)
+                .collect::<Vec<String>>()
+                .join(",")
+        })
+        .collect();
+    content.push(String::new());
+    content.join("\n")
+}
+
+fn escape_html(text: &String) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub fn generate_crosstable_html(
+    tournament: &TournamentRowModel,
+    players: &Vec<PlayerRowModel>,
+    rounds: &Vec<RoundRowModel>,
+    rows: &Vec<CrosstableRow>,
+) -> String {
+    let players_by_id: HashMap<&i32, &PlayerRowModel> =
+        players.iter().map(|player| (&player.id, player)).collect();
+    let tie_breaker_names = get_tie_breaker_names(rows);
+
+    let mut lines = vec![
+        String::from("<!DOCTYPE html>"),
+        String::from("<html>"),
+        String::from("<head>"),
+        String::from("<meta charset=\"utf-8\">"),
+        format!(
+            "<title>{} Crosstable</title>",
+            escape_html(&tournament.name)
+        ),
+        String::from("<style>"),
+        String::from("body { font-family: sans-serif; font-size: 12px; }"),
+        String::from("table { border-collapse: collapse; }"),
+        String::from("th, td { border: 1px solid #000; padding: 2px 6px; text-align: center; }"),
+        String::from("td.name { text-align: left; }"),
+        String::from(".total { color: #555; font-size: 10px; }"),
+        String::from("@media print { body { font-size: 10px; } }"),
+        String::from("</style>"),
+        String::from("</head>"),
+        String::from("<body>"),
+        format!("<h1>{}</h1>", escape_html(&tournament.name)),
+        format!(
+            "<p>{} | {} - {}</p>",
+            escape_html(&tournament.country),
+            date_to_string(tournament.start_date.clone()),
+            date_to_string(tournament.end_date.clone())
+        ),
+        String::from("<table>"),
+    ];
+
+    let mut header = vec![
+        String::from("<th>Rank</th>"),
+        String::from("<th>Name</th>"),
+        String::from("<th>Country</th>"),
+    ];
+    rounds
+        .iter()
+        .for_each(|round| header.push(format!("<th>{}</th>", escape_html(&round.name))));
+    header.push(String::from("<th>Score</th>"));
+    tie_breaker_names
+        .iter()
+        .for_each(|name| header.push(format!("<th>{}</th>", escape_html(name))));
+    lines.push(format!("<tr>{}</tr>", header.join("")));
+
+    rows.iter().for_each(|row| {
+        let mut cells = vec![
+            format!("<td>{}</td>", row.rank),
+            format!(
+                "<td class=\"name\">{}</td>",
+                escape_html(&get_player_name(&players_by_id, &row.standing.player_id))
+            ),
+            format!(
+                "<td>{}</td>",
+                escape_html(&get_player_country(&players_by_id, &row.standing.player_id))
+            ),
+        ];
+        row.entries.iter().for_each(|entry| {
+            cells.push(format!(
+                "<td>{}<br><span class=\"total\">{}</span></td>",
+                format_crosstable_entry(entry),
+                format_running_total(entry)
+            ))
+        });
+        cells.push(format!("<td>{}</td>", row.standing.major_score));
+        row.standing
+            .tie_breaker_scores
+            .iter()
+            .for_each(|(_, score)| cells.push(format!("<td>{}</td>", score)));
+        lines.push(format!("<tr>{}</tr>", cells.join("")));
+    });
+
+    lines.push(String::from("</table>"));
+    lines.push(String::from("</body>"));
+    lines.push(String::from("</html>"));
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_crosstable_exporter {
+        use serde_json::{Map, Value};
+
+        use crate::database_models::{PlayerRowModel, RoundRowModel, TournamentRowModel};
+        use crate::exporters::{generate_crosstable_csv, generate_crosstable_html};
+        use crate::game_match::GameMatchCreator;
+        use crate::properties::{RoundType, TieBreaker};
+        use crate::tournament_manager::{
+            create_crosstable, create_result_keeper_with_config, CrosstableRow, StandingsConfig,
+        };
+        use crate::utils::create_date_format;
+
+        fn create_tournament() -> TournamentRowModel {
+            TournamentRowModel {
+                id: 1,
+                name: String::from("Singapore <Open>"),
+                tournament_type: String::from("swiss_pairing"),
+                country: String::from("SGP"),
+                creator: String::from("admin"),
+                meta_data: Value::from(Map::new()),
+                start_date: create_date_format(2021, 5, 1),
+                end_date: create_date_format(2021, 5, 2),
+                joueurs_snapshot_id: 1,
+                status: String::from("finished"),
+            }
+        }
+
+        fn create_player(id: i32, first_name: &str, last_name: &str) -> PlayerRowModel {
+            PlayerRowModel {
+                id,
+                tournament_id: 1,
+                joueurs_id: id.to_string(),
+                first_name: String::from(first_name),
+                last_name: String::from(last_name),
+                country: String::from("SGP"),
+                rating: 1200,
+                meta_data: Value::from(Map::new()),
+            }
+        }
+
+        fn create_round(id: i32, name: &str) -> RoundRowModel {
+            RoundRowModel {
+                id,
+                tournament_id: 1,
+                name: String::from(name),
+                round_type: RoundType::Automatic.to_i32(),
+                meta_data: Value::from(Map::new()),
+            }
+        }
+
+        fn create_players() -> Vec<PlayerRowModel> {
+            vec![
+                create_player(1, "Ricardo", "Acuna"),
+                create_player(2, "Matias", "Aloatti"),
+                create_player(3, "Last, First", "Player"),
+                create_player(4, "Yuki", "Tanaka"),
+                create_player(5, "Ben", "Lim"),
+                create_player(6, "New", "Comer"),
+            ]
+        }
+
+        fn create_rows(players: &Vec<PlayerRowModel>) -> Vec<CrosstableRow> {
+            let game_matches = vec![
+                GameMatchCreator::create_new_finished_match(
+                    &11,
+                    &1,
+                    &2,
+                    &40,
+                    &24,
+                    &Value::from(Map::new()),
+                ),
+                GameMatchCreator::create_new_finished_match(
+                    &11,
+                    &3,
+                    &4,
+                    &32,
+                    &32,
+                    &Value::from(Map::new()),
+                ),
+                GameMatchCreator::create_new_bye_match(&11, &5, &Value::from(Map::new())),
+                GameMatchCreator::create_new_finished_match(
+                    &12,
+                    &3,
+                    &1,
+                    &34,
+                    &30,
+                    &Value::from(Map::new()),
+                ),
+                GameMatchCreator::create_new_finished_match(
+                    &12,
+                    &4,
+                    &5,
+                    &44,
+                    &20,
+                    &Value::from(Map::new()),
+                ),
+                GameMatchCreator::create_new_bye_match(&12, &2, &Value::from(Map::new())),
+            ];
+            let config = StandingsConfig {
+                tie_breakers: vec![TieBreaker::DiscCount],
+                ..StandingsConfig::default()
+            };
+            let result_keeper = create_result_keeper_with_config(&game_matches, &config);
+            let player_ids = players.iter().map(|player| player.id).collect();
+            create_crosstable(&result_keeper, &player_ids, &vec![11, 12])
+        }
+
+        #[test]
+        fn test_generate_crosstable_csv() {
+            let players = create_players();
+            let rounds = vec![create_round(11, "Round 1"), create_round(12, "Round 2")];
+
+            let csv = generate_crosstable_csv(&players, &rounds, &create_rows(&players));
+            let expected_csv = vec![
+                "Rank,Name,Country,Round 1,Round 1 Total,Round 2,Round 2 Total,Score,disc_count",
+                "1,Yuki Tanaka,SGP,2W= 32-32,0.5,5B+ 44-20,1.5,1.5,76",
+                "2,\"Last, First Player\",SGP,1B= 32-32,0.5,3B+ 34-30,1.5,1.5,66",
+                "3,Ricardo Acuna,SGP,4B+ 40-24,1,2W- 30-34,1,1,70",
+                "4,Matias Aloatti,SGP,3W- 24-40,0,BYE,1,1,56",
+                "5,Ben Lim,SGP,BYE,1,1W- 20-44,1,1,52",
+                "6,New Comer,SGP,,,,,0,0",
+                "",
+            ]
+            .join("\n");
+            assert_eq!(csv, expected_csv);
+        }
+
+        #[test]
+        fn test_generate_crosstable_csv_with_formula_name() {
+            let mut players = create_players();
+            players[5].first_name = String::from("=HYPERLINK(\"http://example.com\")");
+            players[5].country = String::from("@SUM(A1)");
+            let rounds = vec![create_round(11, "-Round 1"), create_round(12, "+Round 2")];
+
+            let csv = generate_crosstable_csv(&players, &rounds, &create_rows(&players));
+            let lines: Vec<&str> = csv.split('\n').collect();
+            assert_eq!(
+                lines[0],
+                "Rank,Name,Country,'-Round 1,'-Round 1 Total,'+Round 2,'+Round 2 Total,Score,disc_count"
+            );
+            assert_eq!(
+                lines[6],
+                "6,\"'=HYPERLINK(\"\"http://example.com\"\") Comer\",'@SUM(A1),,,,,0,0"
+            );
+        }
+
+        #[test]
+        fn test_generate_crosstable_html() {
+            let players = create_players();
+            let rounds = vec![create_round(11, "Round 1"), create_round(12, "Round 2")];
+
+            let html = generate_crosstable_html(
+                &create_tournament(),
+                &players,
+                &rounds,
+                &create_rows(&players),
+            );
+            assert_eq!(html.contains("<h1>Singapore &lt;Open&gt;</h1>"), true);
+            assert_eq!(
+                html.contains("<td>4B+ 40-24<br><span class=\"total\">1</span></td>"),
+                true
+            );
+            assert_eq!(html.contains("<td class=\"name\">New Comer</td>"), true);
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub use crosstable_exporter::{generate_crosstable_csv, generate_crosstable_html};
+pub use wof_results_exporter::generate_wof_results;
+
+mod crosstable_exporter;
+mod wof_results_exporter;
@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::database_models::{MatchRowModel, PlayerRowModel, RoundRowModel, TournamentRowModel};
+use crate::errors::ErrorType;
+use crate::game_match::GameMatchTransformer;
+use crate::tournament_manager::is_unrated_joueurs_id;
+use crate::utils::date_to_string;
+
+// Games are written as "<black joueurs id> <black discs> <white joueurs id> <white discs>". Byes
+// and forfeits are not rated by WOF, so they are only kept as comment lines for reference.
+pub fn generate_wof_results(
+    tournament: &TournamentRowModel,
+    players: &Vec<PlayerRowModel>,
+    rounds: &Vec<RoundRowModel>,
+    game_matches: &Vec<MatchRowModel>,
+) -> Result<String, ErrorType> {
+    let players_by_id: HashMap<&i32, &PlayerRowModel> =
+        players.iter().map(|player| (&player.id, player)).collect();
+    let get_joueurs_id = |player_id: &i32| -> Result<String, ErrorType> {
+        match players_by_id.get(player_id) {
+            Some(player) => Ok(player.joueurs_id.clone()),
+            None => Err(ErrorType::UnknownError(format!(
+                "Player {} is not found in tournament {}",
+                player_id, tournament.id
+            ))),
+        }
+    };
+
+    let mut lines = vec![
+        String::from("% WOF results submission"),
+        format!("% Tournament: {}", tournament.name),
+        format!("% Country: {}", tournament.country),
+        format!(
+            "% Dates: {} - {}",
+            date_to_string(tournament.start_date.clone()),
+            date_to_string(tournament.end_date.clone())
+        ),
+    ];
+
+    let newcomers: Vec<&PlayerRowModel> = players
+        .iter()
+        .filter(|player| is_unrated_joueurs_id(&player.joueurs_id))
+        .sorted_by_key(|player| player.joueurs_id.clone())
+        .collect();
+    if !newcomers.is_empty() {
+        lines.push(String::from("%"));
+        lines.push(String::from("% Newcomers"));
+        newcomers.iter().for_each(|player| {
+            lines.push(format!(
+                "{} {}, {} {}",
+                player.joueurs_id,
+                player.last_name.to_uppercase(),
+                player.first_name,
+                player.country
+            ))
+        });
+    }
+
+    for (round_number, round) in rounds.iter().sorted_by_key(|round| round.id).enumerate() {
+        lines.push(String::from("%"));
+        lines.push(format!("% Round {}: {}", round_number + 1, round.name));
+
+        for match_model in game_matches
+            .iter()
+            .filter(|match_model| match_model.round_id == round.id)
+            .sorted_by_key(|match_model| match_model.id)
+        {
+            let game_match = GameMatchTransformer::transform_to_game_match(match_model);
+            let black_joueurs_id = get_joueurs_id(&match_model.black_player_id)?;
+            if !game_match.is_finished() {
+                return Err(ErrorType::BadRequestError(String::from(
+                    "All matches must be finished before exporting the results",
+                )));
+            }
+
+            if game_match
+                .get_opponent_id(&match_model.black_player_id)
+                .is_none()
+            {
+                lines.push(format!("% bye {}", black_joueurs_id));
+                continue;
+            }
+
+            let white_joueurs_id = get_joueurs_id(&match_model.white_player_id)?;
+            if game_match.is_forfeited() {
+                let black_major_score =
+                    game_match.calculate_major_score(&match_model.black_player_id);
+                let white_major_score =
+                    game_match.calculate_major_score(&match_model.white_player_id);
+                let line = if black_major_score > white_major_score {
+                    format!(
+                        "% forfeit {} wins against {}",
+                        black_joueurs_id, white_joueurs_id
+                    )
+                } else if white_major_score > black_major_score {
+                    format!(
+                        "% forfeit {} wins against {}",
+                        white_joueurs_id, black_joueurs_id
+                    )
+                } else {
+                    format!("% double forfeit {} {}", black_joueurs_id, white_joueurs_id)
+                };
+                lines.push(line);
+                continue;
+            }
+
+            lines.push(format!(
+                "{} {} {} {}",
+                black_joueurs_id,
+                match_model.black_score,
+                white_joueurs_id,
+                match_model.white_score
+            ));
+        }
+    }
+
+    lines.push(String::new());
+    Ok(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_wof_results {
+        use serde_json::{Map, Value};
+
+        use crate::database_models::{
+            MatchRowModel, PlayerRowModel, RoundRowModel, TournamentRowModel,
+        };
+        use crate::exporters::generate_wof_results;
+        use crate::properties::{RoundType, SpecialConditionScore};
+        use crate::utils::create_date_format;
+
+        fn create_tournament() -> TournamentRowModel {
+            TournamentRowModel {
+                id: 1,
+                name: String::from("Singapore Open"),
+                tournament_type: String::from("swiss_pairing"),
+                country: String::from("SGP"),
+                creator: String::from("admin"),
+                meta_data: Value::from(Map::new()),
+                start_date: create_date_format(2021, 5, 1),
+                end_date: create_date_format(2021, 5, 2),
+                joueurs_snapshot_id: 1,
+                status: String::from("finished"),
+            }
+        }
+
+        fn create_player(
+            id: i32,
+            joueurs_id: &str,
+            first_name: &str,
+            last_name: &str,
+        ) -> PlayerRowModel {
+            PlayerRowModel {
+                id,
+                tournament_id: 1,
+                joueurs_id: String::from(joueurs_id),
+                first_name: String::from(first_name),
+                last_name: String::from(last_name),
+                country: String::from("SGP"),
+                rating: 1200,
+                meta_data: Value::from(Map::new()),
+            }
+        }
+
+        fn create_round(id: i32, name: &str) -> RoundRowModel {
+            RoundRowModel {
+                id,
+                tournament_id: 1,
+                name: String::from(name),
+                round_type: RoundType::Automatic.to_i32(),
+                meta_data: Value::from(Map::new()),
+            }
+        }
+
+        fn create_match(
+            id: i32,
+            round_id: i32,
+            black_player_id: i32,
+            white_player_id: i32,
+            black_score: i32,
+            white_score: i32,
+        ) -> MatchRowModel {
+            MatchRowModel {
+                id,
+                round_id,
+                black_player_id,
+                white_player_id,
+                black_score,
+                white_score,
+                meta_data: Value::from(Map::new()),
+            }
+        }
+
+        #[test]
+        fn test_generate_wof_results() {
+            let players = vec![
+                create_player(1, "280016", "Ricardo", "Acuna"),
+                create_player(2, "280045", "Matias", "Aloatti"),
+                create_player(3, "+ab12", "New", "Comer"),
+            ];
+            let rounds = vec![
+                create_round(11, "Round 1"),
+                create_round(12, "Round 2"),
+                create_round(13, "Round 3"),
+            ];
+            let bye_score = SpecialConditionScore::Bye.to_i32();
+            let forfeit_win_score = SpecialConditionScore::ForfeitWin.to_i32();
+            let forfeit_loss_score = SpecialConditionScore::ForfeitLoss.to_i32();
+            let game_matches = vec![
+                create_match(21, 11, 1, 2, 40, 24),
+                create_match(22, 11, 3, -1, bye_score, bye_score),
+                create_match(23, 12, 3, 1, 33, 31),
+                create_match(24, 12, 2, -1, bye_score, bye_score),
+                create_match(25, 13, 1, -1, bye_score, bye_score),
+                create_match(26, 13, 2, 3, forfeit_loss_score, forfeit_win_score),
+            ];
+
+            let results =
+                generate_wof_results(&create_tournament(), &players, &rounds, &game_matches)
+                    .unwrap();
+            let expected_results = vec![
+                "% WOF results submission",
+                "% Tournament: Singapore Open",
+                "% Country: SGP",
+                "% Dates: 2021-05-01 - 2021-05-02",
+                "%",
+                "% Newcomers",
+                "+ab12 COMER, New SGP",
+                "%",
+                "% Round 1: Round 1",
+                "280016 40 280045 24",
+                "% bye +ab12",
+                "%",
+                "% Round 2: Round 2",
+                "+ab12 33 280016 31",
+                "% bye 280045",
+                "%",
+                "% Round 3: Round 3",
+                "% bye 280016",
+                "% forfeit +ab12 wins against 280045",
+                "",
+            ]
+            .join("\n");
+            assert_eq!(results, expected_results);
+        }
+
+        #[test]
+        fn test_unfinished_match() {
+            let players = vec![
+                create_player(1, "280016", "Ricardo", "Acuna"),
+                create_player(2, "280045", "Matias", "Aloatti"),
+            ];
+            let rounds = vec![create_round(11, "Round 1")];
+            let not_finished_score = SpecialConditionScore::NotFinished.to_i32();
+            let game_matches = vec![create_match(
+                21,
+                11,
+                1,
+                2,
+                not_finished_score,
+                not_finished_score,
+            )];
+
+            let results =
+                generate_wof_results(&create_tournament(), &players, &rounds, &game_matches);
+            assert_eq!(results.is_err(), true);
+        }
+    }
+}
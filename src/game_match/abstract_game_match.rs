@@ -22,7 +22,14 @@ pub trait IGameMatch: ClonableIGameMatch + Debug {
     fn get_player_color(&self, player_id: &i32) -> Option<PlayerColor>;
     fn get_players_id(&self) -> (Option<i32>, Option<i32>);
     fn get_opponent_id(&self, player_id: &i32) -> Option<i32>;
+    fn is_finished(&self) -> bool;
+    fn is_forfeited(&self) -> bool;
+    fn is_pairing_bye(&self) -> bool {
+        false
+    }
     fn calculate_major_score(&self, player_id: &i32) -> f64;
+    fn calculate_disc_count(&self, player_id: &i32) -> f64;
+    fn calculate_disc_differential(&self, player_id: &i32) -> f64;
     fn calculate_minor_score(
         &self,
         player_id: &i32,
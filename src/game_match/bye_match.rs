@@ -7,6 +7,9 @@ use crate::properties::{PlayerColor, SpecialConditionScore};
 
 use super::IGameMatch;
 
+pub const BYE_MAJOR_SCORE_META_KEY: &str = "bye_major_score";
+pub const BYE_DISC_COUNT_META_KEY: &str = "bye_disc_count";
+
 #[derive(Clone, Debug)]
 pub struct ByeGameMatch {
     pub round_id: i32,
@@ -14,6 +17,31 @@ pub struct ByeGameMatch {
     pub meta_data: Value,
 }
 
+impl ByeGameMatch {
+    // Byes given to late entries or requested by players carry their own score, the pairing bye
+    // is worth a full win.
+    fn get_major_score(&self) -> f64 {
+        self.meta_data
+            .get(BYE_MAJOR_SCORE_META_KEY)
+            .and_then(|major_score| major_score.as_f64())
+            .unwrap_or(1.0)
+    }
+
+    fn get_disc_count(&self) -> f64 {
+        if let Some(disc_count) = self
+            .meta_data
+            .get(BYE_DISC_COUNT_META_KEY)
+            .and_then(|disc_count| disc_count.as_f64())
+        {
+            return disc_count;
+        }
+        match self.get_major_score() > 0.0 {
+            true => 32.0,
+            false => 0.0,
+        }
+    }
+}
+
 impl IGameMatch for ByeGameMatch {
     fn is_player_playing(&self, player_id: &i32) -> bool {
         player_id == &self.player_id
@@ -37,11 +65,37 @@ impl IGameMatch for ByeGameMatch {
         None
     }
 
+    fn is_finished(&self) -> bool {
+        true
+    }
+
+    fn is_forfeited(&self) -> bool {
+        false
+    }
+
+    fn is_pairing_bye(&self) -> bool {
+        self.meta_data.get(BYE_MAJOR_SCORE_META_KEY).is_none()
+    }
+
     fn calculate_major_score(&self, player_id: &i32) -> f64 {
         if !self.is_player_playing(player_id) {
             return 0.0;
         }
-        1.0
+        self.get_major_score()
+    }
+
+    fn calculate_disc_count(&self, player_id: &i32) -> f64 {
+        if !self.is_player_playing(player_id) {
+            return 0.0;
+        }
+        self.get_disc_count()
+    }
+
+    fn calculate_disc_differential(&self, player_id: &i32) -> f64 {
+        if !self.is_player_playing(player_id) {
+            return 0.0;
+        }
+        0.0
     }
 
     fn calculate_minor_score(
@@ -56,7 +110,7 @@ impl IGameMatch for ByeGameMatch {
 
         let self_major_score = major_scores_by_player_ids.get(player_id).unwrap_or(&0.0);
 
-        return 32.0 + brightwell_constant * self_major_score;
+        return self.get_disc_count() + brightwell_constant * self_major_score;
     }
 
     fn extract_data(&self) -> MatchRowModel {
@@ -0,0 +1,76 @@
+use crate::errors::ErrorType;
+
+const TOTAL_SQUARES: i32 = 64;
+const INITIAL_DISC_COUNT: i32 = 4;
+
+// Following the WOF rules, the empty squares left at the end of a game belong to the winner,
+// and are split equally on a draw.
+pub fn normalize_disc_counts(
+    black_score: &i32,
+    white_score: &i32,
+) -> Result<(i32, i32), ErrorType> {
+    if black_score < &0 || white_score < &0 {
+        return Err(ErrorType::InvalidMatchResult(String::from(
+            "Disc count cannot be negative",
+        )));
+    }
+
+    let total_disc_count = black_score + white_score;
+    if total_disc_count > TOTAL_SQUARES {
+        return Err(ErrorType::InvalidMatchResult(String::from(
+            "Total disc count cannot exceed 64",
+        )));
+    }
+    if total_disc_count < INITIAL_DISC_COUNT {
+        return Err(ErrorType::InvalidMatchResult(String::from(
+            "Total disc count cannot be less than 4",
+        )));
+    }
+
+    let empty_squares = TOTAL_SQUARES - total_disc_count;
+    if black_score > white_score {
+        return Ok((black_score + empty_squares, white_score.clone()));
+    }
+    if white_score > black_score {
+        return Ok((black_score.clone(), white_score + empty_squares));
+    }
+    Ok((
+        black_score + empty_squares / 2,
+        white_score + empty_squares / 2,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_normalize_disc_counts {
+        use crate::errors::ErrorType;
+        use crate::game_match::normalize_disc_counts;
+
+        #[test]
+        fn test_complete_board() {
+            assert_eq!(normalize_disc_counts(&40, &24), Ok((40, 24)));
+            assert_eq!(normalize_disc_counts(&32, &32), Ok((32, 32)));
+            assert_eq!(normalize_disc_counts(&0, &64), Ok((0, 64)));
+        }
+
+        #[test]
+        fn test_empty_squares_to_winner() {
+            assert_eq!(normalize_disc_counts(&40, &20), Ok((44, 20)));
+            assert_eq!(normalize_disc_counts(&20, &40), Ok((20, 44)));
+            assert_eq!(normalize_disc_counts(&13, &0), Ok((64, 0)));
+            assert_eq!(normalize_disc_counts(&30, &30), Ok((32, 32)));
+        }
+
+        #[test]
+        fn test_impossible_disc_counts() {
+            let is_invalid_result = |result: Result<(i32, i32), ErrorType>| match result {
+                Err(ErrorType::InvalidMatchResult(_)) => true,
+                _ => false,
+            };
+            assert_eq!(is_invalid_result(normalize_disc_counts(&-1, &40)), true);
+            assert_eq!(is_invalid_result(normalize_disc_counts(&40, &-2)), true);
+            assert_eq!(is_invalid_result(normalize_disc_counts(&40, &30)), true);
+            assert_eq!(is_invalid_result(normalize_disc_counts(&2, &1)), true);
+        }
+    }
+}
@@ -1,9 +1,13 @@
-use serde_json::Value;
+use serde_json::{Map, Value};
 
 use crate::database_models::MatchRowModel;
-use crate::properties::SpecialConditionScore;
+use crate::properties::{MatchResultType, SpecialConditionScore};
 
-use super::{ByeGameMatch, IGameMatch, NormalGameMatch, UnfinishedGameMatch};
+use super::{
+    AdjudicatedGameMatch, ByeGameMatch, DoubleForfeitGameMatch, ForfeitGameMatch, IGameMatch,
+    NormalGameMatch, UnfinishedGameMatch, ADJUDICATED_META_KEY, BYE_DISC_COUNT_META_KEY,
+    BYE_MAJOR_SCORE_META_KEY,
+};
 
 pub struct GameMatchTransformer {}
 
@@ -25,6 +29,49 @@ impl GameMatchTransformer {
                 meta_data: match_model.meta_data.clone(),
             });
         }
+
+        if match_model.black_score == SpecialConditionScore::DoubleForfeit.to_i32() {
+            return Box::from(DoubleForfeitGameMatch {
+                round_id: match_model.round_id.clone(),
+                black_player_id: match_model.black_player_id.clone(),
+                white_player_id: match_model.white_player_id.clone(),
+                meta_data: match_model.meta_data.clone(),
+            });
+        }
+
+        if match_model.black_score == SpecialConditionScore::ForfeitWin.to_i32()
+            || match_model.white_score == SpecialConditionScore::ForfeitWin.to_i32()
+        {
+            let winner_id =
+                match match_model.black_score == SpecialConditionScore::ForfeitWin.to_i32() {
+                    true => match_model.black_player_id.clone(),
+                    false => match_model.white_player_id.clone(),
+                };
+            return Box::from(ForfeitGameMatch {
+                round_id: match_model.round_id.clone(),
+                black_player_id: match_model.black_player_id.clone(),
+                white_player_id: match_model.white_player_id.clone(),
+                winner_id,
+                meta_data: match_model.meta_data.clone(),
+            });
+        }
+
+        let is_adjudicated = match_model
+            .meta_data
+            .get(ADJUDICATED_META_KEY)
+            .and_then(|is_adjudicated| is_adjudicated.as_bool())
+            .unwrap_or(false);
+        if is_adjudicated {
+            return Box::from(AdjudicatedGameMatch {
+                round_id: match_model.round_id.clone(),
+                black_player_id: match_model.black_player_id.clone(),
+                white_player_id: match_model.white_player_id.clone(),
+                black_score: match_model.black_score.clone(),
+                white_score: match_model.white_score.clone(),
+                meta_data: match_model.meta_data.clone(),
+            });
+        }
+
         Box::from(NormalGameMatch {
             round_id: match_model.round_id.clone(),
             black_player_id: match_model.black_player_id.clone(),
@@ -69,6 +116,40 @@ impl GameMatchCreator {
         });
     }
 
+    pub fn create_new_bye_match_with_score(
+        round_id: &i32,
+        player_id: &i32,
+        major_score: &f64,
+        disc_count: &f64,
+        meta_data: &Value,
+    ) -> Box<dyn IGameMatch> {
+        let mut bye_meta_data = meta_data.as_object().cloned().unwrap_or(Map::new());
+        bye_meta_data.insert(
+            String::from(BYE_MAJOR_SCORE_META_KEY),
+            Value::from(major_score.clone()),
+        );
+        bye_meta_data.insert(
+            String::from(BYE_DISC_COUNT_META_KEY),
+            Value::from(disc_count.clone()),
+        );
+        GameMatchCreator::create_new_bye_match(round_id, player_id, &Value::from(bye_meta_data))
+    }
+
+    // Keeps the major score of the bye, only the disc count given to the player changes.
+    pub fn create_new_bye_match_with_disc_count(
+        round_id: &i32,
+        player_id: &i32,
+        disc_count: &i32,
+        meta_data: &Value,
+    ) -> Box<dyn IGameMatch> {
+        let mut bye_meta_data = meta_data.as_object().cloned().unwrap_or(Map::new());
+        bye_meta_data.insert(
+            String::from(BYE_DISC_COUNT_META_KEY),
+            Value::from(disc_count.clone() as f64),
+        );
+        GameMatchCreator::create_new_bye_match(round_id, player_id, &Value::from(bye_meta_data))
+    }
+
     pub fn create_new_finished_match(
         round_id: &i32,
         black_player_id: &i32,
@@ -86,4 +167,178 @@ impl GameMatchCreator {
             meta_data: meta_data.clone(),
         });
     }
+
+    pub fn create_new_match_with_result(
+        round_id: &i32,
+        black_player_id: &i32,
+        white_player_id: &i32,
+        result_type: &MatchResultType,
+        black_score: &i32,
+        white_score: &i32,
+        meta_data: &Value,
+    ) -> Option<Box<dyn IGameMatch>> {
+        let mut cleaned_meta_data = meta_data.as_object().cloned().unwrap_or(Map::new());
+        cleaned_meta_data.remove(ADJUDICATED_META_KEY);
+        let meta_data = Value::from(cleaned_meta_data);
+
+        // A normal result with both scores not finished resets the match to be played again.
+        let is_reset = black_score == &SpecialConditionScore::NotFinished.to_i32()
+            && white_score == &SpecialConditionScore::NotFinished.to_i32();
+        match result_type {
+            MatchResultType::Normal if is_reset => Some(GameMatchCreator::create_new_match(
+                round_id,
+                black_player_id,
+                white_player_id,
+                &meta_data,
+            )),
+            MatchResultType::Normal => Some(GameMatchCreator::create_new_finished_match(
+                round_id,
+                black_player_id,
+                white_player_id,
+                black_score,
+                white_score,
+                &meta_data,
+            )),
+            MatchResultType::BlackWinByForfeit => Some(GameMatchCreator::create_new_forfeit_match(
+                round_id,
+                black_player_id,
+                white_player_id,
+                black_player_id,
+                &meta_data,
+            )),
+            MatchResultType::WhiteWinByForfeit => Some(GameMatchCreator::create_new_forfeit_match(
+                round_id,
+                black_player_id,
+                white_player_id,
+                white_player_id,
+                &meta_data,
+            )),
+            MatchResultType::DoubleForfeit => {
+                Some(GameMatchCreator::create_new_double_forfeit_match(
+                    round_id,
+                    black_player_id,
+                    white_player_id,
+                    &meta_data,
+                ))
+            }
+            MatchResultType::Adjudicated => Some(GameMatchCreator::create_new_adjudicated_match(
+                round_id,
+                black_player_id,
+                white_player_id,
+                black_score,
+                white_score,
+                &meta_data,
+            )),
+            MatchResultType::Unidentified => None,
+        }
+    }
+
+    pub fn create_new_forfeit_match(
+        round_id: &i32,
+        black_player_id: &i32,
+        white_player_id: &i32,
+        winner_id: &i32,
+        meta_data: &Value,
+    ) -> Box<dyn IGameMatch> {
+        return Box::from(ForfeitGameMatch {
+            round_id: round_id.clone(),
+            black_player_id: black_player_id.clone(),
+            white_player_id: white_player_id.clone(),
+            winner_id: winner_id.clone(),
+            meta_data: meta_data.clone(),
+        });
+    }
+
+    pub fn create_new_double_forfeit_match(
+        round_id: &i32,
+        black_player_id: &i32,
+        white_player_id: &i32,
+        meta_data: &Value,
+    ) -> Box<dyn IGameMatch> {
+        return Box::from(DoubleForfeitGameMatch {
+            round_id: round_id.clone(),
+            black_player_id: black_player_id.clone(),
+            white_player_id: white_player_id.clone(),
+            meta_data: meta_data.clone(),
+        });
+    }
+
+    pub fn create_new_adjudicated_match(
+        round_id: &i32,
+        black_player_id: &i32,
+        white_player_id: &i32,
+        black_score: &i32,
+        white_score: &i32,
+        meta_data: &Value,
+    ) -> Box<dyn IGameMatch> {
+        return Box::from(AdjudicatedGameMatch {
+            round_id: round_id.clone(),
+            black_player_id: black_player_id.clone(),
+            white_player_id: white_player_id.clone(),
+            black_score: black_score.clone(),
+            white_score: white_score.clone(),
+            meta_data: meta_data.clone(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_game_match_creator {
+        use serde_json::{Map, Value};
+
+        use crate::game_match::{GameMatchCreator, BYE_MAJOR_SCORE_META_KEY};
+        use crate::properties::{MatchResultType, SpecialConditionScore};
+
+        #[test]
+        fn test_reset_to_unfinished() {
+            let not_finished_score = SpecialConditionScore::NotFinished.to_i32();
+            let mut meta_data = Map::new();
+            meta_data.insert(String::from("adjudicated"), Value::from(true));
+            let game_match = GameMatchCreator::create_new_match_with_result(
+                &1,
+                &10,
+                &20,
+                &MatchResultType::Normal,
+                &not_finished_score,
+                &not_finished_score,
+                &Value::from(meta_data),
+            )
+            .unwrap();
+
+            assert_eq!(game_match.is_finished(), false);
+            let match_data = game_match.extract_data();
+            assert_eq!(match_data.black_score, not_finished_score);
+            assert_eq!(match_data.white_score, not_finished_score);
+            assert_eq!(match_data.meta_data.get("adjudicated"), None);
+        }
+
+        #[test]
+        fn test_bye_disc_count() {
+            let bye_match = GameMatchCreator::create_new_bye_match_with_score(
+                &1,
+                &10,
+                &0.5,
+                &16.0,
+                &Value::from(Map::new()),
+            );
+            let updated_bye_match = GameMatchCreator::create_new_bye_match_with_disc_count(
+                &1,
+                &10,
+                &20,
+                &bye_match.extract_data().meta_data,
+            );
+
+            assert_eq!(updated_bye_match.get_opponent_id(&10), None);
+            assert_eq!(updated_bye_match.calculate_major_score(&10), 0.5);
+            assert_eq!(updated_bye_match.calculate_disc_count(&10), 20.0);
+            assert_eq!(
+                updated_bye_match
+                    .extract_data()
+                    .meta_data
+                    .get(BYE_MAJOR_SCORE_META_KEY),
+                Some(&Value::from(0.5))
+            );
+        }
+    }
 }
@@ -1,11 +1,20 @@
 pub use abstract_game_match::IGameMatch;
+use adjudicated_match::{AdjudicatedGameMatch, ADJUDICATED_META_KEY};
 use bye_match::ByeGameMatch;
+pub use bye_match::{BYE_DISC_COUNT_META_KEY, BYE_MAJOR_SCORE_META_KEY};
+pub use disc_count::normalize_disc_counts;
+use double_forfeit_match::DoubleForfeitGameMatch;
 pub use factories::{GameMatchCreator, GameMatchTransformer};
+use forfeit_match::ForfeitGameMatch;
 use normal_match::NormalGameMatch;
 use unfinished_match::UnfinishedGameMatch;
 
 mod abstract_game_match;
+mod adjudicated_match;
 mod bye_match;
+mod disc_count;
+mod double_forfeit_match;
 mod factories;
+mod forfeit_match;
 mod normal_match;
 mod unfinished_match;
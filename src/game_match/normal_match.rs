@@ -46,6 +46,14 @@ impl IGameMatch for NormalGameMatch {
         Some(self.black_player_id)
     }
 
+    fn is_finished(&self) -> bool {
+        true
+    }
+
+    fn is_forfeited(&self) -> bool {
+        false
+    }
+
     fn calculate_major_score(&self, player_id: &i32) -> f64 {
         if !self.is_player_playing(player_id) {
             return 0.0;
@@ -66,6 +74,26 @@ impl IGameMatch for NormalGameMatch {
         0.0
     }
 
+    fn calculate_disc_count(&self, player_id: &i32) -> f64 {
+        if !self.is_player_playing(player_id) {
+            return 0.0;
+        }
+        match &self.black_player_id == player_id {
+            true => f64::from(self.black_score),
+            false => f64::from(self.white_score),
+        }
+    }
+
+    fn calculate_disc_differential(&self, player_id: &i32) -> f64 {
+        if !self.is_player_playing(player_id) {
+            return 0.0;
+        }
+        match &self.black_player_id == player_id {
+            true => f64::from(self.black_score - self.white_score),
+            false => f64::from(self.white_score - self.black_score),
+        }
+    }
+
     fn calculate_minor_score(
         &self,
         player_id: &i32,
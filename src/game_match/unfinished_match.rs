@@ -44,6 +44,14 @@ impl IGameMatch for UnfinishedGameMatch {
         Some(self.black_player_id)
     }
 
+    fn is_finished(&self) -> bool {
+        false
+    }
+
+    fn is_forfeited(&self) -> bool {
+        false
+    }
+
     fn calculate_major_score(&self, player_id: &i32) -> f64 {
         if !self.is_player_playing(player_id) {
             return 0.0;
@@ -51,6 +59,20 @@ impl IGameMatch for UnfinishedGameMatch {
         0.0
     }
 
+    fn calculate_disc_count(&self, player_id: &i32) -> f64 {
+        if !self.is_player_playing(player_id) {
+            return 0.0;
+        }
+        0.0
+    }
+
+    fn calculate_disc_differential(&self, player_id: &i32) -> f64 {
+        if !self.is_player_playing(player_id) {
+            return 0.0;
+        }
+        0.0
+    }
+
     fn calculate_minor_score(
         &self,
         player_id: &i32,
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use crate::tournament_manager::Player;
+
+pub struct JoueursDiff {
+    pub added: Vec<Player>,
+    pub removed: Vec<Player>,
+    pub updated: Vec<(Player, Player)>,
+}
+
+impl JoueursDiff {
+    pub fn between(old_players: Vec<Player>, new_players: Vec<Player>) -> JoueursDiff {
+        let mut old_players_by_joueurs_id: HashMap<String, Player> = old_players
+            .into_iter()
+            .map(|player| (player.joueurs_id.clone(), player))
+            .collect();
+
+        let mut added = Vec::new();
+        let mut updated = Vec::new();
+        new_players.into_iter().for_each(|new_player| {
+            match old_players_by_joueurs_id.remove(&new_player.joueurs_id) {
+                Some(old_player) => {
+                    if is_player_changed(&old_player, &new_player) {
+                        updated.push((old_player, new_player));
+                    }
+                }
+                None => added.push(new_player),
+            }
+        });
+
+        let mut removed: Vec<Player> = old_players_by_joueurs_id
+            .into_iter()
+            .map(|(_, player)| player)
+            .collect();
+        added.sort_by(|player_1, player_2| player_1.joueurs_id.cmp(&player_2.joueurs_id));
+        removed.sort_by(|player_1, player_2| player_1.joueurs_id.cmp(&player_2.joueurs_id));
+        updated
+            .sort_by(|(player_1, _), (player_2, _)| player_1.joueurs_id.cmp(&player_2.joueurs_id));
+
+        JoueursDiff {
+            added,
+            removed,
+            updated,
+        }
+    }
+
+    pub fn to_serdemap(&self) -> Map<String, Value> {
+        let mut diff = Map::new();
+        diff.insert(
+            String::from("added"),
+            Value::from(
+                self.added
+                    .iter()
+                    .map(|player| Value::Object(player.to_serdemap()))
+                    .collect::<Vec<Value>>(),
+            ),
+        );
+        diff.insert(
+            String::from("removed"),
+            Value::from(
+                self.removed
+                    .iter()
+                    .map(|player| Value::Object(player.to_serdemap()))
+                    .collect::<Vec<Value>>(),
+            ),
+        );
+        diff.insert(
+            String::from("updated"),
+            Value::from(
+                self.updated
+                    .iter()
+                    .map(|(old_player, new_player)| {
+                        generate_player_change_meta(old_player, new_player)
+                    })
+                    .collect::<Vec<Value>>(),
+            ),
+        );
+        diff
+    }
+}
+
+pub fn is_player_changed(old_player: &Player, new_player: &Player) -> bool {
+    old_player.rating != new_player.rating
+        || old_player.first_name != new_player.first_name
+        || old_player.last_name != new_player.last_name
+}
+
+pub fn generate_player_change_meta(old_player: &Player, new_player: &Player) -> Value {
+    let mut meta = Map::new();
+    meta.insert(
+        String::from("joueurs_id"),
+        Value::from(new_player.joueurs_id.clone()),
+    );
+    meta.insert(String::from("old"), Value::Object(old_player.to_serdemap()));
+    meta.insert(String::from("new"), Value::Object(new_player.to_serdemap()));
+    Value::Object(meta)
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_joueurs_diff {
+        use crate::joueurs::JoueursDiff;
+        use crate::tournament_manager::Player;
+
+        fn create_player(joueurs_id: &str, last_name: &str, rating: i32) -> Player {
+            Player {
+                joueurs_id: String::from(joueurs_id),
+                first_name: String::from("Player"),
+                last_name: String::from(last_name),
+                country: String::from("SGP"),
+                rating,
+            }
+        }
+
+        #[test]
+        fn test_diff() {
+            let old_players = vec![
+                create_player("1", "A", 1500),
+                create_player("2", "B", 1400),
+                create_player("3", "C", 1300),
+            ];
+            let new_players = vec![
+                create_player("4", "D", 1200),
+                create_player("3", "C", 1350),
+                create_player("1", "A", 1500),
+            ];
+
+            let diff = JoueursDiff::between(old_players, new_players);
+            assert_eq!(diff.added.len(), 1);
+            assert_eq!(diff.added[0].joueurs_id, String::from("4"));
+            assert_eq!(diff.removed.len(), 1);
+            assert_eq!(diff.removed[0].joueurs_id, String::from("2"));
+            assert_eq!(diff.updated.len(), 1);
+            assert_eq!(diff.updated[0].0.rating, 1300);
+            assert_eq!(diff.updated[0].1.rating, 1350);
+        }
+
+        #[test]
+        fn test_renamed_player() {
+            let old_players = vec![create_player("1", "A", 1500)];
+            let new_players = vec![create_player("1", "Z", 1500)];
+
+            let diff = JoueursDiff::between(old_players, new_players);
+            assert_eq!(diff.added.len(), 0);
+            assert_eq!(diff.removed.len(), 0);
+            assert_eq!(diff.updated.len(), 1);
+        }
+    }
+}
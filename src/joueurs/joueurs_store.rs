@@ -0,0 +1,108 @@
+use std::env;
+
+use chrono::{Duration, NaiveDateTime, Utc};
+use diesel::PgConnection;
+
+use crate::database_models::JoueursSnapshotRowModel;
+use crate::errors::ErrorType;
+
+use super::{Joueurs, JoueursParser};
+
+const DEFAULT_JOUEURS_CACHE_HOURS: i64 = 24;
+
+pub struct JoueursStore {}
+
+impl JoueursStore {
+    // Reuses the latest snapshot while it is fresh, otherwise refetches it from the WOF website
+    // and falls back to the last good snapshot when the website is unreachable.
+    pub fn get_current_snapshot(
+        connection: &PgConnection,
+    ) -> Result<JoueursSnapshotRowModel, ErrorType> {
+        let latest_snapshot = JoueursSnapshotRowModel::get_latest(connection)?;
+        if let Some(snapshot) = &latest_snapshot {
+            if is_snapshot_fresh(
+                &snapshot.fetched_at,
+                &Utc::now().naive_utc(),
+                &get_joueurs_cache_hours(),
+            ) {
+                return Ok(latest_snapshot.unwrap());
+            }
+        }
+
+        JoueursStore::fetch_or_fallback(latest_snapshot, connection)
+    }
+
+    // Ignores the cache and refetches from the WOF website, falling back to the latest snapshot.
+    pub fn refresh_snapshot(
+        connection: &PgConnection,
+    ) -> Result<JoueursSnapshotRowModel, ErrorType> {
+        let latest_snapshot = JoueursSnapshotRowModel::get_latest(connection)?;
+        JoueursStore::fetch_or_fallback(latest_snapshot, connection)
+    }
+
+    pub fn store(
+        source: &String,
+        raw_joueurs: &String,
+        connection: &PgConnection,
+    ) -> Result<JoueursSnapshotRowModel, ErrorType> {
+        let players = JoueursParser::parse(raw_joueurs)?;
+        if players.is_empty() {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Joueurs file does not contain any player.",
+            )));
+        }
+        JoueursSnapshotRowModel::create(source, players, connection)
+    }
+
+    fn fetch_or_fallback(
+        latest_snapshot: Option<JoueursSnapshotRowModel>,
+        connection: &PgConnection,
+    ) -> Result<JoueursSnapshotRowModel, ErrorType> {
+        let fetch_result = Joueurs::get(3).and_then(|raw_joueurs| {
+            JoueursStore::store(&String::from("wof"), &raw_joueurs, connection)
+        });
+        match (fetch_result, latest_snapshot) {
+            (Ok(snapshot), _) => Ok(snapshot),
+            (Err(_), Some(snapshot)) => {
+                warn!(
+                    "Failed refreshing joueurs, using snapshot {} from {}",
+                    &snapshot.id, &snapshot.fetched_at
+                );
+                Ok(snapshot)
+            }
+            (Err(err), None) => Err(err),
+        }
+    }
+}
+
+fn get_joueurs_cache_hours() -> i64 {
+    match env::var("JOUEURS_CACHE_HOURS") {
+        Ok(hours) => hours.parse::<i64>().unwrap_or(DEFAULT_JOUEURS_CACHE_HOURS),
+        Err(_) => DEFAULT_JOUEURS_CACHE_HOURS,
+    }
+}
+
+fn is_snapshot_fresh(fetched_at: &NaiveDateTime, now: &NaiveDateTime, cache_hours: &i64) -> bool {
+    now.signed_duration_since(fetched_at.clone()) < Duration::hours(cache_hours.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_snapshot_freshness {
+        use chrono::{Duration, NaiveDate};
+
+        use crate::joueurs::joueurs_store::is_snapshot_fresh;
+
+        #[test]
+        fn test_fresh_and_stale() {
+            let fetched_at = NaiveDate::from_ymd(2021, 5, 1).and_hms(10, 0, 0);
+            let cache_hours = 24;
+
+            let now = fetched_at + Duration::hours(23);
+            assert_eq!(is_snapshot_fresh(&fetched_at, &now, &cache_hours), true);
+
+            let now = fetched_at + Duration::hours(24);
+            assert_eq!(is_snapshot_fresh(&fetched_at, &now, &cache_hours), false);
+        }
+    }
+}
@@ -1,7 +1,11 @@
 mod joueurs;
+mod joueurs_diff;
 mod joueurs_name_parser;
 mod joueurs_parser;
 mod joueurs_player_parser;
+mod joueurs_store;
 
 pub use joueurs::Joueurs;
+pub use joueurs_diff::{generate_player_change_meta, is_player_changed, JoueursDiff};
 pub use joueurs_parser::JoueursParser;
+pub use joueurs_store::JoueursStore;
@@ -21,9 +21,11 @@ use rocket_cors::{AllowedOrigins, CorsOptions};
 pub mod account;
 pub mod database_models;
 pub mod errors;
+pub mod exporters;
 pub mod game_match;
 pub mod joueurs;
 pub mod meta_generator;
+pub mod othello;
 pub mod pairings_generator;
 pub mod properties;
 pub mod response_commands;
@@ -52,7 +54,7 @@ fn main() {
     info!("Starting the program");
     create_default_superuser();
 
-    let allowed_methods = ["Get", "Post", "Patch", "Delete"]
+    let allowed_methods = ["Get", "Post", "Put", "Patch", "Delete"]
         .iter()
         .map(|s| FromStr::from_str(s).unwrap())
         .collect();
@@ -84,26 +86,59 @@ fn main() {
                 routes::tournament_routes::create_tournament,
                 routes::tournament_routes::update_tournament,
                 routes::tournament_routes::delete_tournament,
+                routes::tournament_routes::open_tournament_registration,
+                routes::tournament_routes::start_tournament,
+                routes::tournament_routes::finish_tournament,
+                routes::tournament_routes::archive_tournament,
                 routes::tournament_routes::get_tournament_summary,
+                routes::tournament_routes::get_tournament_rating_changes,
+                routes::tournament_routes::export_wof_results,
+                routes::tournament_routes::get_crosstable,
+                routes::tournament_routes::export_crosstable_html,
+                routes::tournament_routes::export_crosstable_csv,
                 routes::tournament_admin_routes::get_tournament_admins,
                 routes::tournament_admin_routes::get_tournament_potential_admins,
                 routes::tournament_admin_routes::add_admin,
                 routes::tournament_admin_routes::remove_admin,
                 routes::player_routes::get_players,
                 routes::player_routes::get_joueurs_players,
+                routes::player_routes::refresh_joueurs,
+                routes::player_routes::get_player_games,
                 routes::player_routes::add_player,
                 routes::player_routes::add_player_new,
+                routes::player_routes::add_late_player,
+                routes::player_routes::update_player,
                 routes::player_routes::delete_player,
+                routes::player_routes::withdraw_player,
+                routes::player_routes::request_player_bye,
+                routes::player_routes::cancel_player_bye,
+                routes::team_routes::get_teams,
+                routes::team_routes::get_team_standings,
+                routes::team_routes::add_team,
+                routes::team_routes::update_team,
+                routes::team_routes::delete_team,
                 routes::round_match_routes::get_tournament_rounds,
                 routes::round_match_routes::create_manual_normal_round,
                 routes::round_match_routes::create_manual_special_round,
                 routes::round_match_routes::create_automatic_round,
+                routes::round_match_routes::preview_automatic_round,
+                routes::round_match_routes::commit_automatic_round,
+                routes::round_match_routes::get_round_robin_schedule,
+                routes::knockout_routes::seed_knockout,
                 routes::round_match_routes::get_round,
                 routes::round_match_routes::get_standings,
                 routes::round_match_routes::update_round,
                 routes::round_match_routes::delete_round,
                 routes::round_match_routes::get_round_matches,
                 routes::round_match_routes::update_match,
+                routes::round_match_routes::update_match_transcript,
+            ],
+        )
+        .mount(
+            "/api/joueurs",
+            routes![
+                routes::joueurs_routes::get_joueurs_snapshots,
+                routes::joueurs_routes::upload_joueurs,
             ],
         )
         .mount(
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use crate::database_models::PlayerRowModel;
+use crate::properties::PlayerColor;
+use crate::tournament_manager::{CrosstableEntry, CrosstableRow};
+
+use super::{StandingMetaGenerator, StandingSummaryMetaGenerator};
+
+pub trait CrosstableMetaGenerator {
+    fn generate_meta_for(&self, row: &CrosstableRow) -> Map<String, Value>;
+}
+
+pub struct CrosstableSummaryMetaGenerator<'a> {
+    pub players_by_id: HashMap<&'a i32, &'a PlayerRowModel>,
+}
+
+impl CrosstableSummaryMetaGenerator<'_> {
+    fn generate_entry_meta_for(&self, entry: &CrosstableEntry) -> Value {
+        let mut meta = Map::new();
+        meta.insert(
+            String::from("round_id"),
+            Value::from(entry.round_id.clone()),
+        );
+        meta.insert(
+            String::from("opponent_id"),
+            Value::from(entry.opponent_id.clone()),
+        );
+        meta.insert(
+            String::from("opponent_rank"),
+            Value::from(entry.opponent_rank.clone()),
+        );
+        let color = match entry.color {
+            Some(PlayerColor::Black) => Value::from("black"),
+            Some(PlayerColor::White) => Value::from("white"),
+            None => Value::Null,
+        };
+        meta.insert(String::from("color"), color);
+        meta.insert(
+            String::from("disc_count"),
+            Value::from(entry.disc_count.clone()),
+        );
+        meta.insert(
+            String::from("opponent_disc_count"),
+            Value::from(entry.opponent_disc_count.clone()),
+        );
+        meta.insert(
+            String::from("result"),
+            Value::from(entry.result.to_string()),
+        );
+        meta.insert(
+            String::from("is_forfeited"),
+            Value::from(entry.is_forfeited.clone()),
+        );
+        meta.insert(
+            String::from("running_major_score"),
+            Value::from(entry.running_major_score.clone()),
+        );
+        meta.insert(
+            String::from("running_disc_count"),
+            Value::from(entry.running_disc_count.clone()),
+        );
+        Value::from(meta)
+    }
+}
+
+impl CrosstableMetaGenerator for CrosstableSummaryMetaGenerator<'_> {
+    fn generate_meta_for(&self, row: &CrosstableRow) -> Map<String, Value> {
+        let standing_meta_generator = StandingSummaryMetaGenerator {
+            players_by_id: self.players_by_id.clone(),
+        };
+        let mut meta = standing_meta_generator.generate_meta_for(&row.standing);
+        meta.insert(String::from("rank"), Value::from(row.rank.clone()));
+
+        let entries_meta: Vec<Value> = row
+            .entries
+            .iter()
+            .map(|entry| match entry {
+                Some(entry) => self.generate_entry_meta_for(entry),
+                None => Value::Null,
+            })
+            .collect();
+        meta.insert(String::from("rounds"), Value::from(entries_meta));
+        meta
+    }
+}
@@ -6,6 +6,7 @@ use crate::database_models::{
     MatchRowModel, PlayerRowModel, RoundRowModel, TournamentRowModel, UserRowModel,
 };
 use crate::meta_generator::RoundMetaGenerator;
+use crate::properties::TieBreaker;
 use crate::tournament_manager::PlayerStanding;
 
 use super::{
@@ -65,6 +66,20 @@ pub fn generate_matches_meta(match_models: Vec<MatchRowModel>) -> Vec<Map<String
         .collect()
 }
 
+pub fn generate_tie_breaker_scores_meta(
+    tie_breaker_scores: &Vec<(TieBreaker, f64)>,
+) -> Vec<Map<String, Value>> {
+    tie_breaker_scores
+        .iter()
+        .map(|(tie_breaker, score)| {
+            let mut meta = Map::new();
+            meta.insert(String::from("name"), Value::from(tie_breaker.to_string()));
+            meta.insert(String::from("score"), Value::from(score.clone()));
+            meta
+        })
+        .collect()
+}
+
 pub fn generate_standings_meta(standings: Vec<PlayerStanding>) -> Vec<Map<String, Value>> {
     let meta_generator = DefaultStandingMetaGenerator {};
     standings
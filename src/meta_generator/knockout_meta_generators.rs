@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use crate::database_models::PlayerRowModel;
+use crate::pairings_generator::KnockoutTie;
+
+pub trait KnockoutTieMetaGenerator {
+    fn generate_meta_for(&self, tie: &KnockoutTie) -> Map<String, Value>;
+}
+
+pub struct DefaultKnockoutTieMetaGenerator<'a> {
+    pub players_by_id: HashMap<&'a i32, &'a PlayerRowModel>,
+}
+
+impl DefaultKnockoutTieMetaGenerator<'_> {
+    fn generate_player_meta_for(&self, player_id: &Option<i32>, score: &f64) -> Value {
+        let player_id = match player_id {
+            Some(player_id) => player_id,
+            None => return Value::Null,
+        };
+
+        let mut meta = Map::new();
+        meta.insert(String::from("player_id"), Value::from(player_id.clone()));
+        if let Some(&player) = self.players_by_id.get(player_id) {
+            meta.insert(
+                String::from("first_name"),
+                Value::from(player.first_name.clone()),
+            );
+            meta.insert(
+                String::from("last_name"),
+                Value::from(player.last_name.clone()),
+            );
+        }
+        meta.insert(String::from("score"), Value::from(score.clone()));
+        Value::from(meta)
+    }
+}
+
+impl KnockoutTieMetaGenerator for DefaultKnockoutTieMetaGenerator<'_> {
+    fn generate_meta_for(&self, tie: &KnockoutTie) -> Map<String, Value> {
+        let mut meta = Map::new();
+        meta.insert(String::from("stage"), Value::from(tie.stage.clone()));
+        meta.insert(
+            String::from("stage_name"),
+            Value::from(tie.stage_name.clone()),
+        );
+        meta.insert(
+            String::from("is_third_place"),
+            Value::from(tie.is_third_place.clone()),
+        );
+        meta.insert(
+            String::from("higher_seed"),
+            self.generate_player_meta_for(&tie.player_ids.0, &tie.scores.0),
+        );
+        meta.insert(
+            String::from("lower_seed"),
+            self.generate_player_meta_for(&tie.player_ids.1, &tie.scores.1),
+        );
+        meta.insert(
+            String::from("game_count"),
+            Value::from(tie.game_count.clone()),
+        );
+        meta.insert(
+            String::from("winner_id"),
+            match tie.winner_id {
+                Some(winner_id) => Value::from(winner_id),
+                None => Value::Null,
+            },
+        );
+        meta
+    }
+}
@@ -28,6 +28,13 @@ impl MatchMetaGenerator for DefaultMatchMetaGenerator {
             String::from("white_score"),
             Value::from(game_match.white_score.clone()),
         );
+        meta.insert(
+            String::from("transcript"),
+            match game_match.get_transcript() {
+                Some(transcript) => Value::from(transcript),
+                None => Value::Null,
+            },
+        );
         meta
     }
 }
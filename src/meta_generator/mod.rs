@@ -1,10 +1,16 @@
+pub use crosstable_meta_generators::{CrosstableMetaGenerator, CrosstableSummaryMetaGenerator};
 pub use helpers::{
     generate_matches_meta, generate_players_meta, generate_rounds_meta, generate_standings_meta,
-    generate_tournaments_meta, generate_users_meta,
+    generate_tie_breaker_scores_meta, generate_tournaments_meta, generate_users_meta,
 };
+pub use knockout_meta_generators::{DefaultKnockoutTieMetaGenerator, KnockoutTieMetaGenerator};
 pub use match_meta_generator::{DefaultMatchMetaGenerator, MatchMetaGenerator};
 pub use meta_generator::MetaGenerator;
+pub use pairing_meta_generators::{
+    DefaultPairingPreviewMetaGenerator, PairingPreviewMetaGenerator,
+};
 pub use player_meta_generators::PlayerMetaGenerator;
+pub use rating_meta_generators::{DefaultRatingChangeMetaGenerator, RatingChangeMetaGenerator};
 pub use round_meta_generators::{
     RoundDetailsMetaGenerator, RoundMetaGenerator, RoundPreviewMetaGenerator,
 };
@@ -13,17 +19,26 @@ pub use summary_meta_generators::{
     MatchSummaryMetaGenerator, RoundSummaryMetaGenerator, StandingSummaryMetaGenerator,
     TournamentSummaryMetaGenerator,
 };
+pub use team_meta_generators::{
+    DefaultTeamMetaGenerator, DefaultTeamStandingMetaGenerator, TeamMetaGenerator,
+    TeamStandingMetaGenerator,
+};
 pub use tournament_meta_generators::{
     TournamentDetailsMetaGenerator, TournamentMetaGenerator, TournamentPreviewMetaGenerator,
 };
 pub use user_meta_generators::UserMetaGenerator;
 
+mod crosstable_meta_generators;
 mod helpers;
+mod knockout_meta_generators;
 mod match_meta_generator;
 mod meta_generator;
+mod pairing_meta_generators;
 mod player_meta_generators;
+mod rating_meta_generators;
 mod round_meta_generators;
 mod standing_meta_generators;
 mod summary_meta_generators;
+mod team_meta_generators;
 mod tournament_meta_generators;
 mod user_meta_generators;
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use crate::database_models::PlayerRowModel;
+use crate::pairings_generator::{PairedPlayerPreview, PairingPreview};
+use crate::properties::PlayerColor;
+
+pub trait PairingPreviewMetaGenerator {
+    fn generate_meta_for(&self, pairing: &PairingPreview) -> Map<String, Value>;
+}
+
+pub struct DefaultPairingPreviewMetaGenerator<'a> {
+    pub players_by_id: HashMap<&'a i32, &'a PlayerRowModel>,
+}
+
+impl DefaultPairingPreviewMetaGenerator<'_> {
+    fn generate_player_meta_for(&self, paired_player: &PairedPlayerPreview) -> Value {
+        let mut meta = Map::new();
+        meta.insert(
+            String::from("player_id"),
+            Value::from(paired_player.player_id.clone()),
+        );
+        if let Some(&player) = self.players_by_id.get(&paired_player.player_id) {
+            meta.insert(
+                String::from("first_name"),
+                Value::from(player.first_name.clone()),
+            );
+            meta.insert(
+                String::from("last_name"),
+                Value::from(player.last_name.clone()),
+            );
+        }
+        let color = match paired_player.color {
+            Some(PlayerColor::Black) => Value::from("black"),
+            Some(PlayerColor::White) => Value::from("white"),
+            None => Value::Null,
+        };
+        meta.insert(String::from("color"), color);
+        meta.insert(
+            String::from("score_group"),
+            Value::from(paired_player.score_group.clone()),
+        );
+        meta.insert(
+            String::from("virtual_points"),
+            Value::from(paired_player.virtual_points.clone()),
+        );
+        let (float_direction, float_reason) = match &paired_player.float {
+            Some((direction, reason)) => (
+                Value::from(direction.to_string()),
+                Value::from(reason.to_string()),
+            ),
+            None => (Value::Null, Value::Null),
+        };
+        meta.insert(String::from("float"), float_direction);
+        meta.insert(String::from("float_reason"), float_reason);
+        Value::from(meta)
+    }
+}
+
+impl PairingPreviewMetaGenerator for DefaultPairingPreviewMetaGenerator<'_> {
+    fn generate_meta_for(&self, pairing: &PairingPreview) -> Map<String, Value> {
+        let mut meta = Map::new();
+        meta.insert(
+            String::from("is_bye"),
+            Value::from(pairing.opponent.is_none()),
+        );
+        meta.insert(
+            String::from("player"),
+            self.generate_player_meta_for(&pairing.player),
+        );
+        let opponent = match &pairing.opponent {
+            Some(opponent) => self.generate_player_meta_for(opponent),
+            None => Value::Null,
+        };
+        meta.insert(String::from("opponent"), opponent);
+        meta.insert(
+            String::from("broken_constraints"),
+            Value::from(
+                pairing
+                    .broken_constraints
+                    .iter()
+                    .map(|pairing_constraint| pairing_constraint.to_string())
+                    .collect::<Vec<String>>(),
+            ),
+        );
+        meta
+    }
+}
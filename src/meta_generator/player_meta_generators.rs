@@ -51,6 +51,22 @@ impl MetaGenerator for PlayerMetaGenerator {
             String::from("rating"),
             Value::from(self.player.rating.clone()),
         );
+        meta.insert(String::from("team"), Value::from(self.player.get_team()));
+        meta.insert(
+            String::from("section"),
+            Value::from(self.player.get_section()),
+        );
+        meta.insert(
+            String::from("withdrawn"),
+            Value::from(self.player.is_withdrawn()),
+        );
+        let requested_byes: Vec<Value> = self
+            .player
+            .get_requested_byes()
+            .iter()
+            .map(|requested_bye| Value::from(requested_bye.to_serdemap()))
+            .collect();
+        meta.insert(String::from("requested_byes"), Value::from(requested_byes));
         meta
     }
 }
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use crate::database_models::PlayerRowModel;
+use crate::tournament_manager::PlayerRatingChange;
+
+pub trait RatingChangeMetaGenerator {
+    fn generate_meta_for(&self, rating_change: &PlayerRatingChange) -> Map<String, Value>;
+}
+
+pub struct DefaultRatingChangeMetaGenerator<'a> {
+    pub players_by_id: HashMap<&'a i32, &'a PlayerRowModel>,
+}
+
+impl RatingChangeMetaGenerator for DefaultRatingChangeMetaGenerator<'_> {
+    fn generate_meta_for(&self, rating_change: &PlayerRatingChange) -> Map<String, Value> {
+        let mut meta = Map::new();
+
+        let &player = self.players_by_id.get(&rating_change.player_id).unwrap();
+        meta.insert(
+            String::from("player_id"),
+            Value::from(rating_change.player_id.clone()),
+        );
+        meta.insert(
+            String::from("joueurs_id"),
+            Value::from(player.joueurs_id.clone()),
+        );
+        meta.insert(
+            String::from("first_name"),
+            Value::from(player.first_name.clone()),
+        );
+        meta.insert(
+            String::from("last_name"),
+            Value::from(player.last_name.clone()),
+        );
+        meta.insert(
+            String::from("is_rated"),
+            Value::from(rating_change.is_rated.clone()),
+        );
+        meta.insert(
+            String::from("rating"),
+            Value::from(rating_change.rating.clone()),
+        );
+        meta.insert(
+            String::from("games_count"),
+            Value::from(rating_change.games_count.clone()),
+        );
+        meta.insert(
+            String::from("expected_score"),
+            Value::from(rating_change.expected_score.clone()),
+        );
+        meta.insert(
+            String::from("actual_score"),
+            Value::from(rating_change.actual_score.clone()),
+        );
+        meta.insert(
+            String::from("projected_rating"),
+            Value::from(rating_change.projected_rating.clone()),
+        );
+        meta
+    }
+}
@@ -13,6 +13,7 @@ impl RoundMetaGenerator for RoundPreviewMetaGenerator {
         let mut meta = Map::new();
         meta.insert(String::from("id"), Value::from(round.id.clone()));
         meta.insert(String::from("name"), Value::from(round.name.clone()));
+        meta.insert(String::from("section"), Value::from(round.get_section()));
         meta
     }
 }
@@ -25,6 +26,7 @@ impl RoundMetaGenerator for RoundDetailsMetaGenerator {
         meta.insert(String::from("id"), Value::from(round.id.clone()));
         meta.insert(String::from("name"), Value::from(round.name.clone()));
         meta.insert(String::from("type"), Value::from(round.round_type.clone()));
+        meta.insert(String::from("section"), Value::from(round.get_section()));
         meta
     }
 }
@@ -3,7 +3,7 @@ use serde_json::{Map, Value};
 use crate::game_match::GameMatchTransformer;
 use crate::tournament_manager::PlayerStanding;
 
-use super::{generate_matches_meta};
+use super::{generate_matches_meta, generate_tie_breaker_scores_meta};
 
 pub trait StandingMetaGenerator {
     fn generate_meta_for(&self, standing: &PlayerStanding) -> Map<String, Value>;
@@ -27,6 +27,12 @@ impl StandingMetaGenerator for DefaultStandingMetaGenerator {
             String::from("minor_score"),
             Value::from(standing.minor_score),
         );
+        meta.insert(
+            String::from("tie_breakers"),
+            Value::from(generate_tie_breaker_scores_meta(
+                &standing.tie_breaker_scores,
+            )),
+        );
 
         let matches_meta = generate_matches_meta(
             standing
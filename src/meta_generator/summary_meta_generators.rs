@@ -7,7 +7,8 @@ use crate::tournament_manager::PlayerStanding;
 use crate::utils::date_to_string;
 
 use super::{
-    MatchMetaGenerator, RoundMetaGenerator, StandingMetaGenerator, TournamentMetaGenerator,
+    generate_tie_breaker_scores_meta, MatchMetaGenerator, RoundMetaGenerator,
+    StandingMetaGenerator, TournamentMetaGenerator,
 };
 
 pub struct TournamentSummaryMetaGenerator {}
@@ -29,6 +30,10 @@ impl TournamentMetaGenerator for TournamentSummaryMetaGenerator {
             String::from("end_date"),
             Value::from(date_to_string(tournament.end_date.clone())),
         );
+        meta.insert(
+            String::from("brightwell_constant"),
+            Value::from(tournament.get_brightwell_constant()),
+        );
         meta
     }
 }
@@ -67,6 +72,12 @@ impl StandingMetaGenerator for StandingSummaryMetaGenerator<'_> {
             String::from("minor_score"),
             Value::from(standing.minor_score.clone()),
         );
+        meta.insert(
+            String::from("tie_breakers"),
+            Value::from(generate_tie_breaker_scores_meta(
+                &standing.tie_breaker_scores,
+            )),
+        );
         meta
     }
 }
@@ -78,6 +89,7 @@ impl RoundMetaGenerator for RoundSummaryMetaGenerator {
         let mut meta = Map::new();
         meta.insert(String::from("id"), Value::from(round.id.clone()));
         meta.insert(String::from("name"), Value::from(round.name.clone()));
+        meta.insert(String::from("section"), Value::from(round.get_section()));
         meta
     }
 }
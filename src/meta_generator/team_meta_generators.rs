@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use crate::database_models::{PlayerRowModel, TeamRowModel};
+use crate::tournament_manager::TeamStanding;
+
+pub trait TeamMetaGenerator {
+    fn generate_meta_for(&self, team: &TeamRowModel) -> Map<String, Value>;
+}
+
+pub struct DefaultTeamMetaGenerator<'a> {
+    pub players_by_id: HashMap<&'a i32, &'a PlayerRowModel>,
+}
+
+impl TeamMetaGenerator for DefaultTeamMetaGenerator<'_> {
+    fn generate_meta_for(&self, team: &TeamRowModel) -> Map<String, Value> {
+        let mut meta = Map::new();
+        meta.insert(String::from("id"), Value::from(team.id.clone()));
+        meta.insert(String::from("name"), Value::from(team.name.clone()));
+
+        let boards_meta: Vec<Value> = team
+            .get_board_player_ids()
+            .iter()
+            .enumerate()
+            .map(|(board_index, player_id)| {
+                let mut board_meta = Map::new();
+                board_meta.insert(String::from("board"), Value::from(board_index + 1));
+                board_meta.insert(String::from("player_id"), Value::from(player_id.clone()));
+                if let Some(&player) = self.players_by_id.get(player_id) {
+                    board_meta.insert(
+                        String::from("first_name"),
+                        Value::from(player.first_name.clone()),
+                    );
+                    board_meta.insert(
+                        String::from("last_name"),
+                        Value::from(player.last_name.clone()),
+                    );
+                    board_meta.insert(String::from("rating"), Value::from(player.rating.clone()));
+                }
+                Value::from(board_meta)
+            })
+            .collect();
+        meta.insert(String::from("boards"), Value::from(boards_meta));
+        meta
+    }
+}
+
+pub trait TeamStandingMetaGenerator {
+    fn generate_meta_for(&self, team_standing: &TeamStanding) -> Map<String, Value>;
+}
+
+pub struct DefaultTeamStandingMetaGenerator<'a> {
+    pub teams_by_id: HashMap<&'a i32, &'a TeamRowModel>,
+}
+
+impl TeamStandingMetaGenerator for DefaultTeamStandingMetaGenerator<'_> {
+    fn generate_meta_for(&self, team_standing: &TeamStanding) -> Map<String, Value> {
+        let mut meta = Map::new();
+        meta.insert(
+            String::from("team_id"),
+            Value::from(team_standing.team_id.clone()),
+        );
+        if let Some(&team) = self.teams_by_id.get(&team_standing.team_id) {
+            meta.insert(String::from("name"), Value::from(team.name.clone()));
+        }
+        meta.insert(
+            String::from("match_points"),
+            Value::from(team_standing.match_points.clone()),
+        );
+        meta.insert(
+            String::from("game_points"),
+            Value::from(team_standing.game_points.clone()),
+        );
+        let tie_breakers_meta: Vec<Value> = team_standing
+            .tie_breaker_scores
+            .iter()
+            .map(|(tie_breaker, score)| {
+                let mut tie_breaker_meta = Map::new();
+                tie_breaker_meta.insert(String::from("name"), Value::from(tie_breaker.to_string()));
+                tie_breaker_meta.insert(String::from("score"), Value::from(score.clone()));
+                Value::from(tie_breaker_meta)
+            })
+            .collect();
+        meta.insert(String::from("tie_breakers"), Value::from(tie_breakers_meta));
+        meta
+    }
+}
@@ -29,6 +29,10 @@ impl TournamentMetaGenerator for TournamentPreviewMetaGenerator<'_> {
             String::from("tournament_type"),
             Value::from(tournament.tournament_type.clone()),
         );
+        meta.insert(
+            String::from("status"),
+            Value::from(tournament.status.clone()),
+        );
         meta.insert(
             String::from("country"),
             Value::from(tournament.country.clone()),
@@ -84,6 +88,10 @@ impl MetaGenerator for TournamentDetailsMetaGenerator {
             String::from("tournament_type"),
             Value::from(self.tournament.tournament_type.clone()),
         );
+        meta.insert(
+            String::from("status"),
+            Value::from(self.tournament.status.clone()),
+        );
         meta.insert(
             String::from("country"),
             Value::from(self.tournament.country.clone()),
@@ -96,6 +104,76 @@ impl MetaGenerator for TournamentDetailsMetaGenerator {
             String::from("end_date"),
             Value::from(date_to_string(self.tournament.end_date.clone())),
         );
+        meta.insert(
+            String::from("joueurs_snapshot_id"),
+            Value::from(self.tournament.joueurs_snapshot_id.clone()),
+        );
+        meta.insert(
+            String::from("brightwell_constant"),
+            Value::from(self.tournament.get_brightwell_constant()),
+        );
+        meta.insert(
+            String::from("tie_breakers"),
+            Value::from(
+                self.tournament
+                    .get_tie_breakers()
+                    .iter()
+                    .map(|tie_breaker| tie_breaker.to_string())
+                    .collect::<Vec<String>>(),
+            ),
+        );
+        meta.insert(
+            String::from("pairing_constraints"),
+            Value::from(
+                self.tournament
+                    .get_pairing_constraints()
+                    .iter()
+                    .map(|pairing_constraint| pairing_constraint.to_string())
+                    .collect::<Vec<String>>(),
+            ),
+        );
+        meta.insert(
+            String::from("pairing_constraint_rounds"),
+            Value::from(self.tournament.get_pairing_constraint_rounds()),
+        );
+        meta.insert(
+            String::from("round_robin_cycles"),
+            Value::from(self.tournament.get_round_robin_cycles()),
+        );
+        meta.insert(
+            String::from("accelerated_rounds"),
+            Value::from(self.tournament.get_accelerated_rounds()),
+        );
+        meta.insert(
+            String::from("knockout_seeds"),
+            Value::from(self.tournament.get_knockout_seeds()),
+        );
+        meta.insert(
+            String::from("knockout_best_of"),
+            Value::from(self.tournament.get_knockout_best_of()),
+        );
+        meta.insert(
+            String::from("knockout_third_place_match"),
+            Value::from(self.tournament.has_knockout_third_place_match()),
+        );
+        meta.insert(
+            String::from("sections"),
+            Value::from(self.tournament.get_sections()),
+        );
+        meta.insert(
+            String::from("team_pairing_type"),
+            Value::from(self.tournament.get_team_pairing_type().to_string()),
+        );
+        meta.insert(
+            String::from("team_tie_breakers"),
+            Value::from(
+                self.tournament
+                    .get_team_tie_breakers()
+                    .iter()
+                    .map(|tie_breaker| tie_breaker.to_string())
+                    .collect::<Vec<String>>(),
+            ),
+        );
 
         let mut creator_meta = Map::new();
         creator_meta.insert(
@@ -0,0 +1,158 @@
+use crate::properties::PlayerColor;
+
+const BOARD_SIZE: i32 = 8;
+const DIRECTIONS: [(i32, i32); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+pub struct Board {
+    squares: [Option<PlayerColor>; 64],
+    next_color: PlayerColor,
+}
+
+impl Board {
+    pub fn new() -> Board {
+        let mut squares = [None; 64];
+        squares[Board::to_index(3, 3)] = Some(PlayerColor::White);
+        squares[Board::to_index(4, 4)] = Some(PlayerColor::White);
+        squares[Board::to_index(3, 4)] = Some(PlayerColor::Black);
+        squares[Board::to_index(4, 3)] = Some(PlayerColor::Black);
+        Board {
+            squares,
+            next_color: PlayerColor::Black,
+        }
+    }
+
+    pub fn to_index(row: i32, column: i32) -> usize {
+        (row * BOARD_SIZE + column) as usize
+    }
+
+    pub fn get_next_color(&self) -> PlayerColor {
+        self.next_color
+    }
+
+    pub fn count_discs(&self, color: PlayerColor) -> i32 {
+        self.squares
+            .iter()
+            .filter(|square| square == &&Some(color))
+            .count() as i32
+    }
+
+    pub fn is_game_over(&self) -> bool {
+        !self.has_legal_move(PlayerColor::Black) && !self.has_legal_move(PlayerColor::White)
+    }
+
+    pub fn has_legal_move(&self, color: PlayerColor) -> bool {
+        (0..64).any(|index| self.is_legal_move(index, color))
+    }
+
+    pub fn is_legal_move(&self, index: usize, color: PlayerColor) -> bool {
+        self.squares[index].is_none() && !self.get_flipped_indices(index, color).is_empty()
+    }
+
+    // Plays the next move, passing first when the side to move has no legal move.
+    pub fn play(&mut self, index: usize) -> bool {
+        if !self.has_legal_move(self.next_color) {
+            self.next_color = Board::get_opponent_color(self.next_color);
+        }
+        if !self.is_legal_move(index, self.next_color) {
+            return false;
+        }
+
+        self.get_flipped_indices(index, self.next_color)
+            .iter()
+            .for_each(|flipped_index| self.squares[*flipped_index] = Some(self.next_color));
+        self.squares[index] = Some(self.next_color);
+        self.next_color = Board::get_opponent_color(self.next_color);
+        true
+    }
+
+    fn get_flipped_indices(&self, index: usize, color: PlayerColor) -> Vec<usize> {
+        let row = index as i32 / BOARD_SIZE;
+        let column = index as i32 % BOARD_SIZE;
+        let opponent_color = Board::get_opponent_color(color);
+
+        let mut flipped_indices = Vec::new();
+        for (row_step, column_step) in DIRECTIONS.iter() {
+            let mut line = Vec::new();
+            let mut current_row = row + row_step;
+            let mut current_column = column + column_step;
+            while Board::is_on_board(current_row, current_column) {
+                let current_index = Board::to_index(current_row, current_column);
+                match self.squares[current_index] {
+                    Some(disc_color) if disc_color == opponent_color => line.push(current_index),
+                    Some(_) => {
+                        flipped_indices.append(&mut line);
+                        break;
+                    }
+                    None => break,
+                }
+                current_row += row_step;
+                current_column += column_step;
+            }
+        }
+        flipped_indices
+    }
+
+    fn is_on_board(row: i32, column: i32) -> bool {
+        row >= 0 && row < BOARD_SIZE && column >= 0 && column < BOARD_SIZE
+    }
+
+    fn get_opponent_color(color: PlayerColor) -> PlayerColor {
+        match color {
+            PlayerColor::Black => PlayerColor::White,
+            PlayerColor::White => PlayerColor::Black,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_board {
+        use crate::othello::Board;
+        use crate::properties::PlayerColor;
+
+        #[test]
+        fn test_initial_position() {
+            let board = Board::new();
+            assert_eq!(board.count_discs(PlayerColor::Black), 2);
+            assert_eq!(board.count_discs(PlayerColor::White), 2);
+            assert_eq!(board.get_next_color(), PlayerColor::Black);
+            assert_eq!(board.is_game_over(), false);
+        }
+
+        #[test]
+        fn test_legal_opening_moves() {
+            let board = Board::new();
+            let legal_moves: Vec<usize> = (0..64)
+                .filter(|index| board.is_legal_move(*index, PlayerColor::Black))
+                .collect();
+            assert_eq!(
+                legal_moves,
+                vec![
+                    Board::to_index(2, 3),
+                    Board::to_index(3, 2),
+                    Board::to_index(4, 5),
+                    Board::to_index(5, 4),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_play() {
+            let mut board = Board::new();
+            assert_eq!(board.play(Board::to_index(0, 0)), false);
+            assert_eq!(board.play(Board::to_index(4, 5)), true);
+            assert_eq!(board.count_discs(PlayerColor::Black), 4);
+            assert_eq!(board.count_discs(PlayerColor::White), 1);
+            assert_eq!(board.get_next_color(), PlayerColor::White);
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub use board::Board;
+pub use transcript::{normalize_transcript, replay_transcript};
+
+mod board;
+mod transcript;
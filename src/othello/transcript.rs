@@ -0,0 +1,135 @@
+use crate::errors::ErrorType;
+use crate::properties::PlayerColor;
+
+use super::Board;
+
+const COLUMNS: &str = "abcdefgh";
+const ROWS: &str = "12345678";
+
+pub fn normalize_transcript(transcript: &String) -> String {
+    transcript
+        .chars()
+        .filter(|character| !character.is_whitespace())
+        .collect::<String>()
+        .to_lowercase()
+}
+
+// Replays a transcript in f5d6 notation and returns the final (black, white) disc count.
+pub fn replay_transcript(transcript: &String) -> Result<(i32, i32), ErrorType> {
+    let normalized_transcript = normalize_transcript(transcript);
+    if normalized_transcript.len() % 2 != 0 || !normalized_transcript.is_ascii() {
+        return Err(ErrorType::InvalidTranscript(String::from(
+            "Transcript is not in f5d6 notation",
+        )));
+    }
+
+    let mut board = Board::new();
+    for (move_index, notation) in normalized_transcript
+        .as_bytes()
+        .chunks(2)
+        .map(|notation| String::from_utf8_lossy(notation).to_string())
+        .enumerate()
+    {
+        let index = match parse_move(&notation) {
+            Some(index) => index,
+            None => {
+                return Err(ErrorType::InvalidTranscript(format!(
+                    "Move {} ({}) is not a valid square",
+                    move_index + 1,
+                    notation
+                )))
+            }
+        };
+        if board.is_game_over() {
+            return Err(ErrorType::InvalidTranscript(format!(
+                "Move {} ({}) is played after the game has ended",
+                move_index + 1,
+                notation
+            )));
+        }
+        if !board.play(index) {
+            return Err(ErrorType::InvalidTranscript(format!(
+                "Move {} ({}) is illegal",
+                move_index + 1,
+                notation
+            )));
+        }
+    }
+
+    if !board.is_game_over() {
+        return Err(ErrorType::InvalidTranscript(String::from(
+            "Transcript does not finish the game",
+        )));
+    }
+    Ok((
+        board.count_discs(PlayerColor::Black),
+        board.count_discs(PlayerColor::White),
+    ))
+}
+
+fn parse_move(notation: &String) -> Option<usize> {
+    let mut characters = notation.chars();
+    let column = COLUMNS.find(characters.next()?)?;
+    let row = ROWS.find(characters.next()?)?;
+    Some(Board::to_index(row as i32, column as i32))
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_replay_transcript {
+        use crate::errors::ErrorType;
+        use crate::othello::replay_transcript;
+
+        fn is_invalid_transcript(result: Result<(i32, i32), ErrorType>) -> bool {
+            match result {
+                Err(ErrorType::InvalidTranscript(_)) => true,
+                _ => false,
+            }
+        }
+
+        #[test]
+        fn test_shortest_game() {
+            let transcript = String::from("D3 C3 B3 D2 E1 D6 D7 E3 F4");
+            assert_eq!(replay_transcript(&transcript), Ok((13, 0)));
+        }
+
+        #[test]
+        fn test_game_with_pass() {
+            let transcript = String::from(
+                "f5f4c3g6f3c5d6f2b5c4g3a6f6c2e3g4b3g7d3h4b1c7e7d2b8e6a5e2f7d7\
+                b4b2f8d8e1b7h2b6h5a4h7d1a3h6e8f1a7g8h8g2g5c6a2a8h3a1c8g1c1",
+            );
+            assert_eq!(replay_transcript(&transcript), Ok((25, 38)));
+        }
+
+        #[test]
+        fn test_illegal_move() {
+            let transcript = String::from("f5d6a1");
+            assert_eq!(is_invalid_transcript(replay_transcript(&transcript)), true);
+        }
+
+        #[test]
+        fn test_invalid_notation() {
+            assert_eq!(
+                is_invalid_transcript(replay_transcript(&String::from("f5z9"))),
+                true
+            );
+            assert_eq!(
+                is_invalid_transcript(replay_transcript(&String::from("f5d"))),
+                true
+            );
+        }
+
+        #[test]
+        fn test_unfinished_game() {
+            let transcript = String::from("f5d6c3d3c4");
+            assert_eq!(is_invalid_transcript(replay_transcript(&transcript)), true);
+        }
+
+        #[test]
+        fn test_move_after_game_end() {
+            let transcript = String::from("d3c3b3d2e1d6d7e3f4a1");
+            assert_eq!(is_invalid_transcript(replay_transcript(&transcript)), true);
+        }
+    }
+}
@@ -5,4 +5,9 @@ pub type Pairings = Vec<Box<dyn IGameMatch>>;
 
 pub trait PairingGenerator {
     fn generate_pairings(&self, round_id: &i32) -> Result<Pairings, ErrorType>;
+
+    // Points added on top of the real score while pairing, they never count in the standings.
+    fn get_virtual_points(&self, _player_id: &i32) -> f64 {
+        0.0
+    }
 }
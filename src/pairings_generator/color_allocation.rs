@@ -0,0 +1,269 @@
+use crate::properties::PlayerColor;
+use crate::tournament_manager::IResultKeeper;
+
+const MAX_COLOR_DIFFERENCE: i32 = 2;
+const MAX_SAME_COLOR_STREAK: usize = 2;
+
+#[derive(Debug, PartialEq)]
+pub struct ColorPreference {
+    pub color: Option<PlayerColor>,
+    pub is_absolute: bool,
+    pub color_difference: i32,
+}
+
+fn get_opposite_color(color: &PlayerColor) -> PlayerColor {
+    match color {
+        PlayerColor::Black => PlayerColor::White,
+        PlayerColor::White => PlayerColor::Black,
+    }
+}
+
+// A player must not play the same colour three times in a row, nor let the difference between
+// black and white games go beyond 2. Otherwise the player prefers the colour that evens out the
+// difference, then the colour that alternates with the last game.
+pub fn get_color_preference(color_history: &Vec<PlayerColor>) -> ColorPreference {
+    let black_count = color_history
+        .iter()
+        .filter(|color| color == &&PlayerColor::Black)
+        .count() as i32;
+    let color_difference = black_count - (color_history.len() as i32 - black_count);
+    let last_color = color_history.last();
+    let is_streak_full = color_history.len() >= MAX_SAME_COLOR_STREAK
+        && color_history[color_history.len() - MAX_SAME_COLOR_STREAK..]
+            .iter()
+            .all(|color| Some(color) == last_color);
+
+    if is_streak_full {
+        return ColorPreference {
+            color: last_color.map(get_opposite_color),
+            is_absolute: true,
+            color_difference,
+        };
+    }
+
+    let balancing_color = match color_difference {
+        0 => last_color.map(get_opposite_color),
+        difference if difference > 0 => Some(PlayerColor::White),
+        _ => Some(PlayerColor::Black),
+    };
+    ColorPreference {
+        color: balancing_color,
+        is_absolute: color_difference.abs() >= MAX_COLOR_DIFFERENCE,
+        color_difference,
+    }
+}
+
+pub fn is_color_compatible(
+    player_1_id: &i32,
+    player_2_id: &i32,
+    past_results: &Box<dyn IResultKeeper>,
+) -> bool {
+    let player_1_preference = get_color_preference(&past_results.get_color_history(player_1_id));
+    let player_2_preference = get_color_preference(&past_results.get_color_history(player_2_id));
+    !(player_1_preference.is_absolute
+        && player_2_preference.is_absolute
+        && player_1_preference.color == player_2_preference.color)
+}
+
+fn get_standing_rank(player_id: &i32, standings: &Vec<i32>) -> usize {
+    standings
+        .iter()
+        .position(|standing_player_id| standing_player_id == player_id)
+        .unwrap_or(standings.len())
+}
+
+pub fn get_player_1_color(
+    player_1_id: &i32,
+    player_2_id: &i32,
+    past_results: &Box<dyn IResultKeeper>,
+) -> PlayerColor {
+    let player_1_preference = get_color_preference(&past_results.get_color_history(player_1_id));
+    let player_2_preference = get_color_preference(&past_results.get_color_history(player_2_id));
+    let player_2_opposite_color = player_2_preference.color.as_ref().map(get_opposite_color);
+
+    if player_1_preference.is_absolute != player_2_preference.is_absolute {
+        return match player_1_preference.is_absolute {
+            true => player_1_preference.color.unwrap(),
+            false => player_2_opposite_color.unwrap(),
+        };
+    }
+
+    if player_1_preference.color.is_some() && player_1_preference.color == player_2_opposite_color {
+        return player_1_preference.color.unwrap();
+    }
+
+    // Both players want the same colour, so the bigger colour imbalance wins, then the higher
+    // standing.
+    let player_1_imbalance = player_1_preference.color_difference.abs();
+    let player_2_imbalance = player_2_preference.color_difference.abs();
+    let is_player_1_prioritized = match player_1_imbalance == player_2_imbalance {
+        true => {
+            let standings = past_results.get_standings();
+            get_standing_rank(player_1_id, &standings) <= get_standing_rank(player_2_id, &standings)
+        }
+        false => player_1_imbalance > player_2_imbalance,
+    };
+    let prioritized_color = match is_player_1_prioritized {
+        true => player_1_preference.color,
+        false => player_2_opposite_color,
+    };
+    prioritized_color
+        .or(player_1_preference.color)
+        .or(player_2_opposite_color)
+        .unwrap_or(PlayerColor::Black)
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_color_preference {
+        use crate::pairings_generator::color_allocation::{get_color_preference, ColorPreference};
+        use crate::properties::PlayerColor;
+
+        #[test]
+        fn test_no_history() {
+            assert_eq!(
+                get_color_preference(&vec![]),
+                ColorPreference {
+                    color: None,
+                    is_absolute: false,
+                    color_difference: 0,
+                }
+            );
+        }
+
+        #[test]
+        fn test_alternating() {
+            let preference = get_color_preference(&vec![PlayerColor::Black, PlayerColor::White]);
+            assert_eq!(preference.color, Some(PlayerColor::Black));
+            assert_eq!(preference.is_absolute, false);
+        }
+
+        #[test]
+        fn test_same_color_streak() {
+            let preference = get_color_preference(&vec![
+                PlayerColor::Black,
+                PlayerColor::Black,
+                PlayerColor::White,
+                PlayerColor::White,
+            ]);
+            assert_eq!(preference.color, Some(PlayerColor::Black));
+            assert_eq!(preference.is_absolute, true);
+            assert_eq!(preference.color_difference, 0);
+        }
+
+        #[test]
+        fn test_color_difference() {
+            let preference = get_color_preference(&vec![
+                PlayerColor::Black,
+                PlayerColor::White,
+                PlayerColor::Black,
+            ]);
+            assert_eq!(preference.color, Some(PlayerColor::White));
+            assert_eq!(preference.is_absolute, false);
+
+            let preference = get_color_preference(&vec![
+                PlayerColor::White,
+                PlayerColor::Black,
+                PlayerColor::White,
+                PlayerColor::Black,
+                PlayerColor::White,
+                PlayerColor::Black,
+                PlayerColor::White,
+                PlayerColor::White,
+            ]);
+            assert_eq!(preference.color, Some(PlayerColor::Black));
+            assert_eq!(preference.is_absolute, true);
+            assert_eq!(preference.color_difference, -2);
+        }
+    }
+
+    mod test_player_1_color {
+        use serde_json::{Map, Value};
+
+        use crate::game_match::{GameMatchCreator, IGameMatch};
+        use crate::pairings_generator::color_allocation::{
+            get_player_1_color, is_color_compatible,
+        };
+        use crate::properties::PlayerColor;
+        use crate::tournament_manager::create_result_keeper;
+
+        fn create_match(
+            round_id: i32,
+            black_player_id: i32,
+            white_player_id: i32,
+            black_score: i32,
+            white_score: i32,
+        ) -> Box<dyn IGameMatch> {
+            GameMatchCreator::create_new_finished_match(
+                &round_id,
+                &black_player_id,
+                &white_player_id,
+                &black_score,
+                &white_score,
+                &Value::from(Map::new()),
+            )
+        }
+
+        #[test]
+        fn test_no_history() {
+            let result_keeper = create_result_keeper(&vec![]);
+            assert_eq!(
+                get_player_1_color(&1, &2, &result_keeper),
+                PlayerColor::Black
+            );
+        }
+
+        #[test]
+        fn test_no_three_in_a_row() {
+            let game_matches = vec![
+                create_match(1, 3, 1, 24, 40),
+                create_match(1, 2, 4, 40, 24),
+                create_match(2, 4, 1, 24, 40),
+                create_match(2, 2, 3, 40, 24),
+            ];
+            let result_keeper = create_result_keeper(&game_matches);
+
+            assert_eq!(
+                get_player_1_color(&2, &1, &result_keeper),
+                PlayerColor::White
+            );
+            assert_eq!(
+                get_player_1_color(&1, &2, &result_keeper),
+                PlayerColor::Black
+            );
+            assert_eq!(is_color_compatible(&1, &2, &result_keeper), true);
+        }
+
+        #[test]
+        fn test_same_absolute_preference() {
+            let game_matches = vec![
+                create_match(1, 1, 3, 40, 24),
+                create_match(1, 2, 4, 24, 40),
+                create_match(2, 1, 4, 40, 24),
+                create_match(2, 2, 3, 24, 40),
+            ];
+            let result_keeper = create_result_keeper(&game_matches);
+
+            assert_eq!(is_color_compatible(&1, &2, &result_keeper), false);
+            assert_eq!(
+                get_player_1_color(&2, &1, &result_keeper),
+                PlayerColor::Black
+            );
+        }
+
+        #[test]
+        fn test_alternation_by_standing() {
+            let game_matches = vec![create_match(1, 1, 3, 40, 24), create_match(1, 2, 4, 24, 40)];
+            let result_keeper = create_result_keeper(&game_matches);
+
+            assert_eq!(
+                get_player_1_color(&2, &1, &result_keeper),
+                PlayerColor::Black
+            );
+            assert_eq!(
+                get_player_1_color(&1, &2, &result_keeper),
+                PlayerColor::White
+            );
+        }
+    }
+}
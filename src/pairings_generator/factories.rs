@@ -1,8 +1,10 @@
 use crate::database_models::PlayerRowModel;
-use crate::properties::TournamentType;
+use crate::properties::{PairingConstraint, TournamentType};
 use crate::tournament_manager::IResultKeeper;
 
-use super::{PairingGenerator, RRPairingsGenerator, SwissPairingsGenerator};
+use super::{
+    get_requested_byes_by_player_id, PairingGenerator, RRPairingsGenerator, SwissPairingsGenerator,
+};
 
 pub struct PairingsGeneratorCreator {}
 
@@ -11,14 +13,28 @@ impl PairingsGeneratorCreator {
         tournament_type: TournamentType,
         players: Vec<PlayerRowModel>,
         past_results: Box<dyn IResultKeeper>,
+        pairing_constraints: Vec<PairingConstraint>,
+        round_robin_cycles: i32,
+        is_accelerated: bool,
+        round_number: i32,
     ) -> Box<dyn PairingGenerator> {
+        let requested_byes = get_requested_byes_by_player_id(&players, &round_number);
         match tournament_type {
             TournamentType::SwissPairing => {
-                Box::from(SwissPairingsGenerator::new(players, past_results))
-            }
-            TournamentType::RoundRobin => {
-                Box::from(RRPairingsGenerator::new(players, past_results))
+                Box::from(SwissPairingsGenerator::new_with_requested_byes(
+                    players,
+                    past_results,
+                    pairing_constraints,
+                    is_accelerated,
+                    requested_byes,
+                ))
             }
+            TournamentType::RoundRobin => Box::from(RRPairingsGenerator::new_with_requested_byes(
+                players,
+                past_results,
+                round_robin_cycles,
+                requested_byes,
+            )),
             _ => unimplemented!(),
         }
     }
@@ -1,18 +1,38 @@
-use crate::properties::PlayerColor;
-use crate::tournament_manager::IResultKeeper;
+use std::collections::HashMap;
 
-pub fn get_player_1_color(
-    player_1_id: &i32,
-    player_2_id: &i32,
-    past_results: &Box<dyn IResultKeeper>,
-) -> PlayerColor {
-    let player_1_black_count = past_results.get_color_count(player_1_id, PlayerColor::Black);
-    let player_1_white_count = past_results.get_color_count(player_1_id, PlayerColor::White);
-    let player_2_black_count = past_results.get_color_count(player_2_id, PlayerColor::Black);
-    let player_2_white_count = past_results.get_color_count(player_2_id, PlayerColor::White);
+use crate::database_models::PlayerRowModel;
+use crate::properties::PairingConstraint;
+use crate::tournament_manager::RequestedBye;
 
-    if player_1_black_count + player_2_white_count > player_2_black_count + player_1_white_count {
-        return PlayerColor::White;
-    }
-    PlayerColor::Black
+pub fn get_broken_pairing_constraints(
+    player_1: &PlayerRowModel,
+    player_2: &PlayerRowModel,
+    pairing_constraints: &Vec<PairingConstraint>,
+) -> Vec<PairingConstraint> {
+    pairing_constraints
+        .iter()
+        .filter(|pairing_constraint| match pairing_constraint {
+            PairingConstraint::SameCountry => player_1.country == player_2.country,
+            PairingConstraint::SameTeam => {
+                player_1.get_team().is_some() && player_1.get_team() == player_2.get_team()
+            }
+            _ => false,
+        })
+        .cloned()
+        .collect()
+}
+
+pub fn get_requested_byes_by_player_id(
+    players: &Vec<PlayerRowModel>,
+    round_number: &i32,
+) -> HashMap<i32, RequestedBye> {
+    players
+        .iter()
+        .filter(|player| !player.is_withdrawn())
+        .filter_map(|player| {
+            player
+                .get_requested_bye(round_number)
+                .map(|requested_bye| (player.id, requested_bye))
+        })
+        .collect()
 }
@@ -0,0 +1,381 @@
+use serde_json::{Map, Value};
+
+use crate::errors::ErrorType;
+use crate::game_match::{GameMatchCreator, IGameMatch};
+
+use super::{PairingGenerator, Pairings};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct KnockoutTie {
+    pub stage: usize,
+    pub stage_name: String,
+    pub is_third_place: bool,
+    pub player_ids: (Option<i32>, Option<i32>),
+    pub scores: (f64, f64),
+    pub game_count: usize,
+    pub has_unfinished_game: bool,
+    pub winner_id: Option<i32>,
+}
+
+impl KnockoutTie {
+    pub fn get_loser_id(&self) -> Option<i32> {
+        let winner_id = self.winner_id?;
+        match self.player_ids {
+            (Some(player_1_id), Some(player_2_id)) if player_1_id == winner_id => Some(player_2_id),
+            (Some(player_1_id), Some(_)) => Some(player_1_id),
+            _ => None,
+        }
+    }
+
+    pub fn is_waiting_for_next_game(&self) -> bool {
+        self.winner_id.is_none()
+            && !self.has_unfinished_game
+            && self.player_ids.0.is_some()
+            && self.player_ids.1.is_some()
+    }
+}
+
+pub struct KnockoutPairingsGenerator {
+    seeds: Vec<i32>,
+    past_matches: Vec<Box<dyn IGameMatch>>,
+    best_of: i32,
+    has_third_place_match: bool,
+}
+
+impl KnockoutPairingsGenerator {
+    pub fn new(
+        seeds: Vec<i32>,
+        past_matches: Vec<Box<dyn IGameMatch>>,
+        best_of: i32,
+        has_third_place_match: bool,
+    ) -> KnockoutPairingsGenerator {
+        KnockoutPairingsGenerator {
+            seeds,
+            past_matches,
+            best_of,
+            has_third_place_match,
+        }
+    }
+
+    pub fn get_stage_count(&self) -> usize {
+        let mut stage_count = 0;
+        while (1 << stage_count) < self.seeds.len() {
+            stage_count += 1;
+        }
+        stage_count
+    }
+
+    pub fn get_stage_name(&self, stage: &usize) -> String {
+        let stage_count = self.get_stage_count();
+        if stage >= &stage_count {
+            return String::from("Third Place Match");
+        }
+        match stage_count - stage {
+            1 => String::from("Final"),
+            2 => String::from("Semifinal"),
+            3 => String::from("Quarterfinal"),
+            remaining_stage_count => format!("Round of {}", 1 << remaining_stage_count),
+        }
+    }
+
+    // Standard seeding keeps the top seeds apart until the last stages, e.g. 1-8, 4-5, 2-7, 3-6.
+    // Missing seeds at the bottom of the bracket become byes for the top seeds.
+    fn get_seed_positions(bracket_size: usize) -> Vec<usize> {
+        let mut seed_positions = vec![0];
+        while seed_positions.len() < bracket_size {
+            let next_size = seed_positions.len() * 2;
+            seed_positions = seed_positions
+                .iter()
+                .flat_map(|seed_position| vec![*seed_position, next_size - 1 - seed_position])
+                .collect();
+        }
+        seed_positions
+    }
+
+    fn get_seed_rank(&self, player_id: &i32) -> usize {
+        self.seeds
+            .iter()
+            .position(|seed_player_id| seed_player_id == player_id)
+            .unwrap_or(self.seeds.len())
+    }
+
+    fn create_tie(
+        &self,
+        stage: usize,
+        is_third_place: bool,
+        player_ids: (Option<i32>, Option<i32>),
+    ) -> KnockoutTie {
+        let mut tie = KnockoutTie {
+            stage,
+            stage_name: self.get_stage_name(&stage),
+            is_third_place,
+            player_ids,
+            scores: (0.0, 0.0),
+            game_count: 0,
+            has_unfinished_game: false,
+            winner_id: None,
+        };
+
+        match player_ids {
+            (Some(player_1_id), Some(player_2_id)) => {
+                let (higher_seed_id, lower_seed_id) =
+                    match self.get_seed_rank(&player_1_id) <= self.get_seed_rank(&player_2_id) {
+                        true => (player_1_id, player_2_id),
+                        false => (player_2_id, player_1_id),
+                    };
+                tie.player_ids = (Some(higher_seed_id), Some(lower_seed_id));
+
+                let games: Vec<&Box<dyn IGameMatch>> = self
+                    .past_matches
+                    .iter()
+                    .filter(|game_match| {
+                        game_match.get_opponent_id(&higher_seed_id) == Some(lower_seed_id)
+                    })
+                    .collect();
+                let finished_games: Vec<&&Box<dyn IGameMatch>> =
+                    games.iter().filter(|game| game.is_finished()).collect();
+                tie.scores = finished_games.iter().fold((0.0, 0.0), |scores, game| {
+                    (
+                        scores.0 + game.calculate_major_score(&higher_seed_id),
+                        scores.1 + game.calculate_major_score(&lower_seed_id),
+                    )
+                });
+                tie.game_count = games.len();
+                tie.has_unfinished_game = finished_games.len() < games.len();
+
+                // A tie needs more than half of the games to be won. Once all games are played,
+                // the leader wins, otherwise the tie goes on until somebody wins a game.
+                let winning_score = self.best_of as f64 / 2.0;
+                let is_all_games_played = finished_games.len() >= self.best_of as usize;
+                if tie.scores.0 > winning_score
+                    || (is_all_games_played && tie.scores.0 > tie.scores.1)
+                {
+                    tie.winner_id = Some(higher_seed_id);
+                }
+                if tie.scores.1 > winning_score
+                    || (is_all_games_played && tie.scores.1 > tie.scores.0)
+                {
+                    tie.winner_id = Some(lower_seed_id);
+                }
+            }
+            (Some(player_id), None) | (None, Some(player_id)) => {
+                tie.player_ids = (Some(player_id), None);
+                if stage == 0 {
+                    tie.winner_id = Some(player_id);
+                }
+            }
+            (None, None) => {}
+        }
+        tie
+    }
+
+    pub fn get_bracket(&self) -> Vec<KnockoutTie> {
+        if self.seeds.len() < 2 {
+            return vec![];
+        }
+
+        let stage_count = self.get_stage_count();
+        let mut stage_ties: Vec<KnockoutTie> =
+            KnockoutPairingsGenerator::get_seed_positions(1 << stage_count)
+                .chunks(2)
+                .map(|seed_positions| {
+                    self.create_tie(
+                        0,
+                        false,
+                        (
+                            self.seeds.get(seed_positions[0]).cloned(),
+                            self.seeds.get(seed_positions[1]).cloned(),
+                        ),
+                    )
+                })
+                .collect();
+
+        let mut bracket = Vec::new();
+        let mut third_place_tie = None;
+        for stage in 1..stage_count {
+            let next_stage_ties = stage_ties
+                .chunks(2)
+                .map(|ties| self.create_tie(stage, false, (ties[0].winner_id, ties[1].winner_id)))
+                .collect();
+            if stage == stage_count - 1 && self.has_third_place_match {
+                third_place_tie = Some(self.create_tie(
+                    stage_count,
+                    true,
+                    (stage_ties[0].get_loser_id(), stage_ties[1].get_loser_id()),
+                ));
+            }
+            bracket.append(&mut stage_ties);
+            stage_ties = next_stage_ties;
+        }
+        bracket.append(&mut stage_ties);
+        bracket.extend(third_place_tie);
+        bracket
+    }
+
+    pub fn get_pending_ties(&self) -> Vec<KnockoutTie> {
+        self.get_bracket()
+            .into_iter()
+            .filter(|tie| tie.is_waiting_for_next_game())
+            .collect()
+    }
+
+    // Colours alternate within a tie, starting with black for the higher seed.
+    pub fn create_next_game(&self, tie: &KnockoutTie, round_id: &i32) -> Box<dyn IGameMatch> {
+        let higher_seed_id = tie.player_ids.0.unwrap();
+        let lower_seed_id = tie.player_ids.1.unwrap();
+        let (black_player_id, white_player_id) = match tie.game_count % 2 {
+            0 => (higher_seed_id, lower_seed_id),
+            _ => (lower_seed_id, higher_seed_id),
+        };
+        GameMatchCreator::create_new_match(
+            round_id,
+            &black_player_id,
+            &white_player_id,
+            &Value::from(Map::new()),
+        )
+    }
+}
+
+impl PairingGenerator for KnockoutPairingsGenerator {
+    fn generate_pairings(&self, round_id: &i32) -> Result<Pairings, ErrorType> {
+        let pending_ties = self.get_pending_ties();
+        let earliest_stage = match pending_ties.iter().map(|tie| tie.stage).min() {
+            Some(stage) => stage,
+            None => return Err(ErrorType::AutomaticPairingError),
+        };
+        Ok(pending_ties
+            .iter()
+            .filter(|tie| tie.stage == earliest_stage)
+            .map(|tie| self.create_next_game(tie, round_id))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_knockout_pairing {
+        use serde_json::{Map, Value};
+
+        use crate::game_match::{GameMatchCreator, IGameMatch};
+        use crate::pairings_generator::{KnockoutPairingsGenerator, PairingGenerator, Pairings};
+
+        fn create_finished_match(
+            black_player_id: i32,
+            white_player_id: i32,
+            black_score: i32,
+            white_score: i32,
+        ) -> Box<dyn IGameMatch> {
+            GameMatchCreator::create_new_finished_match(
+                &0,
+                &black_player_id,
+                &white_player_id,
+                &black_score,
+                &white_score,
+                &Value::from(Map::new()),
+            )
+        }
+
+        fn get_pairings_id(pairings: &Pairings) -> Vec<(Option<i32>, Option<i32>)> {
+            pairings
+                .iter()
+                .map(|pairing| pairing.get_players_id())
+                .collect()
+        }
+
+        #[test]
+        fn test_first_stage_with_byes() {
+            let pairings_generator =
+                KnockoutPairingsGenerator::new(vec![1, 2, 3, 4, 5, 6], vec![], 1, false);
+            assert_eq!(pairings_generator.get_stage_count(), 3);
+            assert_eq!(pairings_generator.get_stage_name(&0), "Quarterfinal");
+
+            let bracket = pairings_generator.get_bracket();
+            assert_eq!(bracket.len(), 7);
+            assert_eq!(bracket[0].player_ids, (Some(1), None));
+            assert_eq!(bracket[0].winner_id, Some(1));
+            assert_eq!(bracket[4].player_ids, (Some(1), None));
+            assert_eq!(bracket[4].stage_name, "Semifinal");
+
+            let pairings = pairings_generator.generate_pairings(&0).unwrap();
+            assert_eq!(
+                get_pairings_id(&pairings),
+                vec![(Some(4), Some(5)), (Some(3), Some(6))]
+            );
+        }
+
+        #[test]
+        fn test_winner_advances() {
+            let past_matches = vec![
+                create_finished_match(4, 5, 20, 44),
+                create_finished_match(3, 6, 40, 24),
+            ];
+            let pairings_generator =
+                KnockoutPairingsGenerator::new(vec![1, 2, 3, 4, 5, 6], past_matches, 1, false);
+
+            let pairings = pairings_generator.generate_pairings(&0).unwrap();
+            assert_eq!(
+                get_pairings_id(&pairings),
+                vec![(Some(1), Some(5)), (Some(2), Some(3))]
+            );
+        }
+
+        #[test]
+        fn test_best_of_three() {
+            let past_matches = vec![create_finished_match(1, 2, 40, 24)];
+            let pairings_generator =
+                KnockoutPairingsGenerator::new(vec![1, 2], past_matches, 3, false);
+            let pairings = pairings_generator.generate_pairings(&0).unwrap();
+            assert_eq!(get_pairings_id(&pairings), vec![(Some(2), Some(1))]);
+
+            let past_matches = vec![
+                create_finished_match(1, 2, 40, 24),
+                create_finished_match(2, 1, 40, 24),
+                create_finished_match(1, 2, 32, 32),
+            ];
+            let pairings_generator =
+                KnockoutPairingsGenerator::new(vec![1, 2], past_matches, 3, false);
+            let pairings = pairings_generator.generate_pairings(&0).unwrap();
+            assert_eq!(get_pairings_id(&pairings), vec![(Some(2), Some(1))]);
+
+            let past_matches = vec![
+                create_finished_match(1, 2, 40, 24),
+                create_finished_match(2, 1, 24, 40),
+            ];
+            let pairings_generator =
+                KnockoutPairingsGenerator::new(vec![1, 2], past_matches, 3, false);
+            assert_eq!(pairings_generator.get_bracket()[0].winner_id, Some(1));
+            assert_eq!(pairings_generator.generate_pairings(&0).is_err(), true);
+        }
+
+        #[test]
+        fn test_unfinished_game_pending() {
+            let past_matches = vec![GameMatchCreator::create_new_match(
+                &0,
+                &1,
+                &2,
+                &Value::from(Map::new()),
+            )];
+            let pairings_generator =
+                KnockoutPairingsGenerator::new(vec![1, 2], past_matches, 1, false);
+            assert_eq!(pairings_generator.generate_pairings(&0).is_err(), true);
+        }
+
+        #[test]
+        fn test_third_place_match() {
+            let past_matches = vec![
+                create_finished_match(1, 4, 40, 24),
+                create_finished_match(2, 3, 24, 40),
+            ];
+            let pairings_generator =
+                KnockoutPairingsGenerator::new(vec![1, 2, 3, 4], past_matches, 1, true);
+
+            let pending_ties = pairings_generator.get_pending_ties();
+            assert_eq!(pending_ties.len(), 2);
+            assert_eq!(pending_ties[0].stage_name, "Final");
+            assert_eq!(pending_ties[0].player_ids, (Some(1), Some(3)));
+            assert_eq!(pending_ties[1].stage_name, "Third Place Match");
+            assert_eq!(pending_ties[1].is_third_place, true);
+            assert_eq!(pending_ties[1].player_ids, (Some(2), Some(4)));
+        }
+    }
+}
@@ -1,11 +1,21 @@
 pub use abstract_pairings_generator::{PairingGenerator, Pairings};
+pub use color_allocation::{get_player_1_color, is_color_compatible};
 pub use factories::PairingsGeneratorCreator;
-pub use helpers::get_player_1_color;
+pub use helpers::{get_broken_pairing_constraints, get_requested_byes_by_player_id};
+pub use knockout_pairings_generator::{KnockoutPairingsGenerator, KnockoutTie};
+pub use pairings_preview::{generate_pairings_preview, PairedPlayerPreview, PairingPreview};
+use perfect_matching::PerfectMatching;
 pub use rr_pairings_generator::RRPairingsGenerator;
 pub use swiss_pairings_generator::SwissPairingsGenerator;
+pub use team_pairings::{create_team_players, expand_team_pairings};
 
 mod abstract_pairings_generator;
+mod color_allocation;
 mod factories;
 mod helpers;
+mod knockout_pairings_generator;
+mod pairings_preview;
+mod perfect_matching;
 mod rr_pairings_generator;
 mod swiss_pairings_generator;
+mod team_pairings;
@@ -0,0 +1,319 @@
+use std::collections::HashMap;
+
+use crate::database_models::PlayerRowModel;
+use crate::properties::{FloatDirection, FloatReason, PairingConstraint, PlayerColor};
+use crate::tournament_manager::IResultKeeper;
+
+use super::{get_broken_pairing_constraints, Pairings};
+
+pub struct PairedPlayerPreview {
+    pub player_id: i32,
+    pub color: Option<PlayerColor>,
+    pub score_group: f64,
+    pub virtual_points: f64,
+    pub float: Option<(FloatDirection, FloatReason)>,
+}
+
+pub struct PairingPreview {
+    pub player: PairedPlayerPreview,
+    pub opponent: Option<PairedPlayerPreview>,
+    pub broken_constraints: Vec<PairingConstraint>,
+}
+
+// A pairing between different score groups floats the higher player down and the lower player
+// up. The float is blamed on the higher score group: it either has an odd number of players left
+// to pair, or all of its remaining players have already met each other. Score groups include the
+// virtual points used for pairing.
+pub fn generate_pairings_preview(
+    pairings: &Pairings,
+    past_results: &Box<dyn IResultKeeper>,
+    players: &Vec<PlayerRowModel>,
+    pairing_constraints: &Vec<PairingConstraint>,
+    virtual_points_by_player_id: &HashMap<i32, f64>,
+) -> Vec<PairingPreview> {
+    let players_by_id: HashMap<&i32, &PlayerRowModel> =
+        players.iter().map(|player| (&player.id, player)).collect();
+    let major_scores_by_player_id: HashMap<i32, f64> = past_results
+        .get_detailed_standings()
+        .into_iter()
+        .map(|standing| (standing.player_id, standing.major_score))
+        .collect();
+    let get_virtual_points = |player_id: &i32| {
+        virtual_points_by_player_id
+            .get(player_id)
+            .unwrap_or(&0.0)
+            .clone()
+    };
+    let get_score_group = |player_id: &i32| {
+        major_scores_by_player_id.get(player_id).unwrap_or(&0.0) + get_virtual_points(player_id)
+    };
+
+    let paired_scores: Vec<(f64, f64)> = pairings
+        .iter()
+        .filter_map(|game_match| match game_match.get_players_id() {
+            (Some(player_1_id), Some(player_2_id)) => {
+                Some((get_score_group(&player_1_id), get_score_group(&player_2_id)))
+            }
+            _ => None,
+        })
+        .collect();
+    let get_float_reason = |score_group: &f64| {
+        let group_player_count = paired_scores
+            .iter()
+            .map(|(score_1, score_2)| {
+                (score_1 == score_group) as usize + (score_2 == score_group) as usize
+            })
+            .sum::<usize>();
+        let floated_in_count = paired_scores
+            .iter()
+            .filter(|(score_1, score_2)| {
+                (score_1 == score_group && score_2 > score_group)
+                    || (score_2 == score_group && score_1 > score_group)
+            })
+            .count();
+        match (group_player_count + floated_in_count) % 2 {
+            1 => FloatReason::OddScoreGroup,
+            _ => FloatReason::RematchAvoidance,
+        }
+    };
+
+    pairings
+        .iter()
+        .filter_map(|game_match| {
+            let (player_id, opponent_id) = game_match.get_players_id();
+            let player_id = player_id?;
+            let player_score_group = get_score_group(&player_id);
+            let mut player = PairedPlayerPreview {
+                player_id,
+                color: game_match.get_player_color(&player_id),
+                score_group: player_score_group,
+                virtual_points: get_virtual_points(&player_id),
+                float: None,
+            };
+            let opponent = opponent_id.map(|opponent_id| {
+                let opponent_score_group = get_score_group(&opponent_id);
+                let mut opponent = PairedPlayerPreview {
+                    player_id: opponent_id,
+                    color: game_match.get_player_color(&opponent_id),
+                    score_group: opponent_score_group,
+                    virtual_points: get_virtual_points(&opponent_id),
+                    float: None,
+                };
+                if player_score_group > opponent_score_group {
+                    let float_reason = get_float_reason(&player_score_group);
+                    player.float = Some((FloatDirection::Down, float_reason));
+                    opponent.float = Some((FloatDirection::Up, float_reason));
+                }
+                if opponent_score_group > player_score_group {
+                    let float_reason = get_float_reason(&opponent_score_group);
+                    player.float = Some((FloatDirection::Up, float_reason));
+                    opponent.float = Some((FloatDirection::Down, float_reason));
+                }
+                opponent
+            });
+            let broken_constraints = match opponent_id {
+                Some(opponent_id) => {
+                    match (
+                        players_by_id.get(&player_id),
+                        players_by_id.get(&opponent_id),
+                    ) {
+                        (Some(&player), Some(&opponent)) => {
+                            get_broken_pairing_constraints(player, opponent, pairing_constraints)
+                        }
+                        _ => vec![],
+                    }
+                }
+                None => vec![],
+            };
+            Some(PairingPreview {
+                player,
+                opponent,
+                broken_constraints,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_pairings_preview {
+        use std::collections::HashMap;
+
+        use serde_json::{Map, Value};
+
+        use crate::database_models::PlayerRowModel;
+        use crate::game_match::{GameMatchCreator, IGameMatch};
+        use crate::pairings_generator::generate_pairings_preview;
+        use crate::properties::{FloatDirection, FloatReason, PairingConstraint, PlayerColor};
+        use crate::tournament_manager::create_result_keeper;
+
+        fn create_finished_match(
+            black_player_id: i32,
+            white_player_id: i32,
+            black_score: i32,
+            white_score: i32,
+        ) -> Box<dyn IGameMatch> {
+            GameMatchCreator::create_new_finished_match(
+                &0,
+                &black_player_id,
+                &white_player_id,
+                &black_score,
+                &white_score,
+                &Value::from(Map::new()),
+            )
+        }
+
+        fn create_match(black_player_id: i32, white_player_id: i32) -> Box<dyn IGameMatch> {
+            GameMatchCreator::create_new_match(
+                &0,
+                &black_player_id,
+                &white_player_id,
+                &Value::from(Map::new()),
+            )
+        }
+
+        #[test]
+        fn test_odd_score_group() {
+            let past_matches = vec![
+                create_finished_match(1, 2, 40, 24),
+                create_finished_match(3, 4, 40, 24),
+                create_finished_match(5, 6, 40, 24),
+            ];
+            let result_keeper = create_result_keeper(&past_matches);
+            let pairings = vec![create_match(1, 3), create_match(2, 5), create_match(4, 6)];
+
+            let preview = generate_pairings_preview(
+                &pairings,
+                &result_keeper,
+                &vec![],
+                &vec![],
+                &HashMap::new(),
+            );
+            assert_eq!(preview.len(), 3);
+            assert_eq!(preview[0].player.color, Some(PlayerColor::Black));
+            assert_eq!(preview[0].player.score_group, 1.0);
+            assert_eq!(preview[0].player.float, None);
+
+            let floated_up_player = &preview[1].player;
+            let floated_down_player = preview[1].opponent.as_ref().unwrap();
+            assert_eq!(
+                floated_up_player.float,
+                Some((FloatDirection::Up, FloatReason::OddScoreGroup))
+            );
+            assert_eq!(
+                floated_down_player.float,
+                Some((FloatDirection::Down, FloatReason::OddScoreGroup))
+            );
+            assert_eq!(preview[2].opponent.as_ref().unwrap().float, None);
+        }
+
+        #[test]
+        fn test_rematch_avoidance() {
+            let past_matches = vec![
+                create_finished_match(1, 2, 40, 24),
+                create_finished_match(3, 4, 40, 24),
+                create_finished_match(1, 3, 32, 32),
+                create_finished_match(2, 4, 32, 32),
+            ];
+            let result_keeper = create_result_keeper(&past_matches);
+            let pairings = vec![create_match(1, 4), create_match(2, 3)];
+
+            let preview = generate_pairings_preview(
+                &pairings,
+                &result_keeper,
+                &vec![],
+                &vec![],
+                &HashMap::new(),
+            );
+            assert_eq!(
+                preview[0].player.float,
+                Some((FloatDirection::Down, FloatReason::RematchAvoidance))
+            );
+            assert_eq!(
+                preview[1].player.float,
+                Some((FloatDirection::Up, FloatReason::RematchAvoidance))
+            );
+        }
+
+        #[test]
+        fn test_virtual_points() {
+            let past_matches = vec![
+                create_finished_match(1, 3, 40, 24),
+                create_finished_match(2, 4, 40, 24),
+            ];
+            let result_keeper = create_result_keeper(&past_matches);
+            let pairings = vec![create_match(1, 2), create_match(3, 4)];
+            let virtual_points_by_player_id: HashMap<i32, f64> =
+                vec![(1, 1.0), (3, 1.0)].into_iter().collect();
+
+            let preview = generate_pairings_preview(
+                &pairings,
+                &result_keeper,
+                &vec![],
+                &vec![],
+                &virtual_points_by_player_id,
+            );
+            assert_eq!(preview[0].player.score_group, 2.0);
+            assert_eq!(preview[0].player.virtual_points, 1.0);
+            assert_eq!(preview[0].opponent.as_ref().unwrap().score_group, 1.0);
+            assert_eq!(
+                preview[0].player.float,
+                Some((FloatDirection::Down, FloatReason::OddScoreGroup))
+            );
+            assert_eq!(preview[1].player.score_group, 1.0);
+            assert_eq!(preview[1].opponent.as_ref().unwrap().virtual_points, 0.0);
+        }
+
+        #[test]
+        fn test_bye() {
+            let result_keeper = create_result_keeper(&vec![]);
+            let pairings = vec![
+                create_match(1, 2),
+                GameMatchCreator::create_new_bye_match(&0, &3, &Value::from(Map::new())),
+            ];
+
+            let preview = generate_pairings_preview(
+                &pairings,
+                &result_keeper,
+                &vec![],
+                &vec![],
+                &HashMap::new(),
+            );
+            assert_eq!(preview[1].player.player_id, 3);
+            assert_eq!(preview[1].player.color, None);
+            assert_eq!(preview[1].opponent.is_none(), true);
+        }
+
+        #[test]
+        fn test_broken_constraints() {
+            let players: Vec<PlayerRowModel> = vec![(1, "SGP"), (2, "SGP"), (3, "MYS"), (4, "IDN")]
+                .into_iter()
+                .map(|(id, country)| PlayerRowModel {
+                    id,
+                    tournament_id: 0,
+                    joueurs_id: id.to_string(),
+                    first_name: String::from("First"),
+                    last_name: String::from("Last"),
+                    country: String::from(country),
+                    rating: 1500,
+                    meta_data: Value::from(Map::new()),
+                })
+                .collect();
+            let result_keeper = create_result_keeper(&vec![]);
+            let pairings = vec![create_match(1, 2), create_match(3, 4)];
+
+            let preview = generate_pairings_preview(
+                &pairings,
+                &result_keeper,
+                &players,
+                &vec![PairingConstraint::SameCountry, PairingConstraint::SameTeam],
+                &HashMap::new(),
+            );
+            assert_eq!(
+                preview[0].broken_constraints,
+                vec![PairingConstraint::SameCountry]
+            );
+            assert_eq!(preview[1].broken_constraints, vec![]);
+        }
+    }
+}
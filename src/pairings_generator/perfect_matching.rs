@@ -0,0 +1,247 @@
+use std::collections::VecDeque;
+
+// Maintains a perfect matching of a general graph (Edmonds' blossom algorithm), so that pairs can
+// be fixed one at a time while checking that the rest of the graph can still be fully paired.
+#[derive(Clone)]
+pub struct PerfectMatching {
+    adjacency: Vec<Vec<bool>>,
+    mate: Vec<Option<usize>>,
+    removed: Vec<bool>,
+}
+
+impl PerfectMatching {
+    pub fn new(adjacency: Vec<Vec<bool>>) -> Option<PerfectMatching> {
+        let vertex_count = adjacency.len();
+        let mut perfect_matching = PerfectMatching {
+            adjacency,
+            mate: vec![None; vertex_count],
+            removed: vec![false; vertex_count],
+        };
+
+        for vertex in 0..vertex_count {
+            if perfect_matching.mate[vertex].is_none() && !perfect_matching.augment_from(vertex) {
+                return None;
+            }
+        }
+        Some(perfect_matching)
+    }
+
+    pub fn is_removed(&self, vertex: &usize) -> bool {
+        self.removed[*vertex]
+    }
+
+    // Removes both vertices from the graph if they are adjacent and the remaining vertices can
+    // still be perfectly matched, otherwise leaves the matching untouched.
+    pub fn try_fix_pair(&mut self, vertex_1: &usize, vertex_2: &usize) -> bool {
+        if !self.is_edge(vertex_1, vertex_2) {
+            return false;
+        }
+
+        if self.mate[*vertex_1] == Some(*vertex_2) {
+            self.remove_pair(vertex_1, vertex_2);
+            return true;
+        }
+
+        let previous_mate = self.mate.clone();
+        let vertex_1_mate = self.mate[*vertex_1].unwrap();
+        let vertex_2_mate = self.mate[*vertex_2].unwrap();
+        self.remove_pair(vertex_1, vertex_2);
+        self.mate[vertex_1_mate] = None;
+        self.mate[vertex_2_mate] = None;
+
+        if self.augment_from(vertex_1_mate) {
+            return true;
+        }
+
+        self.mate = previous_mate;
+        self.removed[*vertex_1] = false;
+        self.removed[*vertex_2] = false;
+        false
+    }
+
+    pub fn can_fix_pair(&self, vertex_1: &usize, vertex_2: &usize) -> bool {
+        self.clone().try_fix_pair(vertex_1, vertex_2)
+    }
+
+    fn remove_pair(&mut self, vertex_1: &usize, vertex_2: &usize) {
+        self.removed[*vertex_1] = true;
+        self.removed[*vertex_2] = true;
+        self.mate[*vertex_1] = None;
+        self.mate[*vertex_2] = None;
+    }
+
+    fn is_edge(&self, vertex_1: &usize, vertex_2: &usize) -> bool {
+        vertex_1 != vertex_2
+            && !self.removed[*vertex_1]
+            && !self.removed[*vertex_2]
+            && self.adjacency[*vertex_1][*vertex_2]
+    }
+
+    fn augment_from(&mut self, root: usize) -> bool {
+        let vertex_count = self.adjacency.len();
+        let mut used = vec![false; vertex_count];
+        let mut parent: Vec<Option<usize>> = vec![None; vertex_count];
+        let mut base: Vec<usize> = (0..vertex_count).collect();
+
+        used[root] = true;
+        let mut queue = VecDeque::new();
+        queue.push_back(root);
+
+        while let Some(vertex) = queue.pop_front() {
+            for to in 0..vertex_count {
+                if !self.is_edge(&vertex, &to)
+                    || base[vertex] == base[to]
+                    || self.mate[vertex] == Some(to)
+                {
+                    continue;
+                }
+
+                let is_blossom = to == root
+                    || match self.mate[to] {
+                        Some(to_mate) => parent[to_mate].is_some(),
+                        None => false,
+                    };
+
+                if is_blossom {
+                    let current_base = self.find_lowest_common_ancestor(&base, &parent, vertex, to);
+                    let mut blossom = vec![false; vertex_count];
+                    self.mark_path(&mut blossom, &base, &mut parent, vertex, current_base, to);
+                    self.mark_path(&mut blossom, &base, &mut parent, to, current_base, vertex);
+                    for i in 0..vertex_count {
+                        if blossom[base[i]] {
+                            base[i] = current_base;
+                            if !used[i] {
+                                used[i] = true;
+                                queue.push_back(i);
+                            }
+                        }
+                    }
+                } else if parent[to].is_none() {
+                    parent[to] = Some(vertex);
+                    match self.mate[to] {
+                        Some(to_mate) => {
+                            used[to_mate] = true;
+                            queue.push_back(to_mate);
+                        }
+                        None => {
+                            self.apply_augmenting_path(&parent, to);
+                            return true;
+                        }
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    fn find_lowest_common_ancestor(
+        &self,
+        base: &Vec<usize>,
+        parent: &Vec<Option<usize>>,
+        vertex_1: usize,
+        vertex_2: usize,
+    ) -> usize {
+        let mut visited = vec![false; self.adjacency.len()];
+
+        let mut current = vertex_1;
+        loop {
+            current = base[current];
+            visited[current] = true;
+            match self.mate[current] {
+                Some(current_mate) => current = parent[current_mate].unwrap(),
+                None => break,
+            }
+        }
+
+        let mut current = vertex_2;
+        loop {
+            current = base[current];
+            if visited[current] {
+                return current;
+            }
+            current = parent[self.mate[current].unwrap()].unwrap();
+        }
+    }
+
+    fn mark_path(
+        &self,
+        blossom: &mut Vec<bool>,
+        base: &Vec<usize>,
+        parent: &mut Vec<Option<usize>>,
+        vertex: usize,
+        blossom_base: usize,
+        child: usize,
+    ) {
+        let mut current = vertex;
+        let mut child = child;
+        while base[current] != blossom_base {
+            let current_mate = self.mate[current].unwrap();
+            blossom[base[current]] = true;
+            blossom[base[current_mate]] = true;
+            parent[current] = Some(child);
+            child = current_mate;
+            current = parent[current_mate].unwrap();
+        }
+    }
+
+    fn apply_augmenting_path(&mut self, parent: &Vec<Option<usize>>, end: usize) {
+        let mut current = Some(end);
+        while let Some(vertex) = current {
+            let previous = parent[vertex].unwrap();
+            let next = self.mate[previous];
+            self.mate[vertex] = Some(previous);
+            self.mate[previous] = Some(vertex);
+            current = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_perfect_matching {
+        use crate::pairings_generator::perfect_matching::PerfectMatching;
+
+        fn create_adjacency(vertex_count: usize, edges: Vec<(usize, usize)>) -> Vec<Vec<bool>> {
+            let mut adjacency = vec![vec![false; vertex_count]; vertex_count];
+            edges.iter().for_each(|(vertex_1, vertex_2)| {
+                adjacency[*vertex_1][*vertex_2] = true;
+                adjacency[*vertex_2][*vertex_1] = true;
+            });
+            adjacency
+        }
+
+        #[test]
+        fn test_no_perfect_matching() {
+            let adjacency = create_adjacency(4, vec![(0, 1), (0, 2), (0, 3)]);
+            assert_eq!(PerfectMatching::new(adjacency).is_none(), true);
+        }
+
+        #[test]
+        fn test_odd_cycle() {
+            // Two triangles joined by a single edge only match through a blossom.
+            let adjacency = create_adjacency(
+                6,
+                vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)],
+            );
+            let mut perfect_matching = PerfectMatching::new(adjacency).unwrap();
+
+            assert_eq!(perfect_matching.try_fix_pair(&0, &2), false);
+            assert_eq!(perfect_matching.try_fix_pair(&0, &1), true);
+            assert_eq!(perfect_matching.is_removed(&0), true);
+            assert_eq!(perfect_matching.is_removed(&1), true);
+            assert_eq!(perfect_matching.try_fix_pair(&2, &4), false);
+            assert_eq!(perfect_matching.try_fix_pair(&2, &3), true);
+            assert_eq!(perfect_matching.try_fix_pair(&4, &5), true);
+        }
+
+        #[test]
+        fn test_fix_pair_outside_current_matching() {
+            let adjacency = create_adjacency(4, vec![(0, 1), (2, 3), (0, 2), (1, 3)]);
+            let mut perfect_matching = PerfectMatching::new(adjacency).unwrap();
+
+            assert_eq!(perfect_matching.try_fix_pair(&0, &3), false);
+            assert_eq!(perfect_matching.try_fix_pair(&0, &2), true);
+            assert_eq!(perfect_matching.try_fix_pair(&1, &3), true);
+        }
+    }
+}
@@ -1,17 +1,20 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
 use serde_json::{Map, Value};
 
 use crate::database_models::PlayerRowModel;
 use crate::errors::ErrorType;
 use crate::game_match::{GameMatchCreator, IGameMatch};
-use crate::properties::PlayerColor;
-use crate::tournament_manager::IResultKeeper;
-use crate::utils::generate_random_number_ranged;
+use crate::tournament_manager::{IResultKeeper, RequestedBye};
 
-use super::{get_player_1_color, PairingGenerator, Pairings};
+use super::{PairingGenerator, Pairings};
 
 pub struct RRPairingsGenerator {
     players: Vec<PlayerRowModel>,
     past_results: Box<dyn IResultKeeper>,
+    cycle_count: i32,
+    requested_byes: HashMap<i32, RequestedBye>,
 }
 
 impl RRPairingsGenerator {
@@ -19,176 +22,210 @@ impl RRPairingsGenerator {
         players: Vec<PlayerRowModel>,
         past_results: Box<dyn IResultKeeper>,
     ) -> RRPairingsGenerator {
-        RRPairingsGenerator {
+        RRPairingsGenerator::new_with_cycles(players, past_results, 1)
+    }
+
+    pub fn new_with_cycles(
+        players: Vec<PlayerRowModel>,
+        past_results: Box<dyn IResultKeeper>,
+        cycle_count: i32,
+    ) -> RRPairingsGenerator {
+        RRPairingsGenerator::new_with_requested_byes(
             players,
             past_results,
-        }
+            cycle_count,
+            HashMap::new(),
+        )
     }
 
-    fn generate_rr_pairings(&self, round_id: &i32, shift: &i32) -> Pairings {
-        let bye_player = self.generate_bye_player();
-
-        let mut player_1_vec = self.players.iter().collect::<Vec<&PlayerRowModel>>();
-        if player_1_vec.len() % 2 == 1 {
-            player_1_vec.push(&bye_player);
+    pub fn new_with_requested_byes(
+        players: Vec<PlayerRowModel>,
+        past_results: Box<dyn IResultKeeper>,
+        cycle_count: i32,
+        requested_byes: HashMap<i32, RequestedBye>,
+    ) -> RRPairingsGenerator {
+        RRPairingsGenerator {
+            players,
+            past_results,
+            cycle_count,
+            requested_byes,
         }
+    }
 
-        let mut remaining_players = player_1_vec.split_off(1);
-        let splitted_players = remaining_players.split_off(*shift as usize);
-        let mut shifted_players: Vec<&PlayerRowModel> =
-            [player_1_vec, splitted_players, remaining_players].concat();
-
-        let midpoint = (shifted_players.len() / 2) as usize;
-        let mut second_part_sorted_players = shifted_players.split_off(midpoint);
-        second_part_sorted_players.reverse(); // For the rr algorithm to work
-        let mut second_part_players_iter = second_part_sorted_players.iter();
-
-        let mut matches = Vec::new();
-        for player_1 in shifted_players {
-            let &player_2 = second_part_players_iter.next().unwrap();
-            if player_1 == &bye_player {
-                matches.push(self.generate_bye_match(round_id, player_2));
-                continue;
-            }
+    pub fn get_round_count(&self) -> usize {
+        self.get_berger_numbered_player_ids()
+            .len()
+            .saturating_sub(1)
+            * self.cycle_count as usize
+    }
 
-            if player_2 == &bye_player {
-                matches.push(self.generate_bye_match(round_id, player_1));
-                continue;
-            }
+    pub fn generate_schedule(&self) -> Vec<Pairings> {
+        (0..self.get_round_count())
+            .map(|round_index| self.generate_scheduled_pairings(&round_index, &0))
+            .collect()
+    }
 
-            matches.push(self.generate_match(round_id, player_1, player_2));
+    // Players are numbered by registration order, an odd player count gets an empty last number
+    // and whoever meets it has a bye.
+    fn get_berger_numbered_player_ids(&self) -> Vec<Option<i32>> {
+        let mut player_ids: Vec<Option<i32>> = self
+            .players
+            .iter()
+            .map(|player| player.id)
+            .sorted()
+            .map(Some)
+            .collect();
+        if player_ids.len() % 2 == 1 {
+            player_ids.push(None);
         }
-        matches
+        player_ids
     }
 
-    fn generate_bye_player(&self) -> PlayerRowModel {
-        PlayerRowModel {
-            id: 0,
-            tournament_id: 0,
-            joueurs_id: "".to_string(),
-            first_name: "".to_string(),
-            last_name: "".to_string(),
-            country: "".to_string(),
-            rating: 0,
-            meta_data: Default::default(),
+    // Follows the FIDE Berger tables. With n numbers, the last number stays fixed while the pairs
+    // of the other numbers in round k (0-indexed) sum up to k modulo n - 1. The first number of
+    // each pair plays black.
+    fn get_berger_round(number_count: &usize, round_index: &usize) -> Vec<(usize, usize)> {
+        let modulus = number_count - 1;
+        let fixed_number = modulus;
+        let fixed_opponent = (round_index * (modulus + 1) / 2) % modulus;
+
+        let mut berger_round = match round_index % 2 {
+            0 => vec![(fixed_opponent, fixed_number)],
+            _ => vec![(fixed_number, fixed_opponent)],
+        };
+        for board in 1..(number_count / 2) {
+            berger_round.push((
+                (fixed_opponent + board) % modulus,
+                (fixed_opponent + modulus - board) % modulus,
+            ));
         }
+        berger_round
     }
 
-    fn generate_bye_match(&self, round_id: &i32, player: &PlayerRowModel) -> Box<dyn IGameMatch> {
-        Box::from(GameMatchCreator::create_new_bye_match(
-            round_id,
-            &player.id,
-            &Value::from(Map::new()),
-        ))
+    // Later cycles repeat the first one, with colours reversed on every other cycle.
+    fn generate_scheduled_pairings(&self, round_index: &usize, round_id: &i32) -> Pairings {
+        let player_ids = self.get_berger_numbered_player_ids();
+        let cycle_round_count = player_ids.len() - 1;
+        let is_color_reversed = (round_index / cycle_round_count) % 2 == 1;
+
+        RRPairingsGenerator::get_berger_round(&player_ids.len(), &(round_index % cycle_round_count))
+            .into_iter()
+            .map(|(first_number, second_number)| {
+                let (black_number, white_number) = match is_color_reversed {
+                    true => (second_number, first_number),
+                    false => (first_number, second_number),
+                };
+                match (player_ids[black_number], player_ids[white_number]) {
+                    (Some(black_player_id), Some(white_player_id)) => {
+                        GameMatchCreator::create_new_match(
+                            round_id,
+                            &black_player_id,
+                            &white_player_id,
+                            &Value::from(Map::new()),
+                        )
+                    }
+                    (Some(player_id), None) | (None, Some(player_id)) => {
+                        GameMatchCreator::create_new_bye_match(
+                            round_id,
+                            &player_id,
+                            &Value::from(Map::new()),
+                        )
+                    }
+                    (None, None) => unreachable!(),
+                }
+            })
+            .collect()
     }
 
-    fn generate_match(
-        &self,
-        round_id: &i32,
-        player_1: &PlayerRowModel,
-        player_2: &PlayerRowModel,
-    ) -> Box<dyn IGameMatch> {
-        let player_1_color = get_player_1_color(&player_1.id, &player_2.id, &self.past_results);
-        let black_player_id = match player_1_color {
-            PlayerColor::Black => player_1.id,
-            PlayerColor::White => player_2.id,
-        };
-        let white_player_id = match player_1_color {
-            PlayerColor::White => player_1.id,
-            PlayerColor::Black => player_2.id,
+    // The schedule is kept, a player with a requested bye skips the scheduled game and the
+    // opponent is left without a game, scoring nothing for the round. A scheduled pairing bye
+    // takes the requested score instead.
+    fn apply_requested_byes(&self, pairing: Box<dyn IGameMatch>, round_id: &i32) -> Pairings {
+        let player_ids = match pairing.get_players_id() {
+            (Some(player_1_id), Some(player_2_id)) => vec![player_1_id, player_2_id],
+            (Some(player_id), None) => {
+                return match self.requested_byes.get(&player_id) {
+                    Some(requested_bye) => {
+                        vec![requested_bye.create_bye_match(round_id, &player_id)]
+                    }
+                    None => vec![pairing],
+                }
+            }
+            _ => return vec![pairing],
         };
-        Box::from(GameMatchCreator::create_new_match(
-            round_id,
-            &black_player_id,
-            &white_player_id,
-            &Value::from(Map::new()),
-        ))
-    }
+        if player_ids
+            .iter()
+            .all(|player_id| !self.requested_byes.contains_key(player_id))
+        {
+            return vec![pairing];
+        }
 
-    fn is_players_matched(
-        &self,
-        pairings: &Pairings,
-        player_1_id: &i32,
-        player_2_id: &i32,
-    ) -> bool {
-        pairings
+        player_ids
             .iter()
-            .find(|pairing| {
-                let result = pairing.get_opponent_id(player_1_id);
-                result.is_some() && &result.unwrap() == player_2_id
+            .map(|player_id| match self.requested_byes.get(player_id) {
+                Some(requested_bye) => requested_bye.create_bye_match(round_id, player_id),
+                None => GameMatchCreator::create_new_bye_match_with_score(
+                    round_id,
+                    player_id,
+                    &0.0,
+                    &0.0,
+                    &Value::from(Map::new()),
+                ),
             })
-            .is_some()
+            .collect()
+    }
+
+    fn get_played_round_count(&self) -> usize {
+        self.past_results
+            .get_detailed_standings()
+            .iter()
+            .map(|standing| standing.match_history.len())
+            .max()
+            .unwrap_or(0)
     }
 
-    fn is_player_bye(&self, pairings: &Pairings, player_id: &i32) -> bool {
+    fn has_rematch(&self, pairings: &Pairings) -> bool {
         pairings
             .iter()
-            .find(|pairing| {
-                let opponent_id = pairing.get_opponent_id(player_id);
-                pairing.is_player_playing(player_id) && opponent_id.is_none()
+            .any(|pairing| match pairing.get_players_id() {
+                (Some(player_1_id), Some(player_2_id)) => self
+                    .past_results
+                    .has_players_met(&player_1_id, &player_2_id),
+                _ => false,
             })
-            .is_some()
     }
 }
 
 impl PairingGenerator for RRPairingsGenerator {
     fn generate_pairings(&self, round_id: &i32) -> Result<Pairings, ErrorType> {
-        if self.past_results.is_empty() {
-            let shift = generate_random_number_ranged(0, self.players.len() as i32 - 1);
-            return Ok(self.generate_rr_pairings(round_id, &shift));
+        let played_round_count = self.get_played_round_count();
+        if played_round_count >= self.get_round_count() {
+            return Err(ErrorType::AutomaticPairingError);
         }
 
-        let standings = self.past_results.get_standings();
-        let highest_ranked_player_id = standings.first().unwrap();
-        let next_opponent_for_highest_ranked_player = standings.iter().find(|&id| {
-            id != highest_ranked_player_id
-                && !self
-                    .past_results
-                    .has_players_met(highest_ranked_player_id, id)
-        });
-        let is_odd_player_count = standings.len() % 2 == 1;
-
-        match next_opponent_for_highest_ranked_player {
-            Some(opponent_id) => {
-                let mut max_shift = standings.len() - 1;
-                if is_odd_player_count {
-                    max_shift += 1;
-                }
-                for shift in 0..max_shift {
-                    let pairings = self.generate_rr_pairings(round_id, &(shift as i32));
-                    if self.is_players_matched(&pairings, highest_ranked_player_id, opponent_id) {
-                        return Ok(pairings);
-                    }
-                }
-                Err(ErrorType::AutomaticPairingError)
-            }
-            None => {
-                if is_odd_player_count && !self.past_results.has_player_bye(highest_ranked_player_id) {
-                    for shift in 0..standings.len() {
-                        let pairings = self.generate_rr_pairings(round_id, &(shift as i32));
-                        if self.is_player_bye(&pairings, highest_ranked_player_id) {
-                            return Ok(pairings);
-                        }
-                    }
-                }
-                Err(ErrorType::AutomaticPairingError)
-            }
+        let pairings = self.generate_scheduled_pairings(&played_round_count, round_id);
+        let cycle_round_count = self.get_berger_numbered_player_ids().len() - 1;
+        if played_round_count < cycle_round_count && self.has_rematch(&pairings) {
+            return Err(ErrorType::AutomaticPairingError);
         }
+        Ok(pairings
+            .into_iter()
+            .flat_map(|pairing| self.apply_requested_byes(pairing, round_id))
+            .collect())
     }
 }
 
 #[cfg(test)]
 mod tests {
     mod test_rr_pairing {
-        use mocktopus::mocking::{MockResult, Mockable};
+        use std::collections::HashMap;
+
         use serde_json::{Map, Value};
 
         use crate::database_models::{MatchRowModel, PlayerRowModel};
-        use crate::game_match::{GameMatchCreator, GameMatchTransformer, IGameMatch};
-        use crate::pairings_generator::{PairingGenerator, RRPairingsGenerator};
-        use crate::properties::PlayerColor;
-        use crate::tournament_manager::create_result_keeper;
-        use crate::utils;
+        use crate::game_match::{GameMatchTransformer, IGameMatch};
+        use crate::pairings_generator::{PairingGenerator, Pairings, RRPairingsGenerator};
+        use crate::tournament_manager::{create_result_keeper, RequestedBye};
         use crate::utils::generate_random_string;
 
         fn create_dummy_player(id: i32, rating: i32) -> PlayerRowModel {
@@ -204,6 +241,13 @@ mod tests {
             }
         }
 
+        fn create_dummy_players(count: i32) -> Vec<PlayerRowModel> {
+            (1..=count)
+                .rev()
+                .map(|id| create_dummy_player(id, 1000 + id * 100))
+                .collect()
+        }
+
         fn create_dummy_match(
             black_player_id: i32,
             white_player_id: i32,
@@ -222,245 +266,224 @@ mod tests {
             GameMatchTransformer::transform_to_game_match(&match_model)
         }
 
-        #[test]
-        fn test_first_round_even() {
-            utils::generate_random_number_ranged.mock_safe(|_, _| MockResult::Return(0));
-            let player_lists = vec![
-                create_dummy_player(1, 1500),
-                create_dummy_player(2, 2000),
-                create_dummy_player(3, 1000),
-                create_dummy_player(4, 200),
-                create_dummy_player(5, 3000),
-                create_dummy_player(6, 1700),
-            ];
-            let game_matches: Vec<Box<dyn IGameMatch>> = Vec::new();
-            let result_keeper = create_result_keeper(&game_matches);
-
-            let pairings_generator = RRPairingsGenerator::new(player_lists, result_keeper);
-            let pairings = pairings_generator.generate_pairings(&0).unwrap();
-
-            assert_eq!(pairings[0].get_player_color(&6), Some(PlayerColor::White));
-            assert_eq!(pairings[0].get_player_color(&1), Some(PlayerColor::Black));
-            assert_eq!(pairings[1].get_player_color(&2), Some(PlayerColor::Black));
-            assert_eq!(pairings[1].get_player_color(&5), Some(PlayerColor::White));
-            assert_eq!(pairings[2].get_player_color(&3), Some(PlayerColor::Black));
-            assert_eq!(pairings[2].get_player_color(&4), Some(PlayerColor::White));
+        fn get_pairings_id(pairings: &Pairings) -> Vec<(Option<i32>, Option<i32>)> {
+            pairings
+                .iter()
+                .map(|pairing| pairing.get_players_id())
+                .collect()
         }
 
         #[test]
-        fn test_first_round_odd() {
-            utils::generate_random_number_ranged.mock_safe(|_, _| MockResult::Return(0));
-            let player_lists = vec![
-                create_dummy_player(1, 1500),
-                create_dummy_player(2, 2000),
-                create_dummy_player(3, 1000),
-                create_dummy_player(4, 200),
-                create_dummy_player(5, 3000),
+        fn test_berger_table_even() {
+            let result_keeper = create_result_keeper(&vec![]);
+            let pairings_generator =
+                RRPairingsGenerator::new(create_dummy_players(6), result_keeper);
+
+            let schedule: Vec<Vec<(Option<i32>, Option<i32>)>> = pairings_generator
+                .generate_schedule()
+                .iter()
+                .map(get_pairings_id)
+                .collect();
+            let berger_table = vec![
+                vec![(1, 6), (2, 5), (3, 4)],
+                vec![(6, 4), (5, 3), (1, 2)],
+                vec![(2, 6), (3, 1), (4, 5)],
+                vec![(6, 5), (1, 4), (2, 3)],
+                vec![(3, 6), (4, 2), (5, 1)],
             ];
-            let game_matches: Vec<Box<dyn IGameMatch>> = Vec::new();
-            let result_keeper = create_result_keeper(&game_matches);
-
-            let pairings_generator = RRPairingsGenerator::new(player_lists, result_keeper);
-            let pairings = pairings_generator.generate_pairings(&0).unwrap();
+            let expected_schedule: Vec<Vec<(Option<i32>, Option<i32>)>> = berger_table
+                .into_iter()
+                .map(|berger_round| {
+                    berger_round
+                        .into_iter()
+                        .map(|(black_player_id, white_player_id)| {
+                            (Some(black_player_id), Some(white_player_id))
+                        })
+                        .collect()
+                })
+                .collect();
+            assert_eq!(schedule, expected_schedule);
+        }
 
-            assert_eq!(pairings[0].get_player_color(&1), None);
-            assert_eq!(pairings[0].is_player_playing(&1), true);
-            assert_eq!(pairings[1].get_player_color(&2), Some(PlayerColor::Black));
-            assert_eq!(pairings[1].get_player_color(&5), Some(PlayerColor::White));
-            assert_eq!(pairings[2].get_player_color(&3), Some(PlayerColor::Black));
-            assert_eq!(pairings[2].get_player_color(&4), Some(PlayerColor::White));
+        #[test]
+        fn test_berger_table_odd() {
+            let result_keeper = create_result_keeper(&vec![]);
+            let pairings_generator =
+                RRPairingsGenerator::new(create_dummy_players(3), result_keeper);
+
+            let schedule = pairings_generator.generate_schedule();
+            assert_eq!(schedule.len(), 3);
+            assert_eq!(
+                get_pairings_id(&schedule[0]),
+                vec![(Some(1), None), (Some(2), Some(3))]
+            );
+            assert_eq!(
+                get_pairings_id(&schedule[1]),
+                vec![(Some(3), None), (Some(1), Some(2))]
+            );
+            assert_eq!(
+                get_pairings_id(&schedule[2]),
+                vec![(Some(2), None), (Some(3), Some(1))]
+            );
         }
 
         #[test]
-        fn test_normal_round_even() {
-            let player_lists = vec![
-                create_dummy_player(1, 1500),
-                create_dummy_player(2, 2000),
-                create_dummy_player(3, 1000),
-                create_dummy_player(4, 200),
-                create_dummy_player(5, 3000),
-                create_dummy_player(6, 1700),
-            ];
+        fn test_normal_round() {
             let game_matches = vec![
                 create_dummy_match(1, 4, 20, 44),
-                create_dummy_match(2, 5, 32, 32),
-                create_dummy_match(3, 6, 19, 45),
+                create_dummy_match(2, 3, 32, 32),
             ];
             let result_keeper = create_result_keeper(&game_matches);
+            let pairings_generator =
+                RRPairingsGenerator::new(create_dummy_players(4), result_keeper);
 
-            let pairings_generator = RRPairingsGenerator::new(player_lists, result_keeper);
             let pairings = pairings_generator.generate_pairings(&0).unwrap();
-
-            assert_eq!(pairings[0].get_player_color(&5), Some(PlayerColor::Black));
-            assert_eq!(pairings[0].get_player_color(&1), Some(PlayerColor::White));
-            assert_eq!(pairings[1].get_player_color(&6), Some(PlayerColor::Black));
-            assert_eq!(pairings[1].get_player_color(&4), Some(PlayerColor::White));
-            assert_eq!(pairings[2].get_player_color(&2), Some(PlayerColor::Black));
-            assert_eq!(pairings[2].get_player_color(&3), Some(PlayerColor::White));
+            assert_eq!(
+                get_pairings_id(&pairings),
+                vec![(Some(4), Some(3)), (Some(1), Some(2))]
+            );
         }
 
         #[test]
-        fn test_normal_round_odd() {
-            let player_lists = vec![
-                create_dummy_player(1, 1500),
-                create_dummy_player(2, 2000),
-                create_dummy_player(3, 1000),
-                create_dummy_player(4, 200),
-                create_dummy_player(5, 3000),
-            ];
+        fn test_normal_round_with_bye() {
             let game_matches = vec![
-                create_dummy_match(5, 1, 20, 44),
-                create_dummy_match(3, 2, 32, 32),
-                GameMatchCreator::create_new_bye_match(&0, &4, &Value::from(Map::new())),
+                create_dummy_match(1, -1, -2, -2),
+                create_dummy_match(2, 3, 32, 32),
             ];
             let result_keeper = create_result_keeper(&game_matches);
+            let pairings_generator =
+                RRPairingsGenerator::new(create_dummy_players(3), result_keeper);
 
-            let pairings_generator = RRPairingsGenerator::new(player_lists, result_keeper);
             let pairings = pairings_generator.generate_pairings(&0).unwrap();
-
-            assert_eq!(pairings[0].get_player_color(&1), Some(PlayerColor::Black));
-            assert_eq!(pairings[0].get_player_color(&4), Some(PlayerColor::White));
-            assert_eq!(pairings[1].get_player_color(&5), Some(PlayerColor::Black));
-            assert_eq!(pairings[1].get_player_color(&3), Some(PlayerColor::White));
-            assert_eq!(pairings[2].get_player_color(&2), None);
-            assert_eq!(pairings[2].is_player_playing(&2), true);
+            assert_eq!(
+                get_pairings_id(&pairings),
+                vec![(Some(3), None), (Some(1), Some(2))]
+            );
         }
 
         #[test]
-        fn test_normal_round_only_one_possibility() {
-            let player_lists = vec![
-                create_dummy_player(1, 1500),
-                create_dummy_player(2, 2000),
-                create_dummy_player(3, 1000),
-                create_dummy_player(4, 200),
-            ];
+        fn test_normal_round_with_requested_bye() {
             let game_matches = vec![
-                create_dummy_match(1, 3, 20, 44),
-                create_dummy_match(4, 2, 32, 32),
-                create_dummy_match(1, 2, 20, 44),
-                create_dummy_match(3, 4, 33, 31),
+                create_dummy_match(1, 4, 20, 44),
+                create_dummy_match(2, 3, 32, 32),
             ];
             let result_keeper = create_result_keeper(&game_matches);
+            let mut requested_byes = HashMap::new();
+            requested_byes.insert(
+                3,
+                RequestedBye {
+                    round_number: 2,
+                    major_score: 0.5,
+                    disc_count: 32,
+                },
+            );
+            let pairings_generator = RRPairingsGenerator::new_with_requested_byes(
+                create_dummy_players(4),
+                result_keeper,
+                1,
+                requested_byes,
+            );
 
-            let pairings_generator = RRPairingsGenerator::new(player_lists, result_keeper);
             let pairings = pairings_generator.generate_pairings(&0).unwrap();
-
-            assert_eq!(pairings[0].get_player_color(&4), Some(PlayerColor::Black));
-            assert_eq!(pairings[0].get_player_color(&1), Some(PlayerColor::White));
-            assert_eq!(pairings[1].get_player_color(&2), Some(PlayerColor::Black));
-            assert_eq!(pairings[1].get_player_color(&3), Some(PlayerColor::White));
+            assert_eq!(
+                get_pairings_id(&pairings),
+                vec![(Some(4), None), (Some(3), None), (Some(1), Some(2))]
+            );
+            assert_eq!(pairings[0].calculate_major_score(&4), 0.0);
+            assert_eq!(pairings[0].calculate_disc_count(&4), 0.0);
+            assert_eq!(pairings[1].calculate_major_score(&3), 0.5);
         }
 
         #[test]
-        fn test_normal_round_no_possibility() {
-            let player_lists = vec![
-                create_dummy_player(1, 1500),
-                create_dummy_player(2, 2000),
-                create_dummy_player(3, 1000),
-                create_dummy_player(4, 200),
-            ];
+        fn test_scheduled_bye_with_requested_bye() {
             let game_matches = vec![
-                create_dummy_match(1, 2, 20, 44),
-                create_dummy_match(3, 4, 20, 44),
-                create_dummy_match(1, 4, 32, 32),
+                create_dummy_match(1, -1, -2, -2),
                 create_dummy_match(2, 3, 32, 32),
-                create_dummy_match(1, 3, 20, 44),
-                create_dummy_match(4, 2, 20, 44),
             ];
             let result_keeper = create_result_keeper(&game_matches);
+            let mut requested_byes = HashMap::new();
+            requested_byes.insert(
+                3,
+                RequestedBye {
+                    round_number: 2,
+                    major_score: 0.5,
+                    disc_count: 20,
+                },
+            );
+            let pairings_generator = RRPairingsGenerator::new_with_requested_byes(
+                create_dummy_players(3),
+                result_keeper,
+                1,
+                requested_byes,
+            );
 
-            let pairings_generator = RRPairingsGenerator::new(player_lists, result_keeper);
-            let pairings_result = pairings_generator.generate_pairings(&0);
-
-            assert_eq!(pairings_result.is_err(), true);
+            let pairings = pairings_generator.generate_pairings(&0).unwrap();
+            assert_eq!(
+                get_pairings_id(&pairings),
+                vec![(Some(3), None), (Some(1), Some(2))]
+            );
+            assert_eq!(pairings[0].calculate_major_score(&3), 0.5);
+            assert_eq!(pairings[0].calculate_disc_count(&3), 20.0);
         }
 
         #[test]
-        fn test_highest_rank_on_bye() {
-            let player_lists = vec![
-                create_dummy_player(1, 1500),
-                create_dummy_player(2, 1000),
-                create_dummy_player(3, 200),
-            ];
+        fn test_schedule_deviation() {
             let game_matches = vec![
-                create_dummy_match(1, 2, 44, 20),
-                create_dummy_match(1, 3, 44, 20),
+                create_dummy_match(1, 2, 20, 44),
+                create_dummy_match(3, 4, 32, 32),
             ];
             let result_keeper = create_result_keeper(&game_matches);
+            let pairings_generator =
+                RRPairingsGenerator::new(create_dummy_players(4), result_keeper);
 
-            let pairings_generator = RRPairingsGenerator::new(player_lists, result_keeper);
             let pairings_result = pairings_generator.generate_pairings(&0);
-
-            assert_eq!(pairings_result.is_err(), false);
+            assert_eq!(pairings_result.is_err(), true);
         }
 
         #[test]
-        fn test_highest_rank_on_bye_twice() {
-            let player_lists = vec![
-                create_dummy_player(1, 1500),
-                create_dummy_player(2, 1000),
-                create_dummy_player(3, 200),
-            ];
+        fn test_schedule_finished() {
             let game_matches = vec![
-                create_dummy_match(1, 2, 44, 20),
-                create_dummy_match(1, 3, 44, 20),
-                create_dummy_match(1, -1, -2, -2),
+                create_dummy_match(1, 4, 20, 44),
+                create_dummy_match(2, 3, 32, 32),
+                create_dummy_match(4, 3, 20, 44),
+                create_dummy_match(1, 2, 32, 32),
+                create_dummy_match(2, 4, 20, 44),
+                create_dummy_match(3, 1, 32, 32),
             ];
             let result_keeper = create_result_keeper(&game_matches);
+            let pairings_generator =
+                RRPairingsGenerator::new(create_dummy_players(4), result_keeper);
 
-            let pairings_generator = RRPairingsGenerator::new(player_lists, result_keeper);
             let pairings_result = pairings_generator.generate_pairings(&0);
-
             assert_eq!(pairings_result.is_err(), true);
         }
 
         #[test]
-        fn test_odd_number_all_shift() {
-            let player_lists_set_1 = vec![
-                create_dummy_player(1, 1500),
-                create_dummy_player(2, 1000),
-                create_dummy_player(3, 200),
+        fn test_double_round_robin() {
+            let game_matches = vec![
+                create_dummy_match(1, 4, 20, 44),
+                create_dummy_match(2, 3, 32, 32),
+                create_dummy_match(4, 3, 20, 44),
+                create_dummy_match(1, 2, 32, 32),
+                create_dummy_match(2, 4, 20, 44),
+                create_dummy_match(3, 1, 32, 32),
             ];
+            let result_keeper = create_result_keeper(&game_matches);
+            let pairings_generator =
+                RRPairingsGenerator::new_with_cycles(create_dummy_players(4), result_keeper, 2);
+            assert_eq!(pairings_generator.get_round_count(), 6);
 
-            let game_matches_set_1 = vec![
-                create_dummy_match(1, 2, 44, 20),
-                create_dummy_match(1, 3, 44, 20),
-            ];
-            let result_keeper_set_1 = create_result_keeper(&game_matches_set_1);
-            let pairings_generator_set_1 =
-                RRPairingsGenerator::new(player_lists_set_1, result_keeper_set_1);
-            let pairings_result_set_1 = pairings_generator_set_1.generate_pairings(&0);
-            assert_eq!(pairings_result_set_1.is_err(), false);
-
-            let player_lists_set_2 = vec![
-                create_dummy_player(1, 1500),
-                create_dummy_player(2, 1000),
-                create_dummy_player(3, 200),
-            ];
-            let game_matches_set_2 = vec![
-                create_dummy_match(2, 1, 44, 20),
-                create_dummy_match(2, 3, 44, 20),
-            ];
-            let result_keeper_set_2 = create_result_keeper(&game_matches_set_2);
-            let pairings_generator_set_2 =
-                RRPairingsGenerator::new(player_lists_set_2, result_keeper_set_2);
-            let pairings_result_set_2 = pairings_generator_set_2.generate_pairings(&1);
-            assert_eq!(pairings_result_set_2.is_err(), false);
-
-            let player_lists_set_3 = vec![
-                create_dummy_player(1, 1500),
-                create_dummy_player(2, 1000),
-                create_dummy_player(3, 200),
-            ];
-            let game_matches_set_3 = vec![
-                create_dummy_match(3, 1, 44, 20),
-                create_dummy_match(3, 2, 44, 20),
-            ];
-            let result_keeper_set_3 = create_result_keeper(&game_matches_set_3);
-            let pairings_generator_set_3 =
-                RRPairingsGenerator::new(player_lists_set_3, result_keeper_set_3);
-            let pairings_result_set_3 = pairings_generator_set_3.generate_pairings(&2);
-            assert_eq!(pairings_result_set_3.is_err(), false);
+            let pairings = pairings_generator.generate_pairings(&0).unwrap();
+            assert_eq!(
+                get_pairings_id(&pairings),
+                vec![(Some(4), Some(1)), (Some(3), Some(2))]
+            );
+
+            let schedule = pairings_generator.generate_schedule();
+            assert_eq!(
+                get_pairings_id(&schedule[5]),
+                vec![(Some(4), Some(2)), (Some(1), Some(3))]
+            );
         }
     }
 }
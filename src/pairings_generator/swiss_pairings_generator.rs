@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
 use serde_json::{Map, Value};
@@ -6,14 +6,23 @@ use serde_json::{Map, Value};
 use crate::database_models::PlayerRowModel;
 use crate::errors::ErrorType;
 use crate::game_match::{GameMatchCreator, IGameMatch};
-use crate::properties::PlayerColor;
-use crate::tournament_manager::IResultKeeper;
+use crate::properties::{PairingConstraint, PlayerColor};
+use crate::tournament_manager::{IResultKeeper, RequestedBye};
 
-use super::{get_player_1_color, PairingGenerator, Pairings};
+use super::{
+    get_broken_pairing_constraints, get_player_1_color, is_color_compatible, PairingGenerator,
+    Pairings, PerfectMatching,
+};
+
+const ACCELERATED_VIRTUAL_POINTS: f64 = 1.0;
 
 pub struct SwissPairingsGenerator {
     players: Vec<PlayerRowModel>,
     past_results: Box<dyn IResultKeeper>,
+    pairing_constraints: Vec<PairingConstraint>,
+    is_accelerated: bool,
+    withdrawn_player_ids: HashSet<i32>,
+    requested_byes: HashMap<i32, RequestedBye>,
 }
 
 impl SwissPairingsGenerator {
@@ -21,211 +30,283 @@ impl SwissPairingsGenerator {
         players: Vec<PlayerRowModel>,
         past_results: Box<dyn IResultKeeper>,
     ) -> SwissPairingsGenerator {
+        SwissPairingsGenerator::new_with_constraints(players, past_results, vec![])
+    }
+
+    pub fn new_with_constraints(
+        players: Vec<PlayerRowModel>,
+        past_results: Box<dyn IResultKeeper>,
+        pairing_constraints: Vec<PairingConstraint>,
+    ) -> SwissPairingsGenerator {
+        SwissPairingsGenerator::new_with_acceleration(
+            players,
+            past_results,
+            pairing_constraints,
+            false,
+        )
+    }
+
+    pub fn new_with_acceleration(
+        players: Vec<PlayerRowModel>,
+        past_results: Box<dyn IResultKeeper>,
+        pairing_constraints: Vec<PairingConstraint>,
+        is_accelerated: bool,
+    ) -> SwissPairingsGenerator {
+        SwissPairingsGenerator::new_with_requested_byes(
+            players,
+            past_results,
+            pairing_constraints,
+            is_accelerated,
+            HashMap::new(),
+        )
+    }
+
+    pub fn new_with_requested_byes(
+        players: Vec<PlayerRowModel>,
+        past_results: Box<dyn IResultKeeper>,
+        pairing_constraints: Vec<PairingConstraint>,
+        is_accelerated: bool,
+        requested_byes: HashMap<i32, RequestedBye>,
+    ) -> SwissPairingsGenerator {
+        // Withdrawn players keep their past results but are left out of every later round.
+        let (withdrawn_players, players): (Vec<PlayerRowModel>, Vec<PlayerRowModel>) = players
+            .into_iter()
+            .partition(|player| player.is_withdrawn());
         SwissPairingsGenerator {
             players,
             past_results,
+            pairing_constraints,
+            is_accelerated,
+            withdrawn_player_ids: withdrawn_players.iter().map(|player| player.id).collect(),
+            requested_byes,
         }
     }
 
-    fn generate_first_round_pairings(&self, round_id: &i32) -> Pairings {
-        let mut matches = Vec::new();
-        let mut sorted_players = self.players[..]
+    fn get_players_sorted_by_rating(&self) -> Vec<&PlayerRowModel> {
+        self.players[..]
             .into_iter()
             .sorted_by_key(|player| -player.rating)
-            .collect::<Vec<&PlayerRowModel>>();
+            .collect()
+    }
 
-        let midpoint = (sorted_players.len() as f32 / 2 as f32).ceil() as usize;
-        let second_part_sorted_players = sorted_players.split_off(midpoint);
-        let mut second_part_players_iter = second_part_sorted_players.iter();
+    // The accelerated top group holds the highest rated half of the players, rounded up to an
+    // even number so that the group can be paired within itself in the first round.
+    fn get_accelerated_group_size(&self) -> usize {
+        let group_size = 2 * ((self.players.len() as f32 / 4 as f32).ceil() as usize);
+        group_size.min(self.players.len())
+    }
 
-        for (idx, player_1) in sorted_players.iter().enumerate() {
-            let player_2_option = second_part_players_iter.next();
-            match player_2_option {
-                Some(player_2) => {
-                    let black_player_id = match idx % 2 == 0 {
-                        true => player_1.id,
-                        false => player_2.id,
-                    };
-
-                    let white_player_id = match idx % 2 == 0 {
-                        true => player_2.id,
-                        false => player_1.id,
-                    };
-                    matches.push(Box::from(GameMatchCreator::create_new_match(
-                        round_id,
-                        &black_player_id,
-                        &white_player_id,
-                        &Value::from(Map::new()),
-                    )));
-                }
-                None => {
-                    matches.push(Box::from(GameMatchCreator::create_new_bye_match(
-                        round_id,
-                        &player_1.id,
-                        &Value::from(Map::new()),
-                    )));
-                }
+    fn get_accelerated_player_ids(&self) -> HashSet<i32> {
+        if !self.is_accelerated {
+            return HashSet::new();
+        }
+        self.get_players_sorted_by_rating()
+            .iter()
+            .take(self.get_accelerated_group_size())
+            .map(|player| player.id)
+            .collect()
+    }
+
+    fn is_pairing_constraint_broken(&self, player_1_id: &i32, player_2_id: &i32) -> bool {
+        if self.pairing_constraints.is_empty() {
+            return false;
+        }
+        let player_1 = self.players.iter().find(|player| &player.id == player_1_id);
+        let player_2 = self.players.iter().find(|player| &player.id == player_2_id);
+        match (player_1, player_2) {
+            (Some(player_1), Some(player_2)) => {
+                !get_broken_pairing_constraints(player_1, player_2, &self.pairing_constraints)
+                    .is_empty()
             }
+            _ => false,
         }
-        matches
     }
 
-    fn generate_normal_pairings(
-        &self,
-        round_id: &i32,
-    ) -> Result<Vec<Box<dyn IGameMatch>>, ErrorType> {
-        let mut memo: HashMap<i128, Option<Pairings>> = HashMap::new();
-        match self.generate_remaining_pairings(round_id, &(0 as i128), &mut memo) {
-            Some(matches) => Ok(matches),
-            None => Err(ErrorType::AutomaticPairingError),
+    fn is_absent(&self, player_id: &i32) -> bool {
+        self.withdrawn_player_ids.contains(player_id) || self.requested_byes.contains_key(player_id)
+    }
+
+    fn generate_requested_bye_matches(&self, round_id: &i32) -> Pairings {
+        self.requested_byes
+            .iter()
+            .sorted_by_key(|(player_id, _)| **player_id)
+            .map(|(player_id, requested_bye)| requested_bye.create_bye_match(round_id, player_id))
+            .collect()
+    }
+
+    fn generate_first_round_pairings(&self, round_id: &i32) -> Pairings {
+        let sorted_players: Vec<&PlayerRowModel> = self
+            .get_players_sorted_by_rating()
+            .into_iter()
+            .filter(|player| !self.is_absent(&player.id))
+            .collect();
+        if !self.is_accelerated {
+            return self.generate_folded_pairings(round_id, sorted_players);
         }
+
+        // A requested bye can leave the top group odd, its last player then joins the bottom group.
+        let accelerated_player_ids = self.get_accelerated_player_ids();
+        let (mut top_group_players, mut bottom_group_players): (Vec<_>, Vec<_>) = sorted_players
+            .into_iter()
+            .partition(|player| accelerated_player_ids.contains(&player.id));
+        if top_group_players.len() % 2 == 1 {
+            bottom_group_players.insert(0, top_group_players.pop().unwrap());
+        }
+        let mut matches = self.generate_folded_pairings(round_id, top_group_players);
+        matches.append(&mut self.generate_folded_pairings(round_id, bottom_group_players));
+        matches
     }
 
-    fn generate_remaining_pairings(
+    fn generate_folded_pairings(
         &self,
         round_id: &i32,
-        bitmask: &i128,
-        memo: &mut HashMap<i128, Option<Pairings>>,
-    ) -> Option<Pairings> {
-        if self.has_all_players_paired(bitmask) {
-            return Some(Vec::new());
-        }
+        mut sorted_players: Vec<&PlayerRowModel>,
+    ) -> Pairings {
+        let mut matches = Vec::new();
+        let midpoint = (sorted_players.len() as f32 / 2 as f32).ceil() as usize;
+        let mut second_part_sorted_players = sorted_players.split_off(midpoint);
 
-        let standings = self.past_results.get_detailed_standings();
-        if !memo.contains_key(bitmask) {
-            let (player_1_idx, player_1_standing) = standings
-                .iter()
-                .enumerate()
-                .map(|(idx, player)| (idx as i32, player))
-                .find(|(idx, _)| !self.has_player_paired(bitmask, idx))
-                .unwrap();
+        for (idx, player_1) in sorted_players.iter().enumerate() {
+            if second_part_sorted_players.is_empty() {
+                matches.push(Box::from(GameMatchCreator::create_new_bye_match(
+                    round_id,
+                    &player_1.id,
+                    &Value::from(Map::new()),
+                )));
+                continue;
+            }
 
-            let pairings = match self.get_no_of_unpaired_players(bitmask) {
-                1 => {
-                    if self
-                        .past_results
-                        .has_player_bye(&player_1_standing.player_id)
-                    {
-                        return None;
-                    }
+            // Everyone shares the same score group in the first round, so the opponent can be
+            // moved further down the second half to satisfy the pairing constraints.
+            let player_2_position = second_part_sorted_players
+                .iter()
+                .position(|player_2| !self.is_pairing_constraint_broken(&player_1.id, &player_2.id))
+                .unwrap_or(0);
+            let player_2 = second_part_sorted_players.remove(player_2_position);
 
-                    let pairing = GameMatchCreator::create_new_bye_match(
-                        round_id,
-                        &player_1_standing.player_id,
-                        &Value::from(Map::new()),
-                    );
-                    let updated_bitmask =
-                        self.add_new_pair_to_bitmask(bitmask, &player_1_idx, &player_1_idx);
-                    let remaining_pairings = self
-                        .generate_remaining_pairings(round_id, &updated_bitmask, memo)
-                        .unwrap();
-                    Some([vec![pairing], remaining_pairings].concat())
-                }
-                _ => {
-                    let result = standings
-                        .iter()
-                        .enumerate()
-                        .map(|(idx, player)| (idx as i32, player))
-                        .find(|(idx, player_2_standing)| {
-                            if idx == &player_1_idx {
-                                return false;
-                            }
-
-                            if self.has_player_paired(bitmask, idx) {
-                                return false;
-                            }
-
-                            if self.past_results.has_players_met(
-                                &player_1_standing.player_id,
-                                &player_2_standing.player_id,
-                            ) {
-                                return false;
-                            }
-
-                            let updated_bitmask =
-                                self.add_new_pair_to_bitmask(bitmask, &player_1_idx, &idx);
-                            self.generate_remaining_pairings(round_id, &updated_bitmask, memo)
-                                .is_some()
-                        });
-
-                    match result {
-                        Some((player_2_idx, player_2_standing)) => {
-                            let pairing = self.generate_pairing(
-                                round_id,
-                                &player_1_standing.player_id,
-                                &player_2_standing.player_id,
-                            );
-
-                            let updated_bitmask =
-                                self.add_new_pair_to_bitmask(bitmask, &player_1_idx, &player_2_idx);
-                            let remaining_pairings = self
-                                .generate_remaining_pairings(round_id, &updated_bitmask, memo)
-                                .unwrap();
-                            Some([vec![pairing], remaining_pairings].concat())
-                        }
-                        None => {
-                            if self.get_no_of_unpaired_players(bitmask) % 2 == 0 {
-                                return None;
-                            }
-
-                            if self
-                                .past_results
-                                .has_player_bye(&player_1_standing.player_id)
-                            {
-                                return None;
-                            }
-                            let updated_bitmask =
-                                self.add_new_pair_to_bitmask(bitmask, &player_1_idx, &player_1_idx);
-                            let remaining_pairings_option =
-                                self.generate_remaining_pairings(round_id, &updated_bitmask, memo);
-                            if remaining_pairings_option.is_none() {
-                                return None;
-                            }
-
-                            let pairing = GameMatchCreator::create_new_bye_match(
-                                round_id,
-                                &player_1_standing.player_id,
-                                &Value::from(Map::new()),
-                            );
-                            Some([vec![pairing], remaining_pairings_option.unwrap()].concat())
-                        }
-                    }
-                }
+            let black_player_id = match idx % 2 == 0 {
+                true => player_1.id,
+                false => player_2.id,
             };
 
-            memo.insert(bitmask.clone(), pairings);
+            let white_player_id = match idx % 2 == 0 {
+                true => player_2.id,
+                false => player_1.id,
+            };
+            matches.push(Box::from(GameMatchCreator::create_new_match(
+                round_id,
+                &black_player_id,
+                &white_player_id,
+                &Value::from(Map::new()),
+            )));
         }
-
-        memo.get(bitmask).unwrap().clone()
+        matches
     }
 
-    fn has_all_players_paired(&self, bitmask: &i128) -> bool {
-        let player_count = self.past_results.get_standings().len();
-        let all_players_paired_bitmask = (2 as i128).pow(player_count as u32) - 1;
-        bitmask == &all_players_paired_bitmask
-    }
+    fn generate_normal_pairings(
+        &self,
+        round_id: &i32,
+    ) -> Result<Vec<Box<dyn IGameMatch>>, ErrorType> {
+        let pairing_scores_by_player_id: HashMap<i32, f64> = self
+            .past_results
+            .get_detailed_standings()
+            .into_iter()
+            .map(|standing| {
+                let virtual_points = self.get_virtual_points(&standing.player_id);
+                (standing.player_id, standing.major_score + virtual_points)
+            })
+            .collect();
+        let mut standings: Vec<i32> = self
+            .past_results
+            .get_standings()
+            .into_iter()
+            .filter(|player_id| !self.is_absent(player_id))
+            .collect();
+        if self.is_accelerated {
+            standings = standings
+                .into_iter()
+                .sorted_by(|player_1_id, player_2_id| {
+                    pairing_scores_by_player_id[player_2_id]
+                        .partial_cmp(&pairing_scores_by_player_id[player_1_id])
+                        .unwrap()
+                })
+                .collect();
+        }
+        let player_count = standings.len();
+        let bye_index = player_count;
+
+        // Pairing players with the same absolute colour preference is only allowed when there is
+        // no other way to pair everyone.
+        let mut perfect_matching =
+            match PerfectMatching::new(self.generate_adjacency(&standings, true))
+                .or_else(|| PerfectMatching::new(self.generate_adjacency(&standings, false)))
+            {
+                Some(perfect_matching) => perfect_matching,
+                None => return Err(ErrorType::AutomaticPairingError),
+            };
 
-    fn has_player_paired(&self, bitmask: &i128, player_index: &i32) -> bool {
-        ((1 << player_index) & bitmask) != 0
-    }
+        let mut matches = Vec::new();
+        for player_1_index in 0..player_count {
+            if perfect_matching.is_removed(&player_1_index) {
+                continue;
+            }
 
-    fn get_no_of_unpaired_players(&self, bitmask: &i128) -> i32 {
-        let mut cnt = 0;
-        for i in 0..self.past_results.get_standings().len() {
-            if !self.has_player_paired(bitmask, &(i as i32)) {
-                cnt += 1;
+            let player_1_id = &standings[player_1_index];
+            let mut player_2_index = (player_1_index + 1..=bye_index)
+                .find(|index| perfect_matching.can_fix_pair(&player_1_index, index))
+                .ok_or(ErrorType::AutomaticPairingError)?;
+
+            // A constrained pairing is only replaced by an opponent from the same score group, so
+            // that the constraints never cause extra floats.
+            if player_2_index != bye_index
+                && self.is_pairing_constraint_broken(player_1_id, &standings[player_2_index])
+            {
+                let score_group = pairing_scores_by_player_id.get(&standings[player_2_index]);
+                if let Some(index) = (player_2_index + 1..bye_index).find(|index| {
+                    pairing_scores_by_player_id.get(&standings[*index]) == score_group
+                        && !self.is_pairing_constraint_broken(player_1_id, &standings[*index])
+                        && perfect_matching.can_fix_pair(&player_1_index, index)
+                }) {
+                    player_2_index = index;
+                }
+            }
+            perfect_matching.try_fix_pair(&player_1_index, &player_2_index);
+
+            if player_2_index == bye_index {
+                matches.push(GameMatchCreator::create_new_bye_match(
+                    round_id,
+                    player_1_id,
+                    &Value::from(Map::new()),
+                ));
+                continue;
             }
+            matches.push(self.generate_pairing(round_id, player_1_id, &standings[player_2_index]));
         }
-        cnt
+        Ok(matches)
     }
 
-    fn add_new_pair_to_bitmask(
-        &self,
-        bitmask: &i128,
-        player_1_index: &i32,
-        player_2_index: &i32,
-    ) -> i128 {
-        (1 << player_1_index) | (1 << player_2_index) | bitmask
+    // Players are indexed by their standing, an extra vertex at the end represents the bye and
+    // only exists when the number of players is odd.
+    fn generate_adjacency(&self, standings: &Vec<i32>, is_color_checked: bool) -> Vec<Vec<bool>> {
+        let player_count = standings.len();
+        let vertex_count = player_count + player_count % 2;
+        let mut adjacency = vec![vec![false; vertex_count]; vertex_count];
+
+        for (player_1_index, player_1_id) in standings.iter().enumerate() {
+            for (player_2_index, player_2_id) in standings.iter().enumerate() {
+                adjacency[player_1_index][player_2_index] = player_1_index != player_2_index
+                    && !self.past_results.has_players_met(player_1_id, player_2_id)
+                    && (!is_color_checked
+                        || is_color_compatible(player_1_id, player_2_id, &self.past_results));
+            }
+
+            if vertex_count > player_count {
+                let is_bye_allowed = !self.past_results.has_player_bye(player_1_id);
+                adjacency[player_1_index][player_count] = is_bye_allowed;
+                adjacency[player_count][player_1_index] = is_bye_allowed;
+            }
+        }
+        adjacency
     }
 
     fn generate_pairing(
@@ -254,23 +335,34 @@ impl SwissPairingsGenerator {
 
 impl PairingGenerator for SwissPairingsGenerator {
     fn generate_pairings(&self, round_id: &i32) -> Result<Pairings, ErrorType> {
-        if self.past_results.is_empty() {
-            return Ok(self.generate_first_round_pairings(round_id));
+        let mut matches = match self.past_results.is_empty() {
+            true => self.generate_first_round_pairings(round_id),
+            false => self.generate_normal_pairings(round_id)?,
+        };
+        matches.append(&mut self.generate_requested_bye_matches(round_id));
+        Ok(matches)
+    }
+
+    fn get_virtual_points(&self, player_id: &i32) -> f64 {
+        match self.get_accelerated_player_ids().contains(player_id) {
+            true => ACCELERATED_VIRTUAL_POINTS,
+            false => 0.0,
         }
-        self.generate_normal_pairings(round_id)
     }
 }
 
 #[cfg(test)]
 mod tests {
     mod test_swiss_pairing {
+        use std::collections::HashMap;
+
         use serde_json::{Map, Value};
 
         use crate::database_models::{MatchRowModel, PlayerRowModel};
         use crate::game_match::{GameMatchCreator, GameMatchTransformer, IGameMatch};
-        use crate::pairings_generator::{PairingGenerator, SwissPairingsGenerator};
-        use crate::properties::PlayerColor;
-        use crate::tournament_manager::create_result_keeper;
+        use crate::pairings_generator::{PairingGenerator, Pairings, SwissPairingsGenerator};
+        use crate::properties::{PairingConstraint, PlayerColor};
+        use crate::tournament_manager::{create_result_keeper, RequestedBye};
         use crate::utils::generate_random_string;
 
         fn create_dummy_player(id: i32, rating: i32) -> PlayerRowModel {
@@ -286,6 +378,19 @@ mod tests {
             }
         }
 
+        fn create_dummy_player_with_country(id: i32, rating: i32, country: &str) -> PlayerRowModel {
+            let mut player = create_dummy_player(id, rating);
+            player.country = String::from(country);
+            player
+        }
+
+        fn get_opponent_id(pairings: &Pairings, player_id: &i32) -> Option<i32> {
+            pairings
+                .iter()
+                .find(|game_match| game_match.is_player_playing(player_id))
+                .and_then(|game_match| game_match.get_opponent_id(player_id))
+        }
+
         fn create_dummy_match(
             black_player_id: i32,
             white_player_id: i32,
@@ -351,6 +456,56 @@ mod tests {
             assert_eq!(pairings[2].is_player_playing(&1), true);
         }
 
+        #[test]
+        fn test_first_round_accelerated() {
+            let player_lists = (1..=8)
+                .map(|id| create_dummy_player(id, 9000 - id * 1000))
+                .collect();
+            let result_keeper = create_result_keeper(&vec![]);
+
+            let pairings_generator = SwissPairingsGenerator::new_with_acceleration(
+                player_lists,
+                result_keeper,
+                vec![],
+                true,
+            );
+            let pairings = pairings_generator.generate_pairings(&0).unwrap();
+
+            assert_eq!(get_opponent_id(&pairings, &1), Some(3));
+            assert_eq!(get_opponent_id(&pairings, &2), Some(4));
+            assert_eq!(get_opponent_id(&pairings, &5), Some(7));
+            assert_eq!(get_opponent_id(&pairings, &6), Some(8));
+            assert_eq!(pairings_generator.get_virtual_points(&4), 1.0);
+            assert_eq!(pairings_generator.get_virtual_points(&5), 0.0);
+        }
+
+        #[test]
+        fn test_normal_round_accelerated() {
+            let player_lists = (1..=8)
+                .map(|id| create_dummy_player(id, 9000 - id * 1000))
+                .collect();
+            let game_matches = vec![
+                create_dummy_match(1, 3, 40, 24),
+                create_dummy_match(4, 2, 24, 40),
+                create_dummy_match(5, 7, 40, 24),
+                create_dummy_match(8, 6, 24, 40),
+            ];
+            let result_keeper = create_result_keeper(&game_matches);
+
+            let pairings_generator = SwissPairingsGenerator::new_with_acceleration(
+                player_lists,
+                result_keeper,
+                vec![],
+                true,
+            );
+            let pairings = pairings_generator.generate_pairings(&0).unwrap();
+
+            assert_eq!(get_opponent_id(&pairings, &1), Some(2));
+            assert_eq!(get_opponent_id(&pairings, &5), Some(6));
+            assert_eq!(get_opponent_id(&pairings, &3), Some(4));
+            assert_eq!(get_opponent_id(&pairings, &7), Some(8));
+        }
+
         #[test]
         fn test_normal_round_even() {
             let player_lists = vec![
@@ -375,8 +530,8 @@ mod tests {
             assert_eq!(pairings[0].get_player_color(&1), Some(PlayerColor::White));
             assert_eq!(pairings[1].get_player_color(&2), Some(PlayerColor::Black));
             assert_eq!(pairings[1].get_player_color(&5), Some(PlayerColor::White));
-            assert_eq!(pairings[2].get_player_color(&3), Some(PlayerColor::Black));
-            assert_eq!(pairings[2].get_player_color(&6), Some(PlayerColor::White));
+            assert_eq!(pairings[2].get_player_color(&3), Some(PlayerColor::White));
+            assert_eq!(pairings[2].get_player_color(&6), Some(PlayerColor::Black));
         }
 
         #[test]
@@ -406,6 +561,94 @@ mod tests {
             assert_eq!(pairings[2].is_player_playing(&3), true);
         }
 
+        #[test]
+        fn test_normal_round_withdrawn_player() {
+            let mut withdrawn_player = create_dummy_player(6, 1700);
+            withdrawn_player.set_withdrawn(&true);
+            let player_lists = vec![
+                create_dummy_player(1, 1500),
+                create_dummy_player(2, 2000),
+                create_dummy_player(3, 1000),
+                create_dummy_player(4, 200),
+                create_dummy_player(5, 3000),
+                withdrawn_player,
+            ];
+            let game_matches = vec![
+                create_dummy_match(5, 1, 20, 44),
+                create_dummy_match(3, 2, 32, 32),
+                create_dummy_match(6, 4, 19, 45),
+            ];
+            let result_keeper = create_result_keeper(&game_matches);
+
+            let pairings_generator = SwissPairingsGenerator::new(player_lists, result_keeper);
+            let pairings = pairings_generator.generate_pairings(&0).unwrap();
+
+            assert_eq!(pairings.len(), 3);
+            assert_eq!(
+                pairings
+                    .iter()
+                    .any(|game_match| game_match.is_player_playing(&6)),
+                false
+            );
+            assert_eq!(
+                pairings
+                    .iter()
+                    .filter(|game_match| game_match.get_players_id().1.is_none())
+                    .count(),
+                1
+            );
+        }
+
+        #[test]
+        fn test_normal_round_requested_bye() {
+            let player_lists = vec![
+                create_dummy_player(1, 1500),
+                create_dummy_player(2, 2000),
+                create_dummy_player(3, 1000),
+                create_dummy_player(4, 200),
+                create_dummy_player(5, 3000),
+                create_dummy_player(6, 1700),
+            ];
+            let game_matches = vec![
+                create_dummy_match(5, 1, 20, 44),
+                create_dummy_match(3, 2, 32, 32),
+                create_dummy_match(6, 4, 19, 45),
+            ];
+            let result_keeper = create_result_keeper(&game_matches);
+            let mut requested_byes = HashMap::new();
+            requested_byes.insert(
+                2,
+                RequestedBye {
+                    round_number: 2,
+                    major_score: 0.0,
+                    disc_count: 0,
+                },
+            );
+
+            let pairings_generator = SwissPairingsGenerator::new_with_requested_byes(
+                player_lists,
+                result_keeper,
+                vec![],
+                false,
+                requested_byes,
+            );
+            let pairings = pairings_generator.generate_pairings(&0).unwrap();
+
+            assert_eq!(pairings.len(), 4);
+            assert_eq!(get_opponent_id(&pairings, &2), None);
+            let requested_bye_match = pairings.last().unwrap();
+            assert_eq!(requested_bye_match.is_player_playing(&2), true);
+            assert_eq!(requested_bye_match.is_pairing_bye(), false);
+            assert_eq!(requested_bye_match.calculate_major_score(&2), 0.0);
+            assert_eq!(
+                pairings
+                    .iter()
+                    .filter(|game_match| game_match.is_pairing_bye())
+                    .count(),
+                1
+            );
+        }
+
         #[test]
         fn test_normal_round_only_one_possibility() {
             let player_lists = vec![
@@ -425,8 +668,8 @@ mod tests {
             let pairings_generator = SwissPairingsGenerator::new(player_lists, result_keeper);
             let pairings = pairings_generator.generate_pairings(&0).unwrap();
 
-            assert_eq!(pairings[0].get_player_color(&2), Some(PlayerColor::Black));
-            assert_eq!(pairings[0].get_player_color(&3), Some(PlayerColor::White));
+            assert_eq!(pairings[0].get_player_color(&2), Some(PlayerColor::White));
+            assert_eq!(pairings[0].get_player_color(&3), Some(PlayerColor::Black));
             assert_eq!(pairings[1].get_player_color(&4), Some(PlayerColor::Black));
             assert_eq!(pairings[1].get_player_color(&1), Some(PlayerColor::White));
         }
@@ -452,6 +695,31 @@ mod tests {
             assert_eq!(pairings_result.is_err(), true);
         }
 
+        #[test]
+        fn test_normal_round_rematch_after_forfeit() {
+            let player_lists = vec![
+                create_dummy_player(1, 1500),
+                create_dummy_player(2, 2000),
+                create_dummy_player(3, 1000),
+                create_dummy_player(4, 200),
+            ];
+            let game_matches = vec![
+                create_dummy_match(1, 2, 20, 44),
+                create_dummy_match(1, 4, -3, -4),
+                create_dummy_match(1, 3, 20, 44),
+            ];
+            let result_keeper = create_result_keeper(&game_matches);
+
+            let pairings_generator = SwissPairingsGenerator::new(player_lists, result_keeper);
+            let pairings = pairings_generator.generate_pairings(&0).unwrap();
+
+            let pairing_with_player_1 = pairings
+                .iter()
+                .find(|pairing| pairing.is_player_playing(&1))
+                .unwrap();
+            assert_eq!(pairing_with_player_1.get_opponent_id(&1), Some(4));
+        }
+
         #[test]
         fn test_normal_round_double_bye() {
             let player_lists = vec![
@@ -488,19 +756,19 @@ mod tests {
             let pairings = pairings_generator.generate_pairings(&0).unwrap();
 
             assert_eq!(
-                pairings[0].get_player_color(&4488),
+                pairings[0].get_player_color(&4491),
                 Some(PlayerColor::Black)
             );
             assert_eq!(
-                pairings[0].get_player_color(&4492),
+                pairings[0].get_player_color(&4488),
                 Some(PlayerColor::White)
             );
             assert_eq!(
-                pairings[1].get_player_color(&4491),
+                pairings[1].get_player_color(&4487),
                 Some(PlayerColor::Black)
             );
             assert_eq!(
-                pairings[1].get_player_color(&4487),
+                pairings[1].get_player_color(&4492),
                 Some(PlayerColor::White)
             );
             assert_eq!(
@@ -544,5 +812,134 @@ mod tests {
             assert_eq!(pairings[1].get_player_color(&2), Some(PlayerColor::White));
             assert_eq!(pairings[1].get_player_color(&3), Some(PlayerColor::Black));
         }
+
+        #[test]
+        fn test_normal_round_more_than_128_players() {
+            let player_count = 201;
+            let create_player_lists = || -> Vec<PlayerRowModel> {
+                (1..=player_count)
+                    .map(|id| create_dummy_player(id, 1000 + id))
+                    .collect()
+            };
+            let first_round_results = SwissPairingsGenerator::new(
+                create_player_lists(),
+                create_result_keeper(&Vec::new()),
+            )
+            .generate_pairings(&0)
+            .unwrap();
+            let game_matches: Vec<Box<dyn IGameMatch>> = first_round_results
+                .iter()
+                .map(|pairing| match pairing.get_players_id() {
+                    (Some(black_player_id), Some(white_player_id)) => {
+                        create_dummy_match(black_player_id, white_player_id, 40, 24)
+                    }
+                    (Some(player_id), None) => create_dummy_match(player_id, -1, -2, -2),
+                    _ => unreachable!(),
+                })
+                .collect();
+            let result_keeper = create_result_keeper(&game_matches);
+            let previous_result_keeper = create_result_keeper(&game_matches);
+
+            let pairings_generator =
+                SwissPairingsGenerator::new(create_player_lists(), result_keeper);
+            let pairings = pairings_generator.generate_pairings(&1).unwrap();
+
+            assert_eq!(pairings.len(), 101);
+            let mut paired_player_ids = Vec::new();
+            pairings
+                .iter()
+                .for_each(|pairing| match pairing.get_players_id() {
+                    (Some(player_1_id), Some(player_2_id)) => {
+                        assert_eq!(
+                            previous_result_keeper.has_players_met(&player_1_id, &player_2_id),
+                            false
+                        );
+                        paired_player_ids.push(player_1_id);
+                        paired_player_ids.push(player_2_id);
+                    }
+                    (Some(player_id), None) => {
+                        assert_eq!(previous_result_keeper.has_player_bye(&player_id), false);
+                        paired_player_ids.push(player_id);
+                    }
+                    _ => unreachable!(),
+                });
+            paired_player_ids.sort();
+            assert_eq!(paired_player_ids, (1..=player_count).collect::<Vec<i32>>());
+        }
+
+        #[test]
+        fn test_first_round_same_country_avoided() {
+            let player_lists = vec![
+                create_dummy_player_with_country(1, 4000, "SGP"),
+                create_dummy_player_with_country(2, 3000, "MYS"),
+                create_dummy_player_with_country(3, 2000, "SGP"),
+                create_dummy_player_with_country(4, 1000, "IDN"),
+            ];
+            let game_matches: Vec<Box<dyn IGameMatch>> = Vec::new();
+            let result_keeper = create_result_keeper(&game_matches);
+
+            let pairings_generator = SwissPairingsGenerator::new_with_constraints(
+                player_lists,
+                result_keeper,
+                vec![PairingConstraint::SameCountry],
+            );
+            let pairings = pairings_generator.generate_pairings(&0).unwrap();
+
+            assert_eq!(get_opponent_id(&pairings, &1), Some(4));
+            assert_eq!(get_opponent_id(&pairings, &2), Some(3));
+        }
+
+        #[test]
+        fn test_normal_round_same_country_avoided() {
+            let player_lists = vec![
+                create_dummy_player_with_country(1, 1500, "SGP"),
+                create_dummy_player_with_country(2, 1500, "MYS"),
+                create_dummy_player_with_country(3, 1500, "SGP"),
+                create_dummy_player_with_country(4, 1500, "IDN"),
+                create_dummy_player_with_country(5, 1500, "THA"),
+                create_dummy_player_with_country(6, 1500, "JPN"),
+            ];
+            let game_matches = vec![
+                create_dummy_match(1, 2, 44, 20),
+                create_dummy_match(3, 4, 40, 24),
+                create_dummy_match(5, 6, 36, 28),
+            ];
+            let result_keeper = create_result_keeper(&game_matches);
+
+            let pairings_generator = SwissPairingsGenerator::new_with_constraints(
+                player_lists,
+                result_keeper,
+                vec![PairingConstraint::SameCountry],
+            );
+            let pairings = pairings_generator.generate_pairings(&0).unwrap();
+
+            assert_eq!(get_opponent_id(&pairings, &1), Some(5));
+            assert_eq!(get_opponent_id(&pairings, &3), Some(6));
+            assert_eq!(get_opponent_id(&pairings, &4), Some(2));
+        }
+
+        #[test]
+        fn test_normal_round_same_country_kept_within_score_group() {
+            let player_lists = vec![
+                create_dummy_player_with_country(1, 1500, "SGP"),
+                create_dummy_player_with_country(2, 1500, "MYS"),
+                create_dummy_player_with_country(3, 1500, "SGP"),
+                create_dummy_player_with_country(4, 1500, "IDN"),
+            ];
+            let game_matches = vec![
+                create_dummy_match(1, 2, 44, 20),
+                create_dummy_match(3, 4, 40, 24),
+            ];
+            let result_keeper = create_result_keeper(&game_matches);
+
+            let pairings_generator = SwissPairingsGenerator::new_with_constraints(
+                player_lists,
+                result_keeper,
+                vec![PairingConstraint::SameCountry],
+            );
+            let pairings = pairings_generator.generate_pairings(&0).unwrap();
+
+            assert_eq!(get_opponent_id(&pairings, &1), Some(3));
+        }
     }
 }
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use crate::database_models::{PlayerRowModel, TeamRowModel};
+use crate::game_match::{GameMatchCreator, IGameMatch};
+use crate::tournament_manager::{AWAY_TEAM_ID_META_KEY, BOARD_META_KEY, HOME_TEAM_ID_META_KEY};
+
+// Teams are paired as if they were players, rated by the average rating of their boards.
+pub fn create_team_players(
+    teams: &Vec<TeamRowModel>,
+    players: &Vec<PlayerRowModel>,
+) -> Vec<PlayerRowModel> {
+    let ratings_by_player_id: HashMap<i32, i32> = players
+        .iter()
+        .map(|player| (player.id, player.rating))
+        .collect();
+    teams
+        .iter()
+        .map(|team| {
+            let board_ratings: Vec<i32> = team
+                .get_board_player_ids()
+                .iter()
+                .filter_map(|player_id| ratings_by_player_id.get(player_id).cloned())
+                .collect();
+            let rating = match board_ratings.is_empty() {
+                true => 0,
+                false => board_ratings.iter().sum::<i32>() / board_ratings.len() as i32,
+            };
+            PlayerRowModel {
+                id: team.id,
+                tournament_id: team.tournament_id,
+                joueurs_id: String::new(),
+                first_name: team.name.clone(),
+                last_name: String::new(),
+                country: String::new(),
+                rating,
+                meta_data: Value::from(Map::new()),
+            }
+        })
+        .collect()
+}
+
+fn create_board_meta_data(home_team_id: &i32, away_team_id: Option<&i32>, board: usize) -> Value {
+    let mut meta_data = Map::new();
+    meta_data.insert(
+        String::from(HOME_TEAM_ID_META_KEY),
+        Value::from(home_team_id.clone()),
+    );
+    if let Some(away_team_id) = away_team_id {
+        meta_data.insert(
+            String::from(AWAY_TEAM_ID_META_KEY),
+            Value::from(away_team_id.clone()),
+        );
+    }
+    meta_data.insert(String::from(BOARD_META_KEY), Value::from(board));
+    Value::from(meta_data)
+}
+
+fn expand_team_pairing(
+    team_pairing: &Box<dyn IGameMatch>,
+    board_player_ids_by_team_id: &HashMap<i32, Vec<i32>>,
+    round_id: &i32,
+) -> Vec<Box<dyn IGameMatch>> {
+    let empty_boards = vec![];
+    let (home_team_id, away_team_id) = team_pairing.get_players_id();
+    let home_team_id = home_team_id.unwrap_or_default();
+    let home_boards = board_player_ids_by_team_id
+        .get(&home_team_id)
+        .unwrap_or(&empty_boards);
+
+    let away_team_id = match away_team_id {
+        Some(away_team_id) => away_team_id,
+        None => {
+            return home_boards
+                .iter()
+                .enumerate()
+                .map(|(board_index, player_id)| {
+                    let meta_data = create_board_meta_data(&home_team_id, None, board_index + 1);
+                    GameMatchCreator::create_new_bye_match(round_id, player_id, &meta_data)
+                })
+                .collect()
+        }
+    };
+    let away_boards = board_player_ids_by_team_id
+        .get(&away_team_id)
+        .unwrap_or(&empty_boards);
+    home_boards
+        .iter()
+        .zip(away_boards.iter())
+        .enumerate()
+        .map(|(board_index, (home_player_id, away_player_id))| {
+            let board = board_index + 1;
+            let meta_data = create_board_meta_data(&home_team_id, Some(&away_team_id), board);
+            match board % 2 == 1 {
+                true => GameMatchCreator::create_new_match(
+                    round_id,
+                    home_player_id,
+                    away_player_id,
+                    &meta_data,
+                ),
+                false => GameMatchCreator::create_new_match(
+                    round_id,
+                    away_player_id,
+                    home_player_id,
+                    &meta_data,
+                ),
+            }
+        })
+        .collect()
+}
+
+// Every team pairing is played board by board, the home team plays black on odd boards.
+pub fn expand_team_pairings(
+    team_pairings: &Vec<Box<dyn IGameMatch>>,
+    teams: &Vec<TeamRowModel>,
+    round_id: &i32,
+) -> Vec<Box<dyn IGameMatch>> {
+    let board_player_ids_by_team_id: HashMap<i32, Vec<i32>> = teams
+        .iter()
+        .map(|team| (team.id, team.get_board_player_ids()))
+        .collect();
+    team_pairings
+        .iter()
+        .flat_map(|team_pairing| {
+            expand_team_pairing(team_pairing, &board_player_ids_by_team_id, round_id)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_team_pairings {
+        use serde_json::{Map, Value};
+
+        use crate::database_models::{PlayerRowModel, TeamRowModel};
+        use crate::game_match::GameMatchCreator;
+        use crate::pairings_generator::{create_team_players, expand_team_pairings};
+        use crate::tournament_manager::get_team_encounters;
+
+        fn create_dummy_team(id: i32, board_player_ids: Vec<i32>) -> TeamRowModel {
+            let mut team = TeamRowModel {
+                id,
+                tournament_id: 0,
+                name: format!("Team {}", id),
+                meta_data: Value::from(Map::new()),
+            };
+            team.set_board_player_ids(&board_player_ids);
+            team
+        }
+
+        fn create_dummy_player(id: i32, rating: i32) -> PlayerRowModel {
+            PlayerRowModel {
+                id,
+                tournament_id: 0,
+                joueurs_id: id.to_string(),
+                first_name: String::new(),
+                last_name: String::new(),
+                country: String::new(),
+                rating,
+                meta_data: Value::from(Map::new()),
+            }
+        }
+
+        #[test]
+        fn test_create_team_players() {
+            let teams = vec![
+                create_dummy_team(10, vec![1, 2]),
+                create_dummy_team(20, vec![]),
+            ];
+            let players = vec![create_dummy_player(1, 1800), create_dummy_player(2, 1600)];
+
+            let team_players = create_team_players(&teams, &players);
+            assert_eq!(team_players.len(), 2);
+            assert_eq!(team_players[0].id, 10);
+            assert_eq!(team_players[0].first_name, String::from("Team 10"));
+            assert_eq!(team_players[0].rating, 1700);
+            assert_eq!(team_players[1].rating, 0);
+        }
+
+        #[test]
+        fn test_expand_team_pairings() {
+            let teams = vec![
+                create_dummy_team(10, vec![1, 2, 3]),
+                create_dummy_team(20, vec![4, 5, 6]),
+                create_dummy_team(30, vec![7, 8, 9]),
+            ];
+            let team_pairings = vec![
+                GameMatchCreator::create_new_match(&0, &20, &10, &Value::from(Map::new())),
+                GameMatchCreator::create_new_bye_match(&0, &30, &Value::from(Map::new())),
+            ];
+
+            let board_matches = expand_team_pairings(&team_pairings, &teams, &5);
+            assert_eq!(board_matches.len(), 6);
+            assert_eq!(board_matches[0].get_players_id(), (Some(4), Some(1)));
+            assert_eq!(board_matches[1].get_players_id(), (Some(2), Some(5)));
+            assert_eq!(board_matches[2].get_players_id(), (Some(6), Some(3)));
+            assert_eq!(board_matches[3].get_players_id(), (Some(7), None));
+            assert_eq!(board_matches[5].get_players_id(), (Some(9), None));
+            board_matches
+                .iter()
+                .for_each(|board_match| assert_eq!(board_match.extract_data().round_id, 5));
+
+            let encounters = get_team_encounters(&board_matches);
+            assert_eq!(encounters.len(), 2);
+            assert_eq!(encounters[0].home_team_id, 20);
+            assert_eq!(encounters[0].away_team_id, Some(10));
+            assert_eq!(encounters[1].home_team_id, 30);
+            assert_eq!(encounters[1].away_team_id, None);
+        }
+    }
+}
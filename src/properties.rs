@@ -27,6 +27,8 @@ impl UserRole {
 pub enum TournamentType {
     RoundRobin,
     SwissPairing,
+    Knockout,
+    Team,
     Unidentified,
 }
 
@@ -35,6 +37,8 @@ impl TournamentType {
         match tournament_type.as_str() {
             "round_robin" => TournamentType::RoundRobin,
             "swiss_pairing" => TournamentType::SwissPairing,
+            "knockout" => TournamentType::Knockout,
+            "team" => TournamentType::Team,
             _ => TournamentType::Unidentified,
         }
     }
@@ -43,11 +47,57 @@ impl TournamentType {
         match self {
             TournamentType::RoundRobin => String::from("round_robin"),
             TournamentType::SwissPairing => String::from("swiss_pairing"),
+            TournamentType::Knockout => String::from("knockout"),
+            TournamentType::Team => String::from("team"),
             _ => String::from("unidentified"),
         }
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TournamentStatus {
+    Draft,
+    RegistrationOpen,
+    InProgress,
+    Finished,
+    Archived,
+    Unidentified,
+}
+
+impl TournamentStatus {
+    pub fn from_string(status: String) -> TournamentStatus {
+        match status.as_str() {
+            "draft" => TournamentStatus::Draft,
+            "registration_open" => TournamentStatus::RegistrationOpen,
+            "in_progress" => TournamentStatus::InProgress,
+            "finished" => TournamentStatus::Finished,
+            "archived" => TournamentStatus::Archived,
+            _ => TournamentStatus::Unidentified,
+        }
+    }
+
+    pub fn to_string(&self) -> String {
+        match self {
+            TournamentStatus::Draft => String::from("draft"),
+            TournamentStatus::RegistrationOpen => String::from("registration_open"),
+            TournamentStatus::InProgress => String::from("in_progress"),
+            TournamentStatus::Finished => String::from("finished"),
+            TournamentStatus::Archived => String::from("archived"),
+            _ => String::from("unidentified"),
+        }
+    }
+
+    pub fn get_previous_status(&self) -> Option<TournamentStatus> {
+        match self {
+            TournamentStatus::RegistrationOpen => Some(TournamentStatus::Draft),
+            TournamentStatus::InProgress => Some(TournamentStatus::RegistrationOpen),
+            TournamentStatus::Finished => Some(TournamentStatus::InProgress),
+            TournamentStatus::Archived => Some(TournamentStatus::Finished),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum RoundType {
     Unidentified,
@@ -81,6 +131,9 @@ pub enum SpecialConditionScore {
     Unidentified,
     NotFinished,
     Bye,
+    ForfeitWin,
+    ForfeitLoss,
+    DoubleForfeit,
 }
 
 impl SpecialConditionScore {
@@ -88,6 +141,9 @@ impl SpecialConditionScore {
         match round_type {
             -1 => SpecialConditionScore::NotFinished,
             -2 => SpecialConditionScore::Bye,
+            -3 => SpecialConditionScore::ForfeitWin,
+            -4 => SpecialConditionScore::ForfeitLoss,
+            -5 => SpecialConditionScore::DoubleForfeit,
             _ => SpecialConditionScore::Unidentified,
         }
     }
@@ -97,16 +153,212 @@ impl SpecialConditionScore {
             SpecialConditionScore::Unidentified => -100,
             SpecialConditionScore::NotFinished => -1,
             SpecialConditionScore::Bye => -2,
+            SpecialConditionScore::ForfeitWin => -3,
+            SpecialConditionScore::ForfeitLoss => -4,
+            SpecialConditionScore::DoubleForfeit => -5,
         }
     }
 }
 
 #[derive(Debug, PartialEq)]
+pub enum MatchResultType {
+    Normal,
+    BlackWinByForfeit,
+    WhiteWinByForfeit,
+    DoubleForfeit,
+    Adjudicated,
+    Unidentified,
+}
+
+impl MatchResultType {
+    pub fn from_string(result_type: String) -> MatchResultType {
+        match result_type.as_str() {
+            "normal" => MatchResultType::Normal,
+            "black_win_by_forfeit" => MatchResultType::BlackWinByForfeit,
+            "white_win_by_forfeit" => MatchResultType::WhiteWinByForfeit,
+            "double_forfeit" => MatchResultType::DoubleForfeit,
+            "adjudicated" => MatchResultType::Adjudicated,
+            _ => MatchResultType::Unidentified,
+        }
+    }
+
+    pub fn to_string(&self) -> String {
+        match self {
+            MatchResultType::Normal => String::from("normal"),
+            MatchResultType::BlackWinByForfeit => String::from("black_win_by_forfeit"),
+            MatchResultType::WhiteWinByForfeit => String::from("white_win_by_forfeit"),
+            MatchResultType::DoubleForfeit => String::from("double_forfeit"),
+            MatchResultType::Adjudicated => String::from("adjudicated"),
+            _ => String::from("unidentified"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum PlayerColor {
     Black,
     White,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FloatDirection {
+    Up,
+    Down,
+}
+
+impl FloatDirection {
+    pub fn to_string(&self) -> String {
+        match self {
+            FloatDirection::Up => String::from("up"),
+            FloatDirection::Down => String::from("down"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FloatReason {
+    OddScoreGroup,
+    RematchAvoidance,
+}
+
+impl FloatReason {
+    pub fn to_string(&self) -> String {
+        match self {
+            FloatReason::OddScoreGroup => String::from("odd_score_group"),
+            FloatReason::RematchAvoidance => String::from("rematch_avoidance"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GameResult {
+    Win,
+    Draw,
+    Loss,
+    Bye,
+    Unfinished,
+}
+
+impl GameResult {
+    pub fn to_string(&self) -> String {
+        match self {
+            GameResult::Win => String::from("win"),
+            GameResult::Draw => String::from("draw"),
+            GameResult::Loss => String::from("loss"),
+            GameResult::Bye => String::from("bye"),
+            GameResult::Unfinished => String::from("unfinished"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PairingConstraint {
+    SameCountry,
+    SameTeam,
+    Unidentified,
+}
+
+impl PairingConstraint {
+    pub fn from_string(pairing_constraint: String) -> PairingConstraint {
+        match pairing_constraint.as_str() {
+            "same_country" => PairingConstraint::SameCountry,
+            "same_team" => PairingConstraint::SameTeam,
+            _ => PairingConstraint::Unidentified,
+        }
+    }
+
+    pub fn to_string(&self) -> String {
+        match self {
+            PairingConstraint::SameCountry => String::from("same_country"),
+            PairingConstraint::SameTeam => String::from("same_team"),
+            _ => String::from("unidentified"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TieBreaker {
+    Brightwell,
+    DiscCount,
+    DiscDifferential,
+    Buchholz,
+    MedianBuchholz,
+    SonnebornBerger,
+    HeadToHead,
+    Wins,
+    Rating,
+    Unidentified,
+}
+
+impl TieBreaker {
+    pub fn from_string(tie_breaker: String) -> TieBreaker {
+        match tie_breaker.as_str() {
+            "brightwell" => TieBreaker::Brightwell,
+            "disc_count" => TieBreaker::DiscCount,
+            "disc_differential" => TieBreaker::DiscDifferential,
+            "buchholz" => TieBreaker::Buchholz,
+            "median_buchholz" => TieBreaker::MedianBuchholz,
+            "sonneborn_berger" => TieBreaker::SonnebornBerger,
+            "head_to_head" => TieBreaker::HeadToHead,
+            "wins" => TieBreaker::Wins,
+            "rating" => TieBreaker::Rating,
+            _ => TieBreaker::Unidentified,
+        }
+    }
+
+    pub fn to_string(&self) -> String {
+        match self {
+            TieBreaker::Brightwell => String::from("brightwell"),
+            TieBreaker::DiscCount => String::from("disc_count"),
+            TieBreaker::DiscDifferential => String::from("disc_differential"),
+            TieBreaker::Buchholz => String::from("buchholz"),
+            TieBreaker::MedianBuchholz => String::from("median_buchholz"),
+            TieBreaker::SonnebornBerger => String::from("sonneborn_berger"),
+            TieBreaker::HeadToHead => String::from("head_to_head"),
+            TieBreaker::Wins => String::from("wins"),
+            TieBreaker::Rating => String::from("rating"),
+            _ => String::from("unidentified"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TeamTieBreaker {
+    GamePoints,
+    DiscCount,
+    DiscDifferential,
+    Buchholz,
+    HeadToHead,
+    MatchWins,
+    Unidentified,
+}
+
+impl TeamTieBreaker {
+    pub fn from_string(tie_breaker: String) -> TeamTieBreaker {
+        match tie_breaker.as_str() {
+            "game_points" => TeamTieBreaker::GamePoints,
+            "disc_count" => TeamTieBreaker::DiscCount,
+            "disc_differential" => TeamTieBreaker::DiscDifferential,
+            "buchholz" => TeamTieBreaker::Buchholz,
+            "head_to_head" => TeamTieBreaker::HeadToHead,
+            "match_wins" => TeamTieBreaker::MatchWins,
+            _ => TeamTieBreaker::Unidentified,
+        }
+    }
+
+    pub fn to_string(&self) -> String {
+        match self {
+            TeamTieBreaker::GamePoints => String::from("game_points"),
+            TeamTieBreaker::DiscCount => String::from("disc_count"),
+            TeamTieBreaker::DiscDifferential => String::from("disc_differential"),
+            TeamTieBreaker::Buchholz => String::from("buchholz"),
+            TeamTieBreaker::HeadToHead => String::from("head_to_head"),
+            TeamTieBreaker::MatchWins => String::from("match_wins"),
+            _ => String::from("unidentified"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     mod test_user_role {
@@ -178,6 +430,14 @@ mod tests {
                 TournamentType::from_string(String::from("swiss_pairing")),
                 TournamentType::SwissPairing
             );
+            assert_eq!(
+                TournamentType::from_string(String::from("knockout")),
+                TournamentType::Knockout
+            );
+            assert_eq!(
+                TournamentType::from_string(String::from("team")),
+                TournamentType::Team
+            );
             assert_eq!(
                 TournamentType::from_string(String::from("")),
                 TournamentType::Unidentified
@@ -198,6 +458,11 @@ mod tests {
                 TournamentType::SwissPairing.to_string(),
                 String::from("swiss_pairing")
             );
+            assert_eq!(
+                TournamentType::Knockout.to_string(),
+                String::from("knockout")
+            );
+            assert_eq!(TournamentType::Team.to_string(), String::from("team"));
             assert_eq!(
                 TournamentType::Unidentified.to_string(),
                 String::from("unidentified")
@@ -205,6 +470,42 @@ mod tests {
         }
     }
 
+    mod test_tournament_status {
+        use crate::properties::TournamentStatus;
+
+        #[test]
+        fn test_from_and_to_string() {
+            let statuses = vec![
+                TournamentStatus::Draft,
+                TournamentStatus::RegistrationOpen,
+                TournamentStatus::InProgress,
+                TournamentStatus::Finished,
+                TournamentStatus::Archived,
+            ];
+            for status in statuses {
+                assert_eq!(TournamentStatus::from_string(status.to_string()), status);
+            }
+            assert_eq!(
+                TournamentStatus::from_string(String::from("random")),
+                TournamentStatus::Unidentified
+            );
+        }
+
+        #[test]
+        fn test_get_previous_status() {
+            assert_eq!(TournamentStatus::Draft.get_previous_status(), None);
+            assert_eq!(
+                TournamentStatus::InProgress.get_previous_status(),
+                Some(TournamentStatus::RegistrationOpen)
+            );
+            assert_eq!(
+                TournamentStatus::Archived.get_previous_status(),
+                Some(TournamentStatus::Finished)
+            );
+            assert_eq!(TournamentStatus::Unidentified.get_previous_status(), None);
+        }
+    }
+
     mod test_round_type {
         use crate::properties::RoundType;
 
@@ -239,6 +540,18 @@ mod tests {
                 SpecialConditionScore::from_i32(-2),
                 SpecialConditionScore::Bye
             );
+            assert_eq!(
+                SpecialConditionScore::from_i32(-3),
+                SpecialConditionScore::ForfeitWin
+            );
+            assert_eq!(
+                SpecialConditionScore::from_i32(-4),
+                SpecialConditionScore::ForfeitLoss
+            );
+            assert_eq!(
+                SpecialConditionScore::from_i32(-5),
+                SpecialConditionScore::DoubleForfeit
+            );
             assert_eq!(
                 SpecialConditionScore::from_i32(-100),
                 SpecialConditionScore::Unidentified
@@ -249,7 +562,110 @@ mod tests {
         fn test_to_i32() {
             assert_eq!(SpecialConditionScore::NotFinished.to_i32(), -1);
             assert_eq!(SpecialConditionScore::Bye.to_i32(), -2);
+            assert_eq!(SpecialConditionScore::ForfeitWin.to_i32(), -3);
+            assert_eq!(SpecialConditionScore::ForfeitLoss.to_i32(), -4);
+            assert_eq!(SpecialConditionScore::DoubleForfeit.to_i32(), -5);
             assert_eq!(SpecialConditionScore::Unidentified.to_i32(), -100);
         }
     }
+
+    mod test_match_result_type {
+        use crate::properties::MatchResultType;
+
+        #[test]
+        fn test_from_and_to_string() {
+            let result_types = vec![
+                "normal",
+                "black_win_by_forfeit",
+                "white_win_by_forfeit",
+                "double_forfeit",
+                "adjudicated",
+            ];
+            result_types.iter().for_each(|&result_type| {
+                assert_eq!(
+                    MatchResultType::from_string(String::from(result_type)).to_string(),
+                    String::from(result_type)
+                );
+            });
+            assert_eq!(
+                MatchResultType::from_string(String::from("random")),
+                MatchResultType::Unidentified
+            );
+        }
+    }
+
+    mod test_tie_breaker {
+        use crate::properties::TieBreaker;
+
+        #[test]
+        fn test_from_string() {
+            assert_eq!(
+                TieBreaker::from_string(String::from("brightwell")),
+                TieBreaker::Brightwell
+            );
+            assert_eq!(
+                TieBreaker::from_string(String::from("median_buchholz")),
+                TieBreaker::MedianBuchholz
+            );
+            assert_eq!(
+                TieBreaker::from_string(String::from("sonneborn_berger")),
+                TieBreaker::SonnebornBerger
+            );
+            assert_eq!(
+                TieBreaker::from_string(String::from("random")),
+                TieBreaker::Unidentified
+            );
+        }
+
+        #[test]
+        fn test_from_and_to_string() {
+            let tie_breakers = vec![
+                "brightwell",
+                "disc_count",
+                "disc_differential",
+                "buchholz",
+                "median_buchholz",
+                "sonneborn_berger",
+                "head_to_head",
+                "wins",
+                "rating",
+            ];
+            tie_breakers.iter().for_each(|&tie_breaker| {
+                assert_eq!(
+                    TieBreaker::from_string(String::from(tie_breaker)).to_string(),
+                    String::from(tie_breaker)
+                );
+            });
+            assert_eq!(
+                TieBreaker::from_string(String::from("")).to_string(),
+                String::from("unidentified")
+            );
+        }
+    }
+
+    mod test_team_tie_breaker {
+        use crate::properties::TeamTieBreaker;
+
+        #[test]
+        fn test_from_and_to_string() {
+            let tie_breakers = vec![
+                "game_points",
+                "disc_count",
+                "disc_differential",
+                "buchholz",
+                "head_to_head",
+                "match_wins",
+            ];
+            tie_breakers.iter().for_each(|&tie_breaker| {
+                assert_eq!(
+                    TeamTieBreaker::from_string(String::from(tie_breaker)).to_string(),
+                    String::from(tie_breaker)
+                );
+            });
+            assert_eq!(
+                TeamTieBreaker::from_string(String::from("brightwell")),
+                TeamTieBreaker::Unidentified
+            );
+        }
+    }
 }
@@ -1,20 +1,43 @@
+use std::io::Cursor;
+
 use diesel::prelude::*;
+use rocket::http::{ContentType, Status};
+use rocket::request::Request;
+use rocket::response;
+use rocket::response::status::Custom;
+use rocket::response::{Responder, Response};
 use rocket_contrib::json::{Json, JsonValue};
 
 use crate::errors::ErrorType;
 
+fn generate_error_response(error: &ErrorType) -> Custom<Json<JsonValue>> {
+    Custom(
+        error.to_http_status(),
+        Json(json!({
+            "success": "",
+            "error": {
+                "code": &error.to_error_code(),
+                "message": &error.to_error_message(),
+            }
+        })),
+    )
+}
+
 pub trait ResponseCommand {
-    fn execute(&self, connection: &PgConnection) -> Json<JsonValue> {
+    fn execute(&self, connection: &PgConnection) -> Custom<Json<JsonValue>> {
         match self.do_execute(connection) {
             Ok(result) => {
                 info!("Successful request for {}", self.get_request_summary());
-                Json(json!({
-                    "success": result,
-                    "error": {
-                        "code": 0,
-                        "message": "",
-                    }
-                }))
+                Custom(
+                    Status::Ok,
+                    Json(json!({
+                        "success": result,
+                        "error": {
+                            "code": 0,
+                            "message": "",
+                        }
+                    })),
+                )
             }
             Err(error) => {
                 error!(
@@ -22,13 +45,7 @@ pub trait ResponseCommand {
                     self.get_request_summary(),
                     &error.to_error_message()
                 );
-                Json(json!({
-                    "success": "",
-                    "error": {
-                        "code": &error.to_error_code(),
-                        "message": &error.to_error_message(),
-                    }
-                }))
+                generate_error_response(&error)
             }
         }
     }
@@ -37,3 +54,45 @@ pub trait ResponseCommand {
 
     fn get_request_summary(&self) -> String;
 }
+
+pub struct FileContent {
+    pub file_name: String,
+    pub content_type: ContentType,
+    pub content: String,
+}
+
+impl<'r> Responder<'r> for FileContent {
+    fn respond_to(self, _request: &Request) -> response::Result<'r> {
+        Response::build()
+            .header(self.content_type)
+            .raw_header(
+                "Content-Disposition",
+                format!("attachment; filename=\"{}\"", self.file_name),
+            )
+            .sized_body(Cursor::new(self.content))
+            .ok()
+    }
+}
+
+pub trait FileResponseCommand {
+    fn execute(&self, connection: &PgConnection) -> Result<FileContent, Custom<Json<JsonValue>>> {
+        match self.do_execute(connection) {
+            Ok(file_content) => {
+                info!("Successful request for {}", self.get_request_summary());
+                Ok(file_content)
+            }
+            Err(error) => {
+                error!(
+                    "Failed request for {}, {}",
+                    self.get_request_summary(),
+                    &error.to_error_message()
+                );
+                Err(generate_error_response(&error))
+            }
+        }
+    }
+
+    fn do_execute(&self, connection: &PgConnection) -> Result<FileContent, ErrorType>;
+
+    fn get_request_summary(&self) -> String;
+}
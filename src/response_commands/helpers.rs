@@ -1,8 +1,17 @@
+use std::collections::{HashMap, HashSet};
+use std::iter::FromIterator;
+
 use diesel::PgConnection;
 
 use crate::account::Account;
-use crate::database_models::TournamentRowModel;
+use crate::database_models::{
+    MatchDAO, MatchRowModel, PlayerRowModel, RoundDAO, RoundRowModel, TournamentRowModel,
+};
 use crate::errors::ErrorType;
+use crate::properties::TournamentStatus;
+use crate::tournament_manager::{
+    get_rating_k_factor, is_unrated_joueurs_id, RatingCalculator, StandingsConfig,
+};
 
 pub fn is_allowed_to_manage_tournament(
     account: &Account,
@@ -18,3 +27,239 @@ pub fn is_allowed_to_manage_tournament(
     let is_managed_by_account = tournament.is_managed_by(&username, connection)?;
     return Ok(is_created_by_account || is_managed_by_account);
 }
+
+pub fn check_tournament_status(
+    tournament: &TournamentRowModel,
+    allowed_statuses: &[TournamentStatus],
+) -> Result<(), ErrorType> {
+    let status = tournament.get_status();
+    if !allowed_statuses.contains(&status) {
+        return Err(ErrorType::InvalidTournamentStatus(status.to_string()));
+    }
+    Ok(())
+}
+
+pub fn get_round_in_tournament(
+    round_id: &i32,
+    tournament_id: &i32,
+    connection: &PgConnection,
+) -> Result<RoundRowModel, ErrorType> {
+    let round = RoundRowModel::get(round_id, connection)?;
+    if &round.tournament_id != tournament_id {
+        return Err(ErrorType::NotFound(
+            String::from("Round"),
+            round_id.to_string(),
+        ));
+    }
+    Ok(round)
+}
+
+pub fn get_match_in_round(
+    match_id: &i32,
+    round_id: &i32,
+    tournament_id: &i32,
+    connection: &PgConnection,
+) -> Result<MatchRowModel, ErrorType> {
+    let round = get_round_in_tournament(round_id, tournament_id, connection)?;
+    let game_match = MatchRowModel::get(match_id, connection)?;
+    if game_match.round_id != round.id {
+        return Err(ErrorType::NotFound(
+            String::from("Match"),
+            match_id.to_string(),
+        ));
+    }
+    Ok(game_match)
+}
+
+pub fn get_player_in_tournament(
+    player_id: &i32,
+    tournament_id: &i32,
+    connection: &PgConnection,
+) -> Result<PlayerRowModel, ErrorType> {
+    let player = PlayerRowModel::get(player_id, connection)?;
+    if &player.tournament_id != tournament_id {
+        return Err(ErrorType::NotFound(
+            String::from("Player"),
+            player_id.to_string(),
+        ));
+    }
+    Ok(player)
+}
+
+pub fn create_standings_config(
+    tournament: &TournamentRowModel,
+    players: &Vec<PlayerRowModel>,
+) -> StandingsConfig {
+    StandingsConfig {
+        tie_breakers: tournament.get_tie_breakers(),
+        brightwell_constant: tournament.get_brightwell_constant(),
+        ratings_by_player_id: HashMap::from_iter(
+            players
+                .iter()
+                .map(|player| (player.id.clone(), player.rating.clone())),
+        ),
+    }
+}
+
+pub fn create_rating_calculator(players: &Vec<PlayerRowModel>) -> RatingCalculator {
+    RatingCalculator {
+        ratings_by_player_id: HashMap::from_iter(
+            players
+                .iter()
+                .map(|player| (player.id.clone(), player.rating.clone())),
+        ),
+        unrated_player_ids: HashSet::from_iter(
+            players
+                .iter()
+                .filter(|player| is_unrated_joueurs_id(&player.joueurs_id))
+                .map(|player| player.id.clone()),
+        ),
+        k_factor: get_rating_k_factor(),
+    }
+}
+
+pub fn validate_section(
+    tournament: &TournamentRowModel,
+    section: &Option<String>,
+) -> Result<(), ErrorType> {
+    let sections = tournament.get_sections();
+    match section {
+        None if sections.is_empty() => Ok(()),
+        Some(section) if sections.contains(section) => Ok(()),
+        None => Err(ErrorType::BadRequestError(String::from(
+            "A section is needed for tournaments with sections",
+        ))),
+        Some(section) => Err(ErrorType::NotFound(
+            String::from("Section"),
+            section.clone(),
+        )),
+    }
+}
+
+pub fn get_section_rounds(
+    tournament_id: &i32,
+    section: &Option<String>,
+    connection: &PgConnection,
+) -> Result<Vec<RoundRowModel>, ErrorType> {
+    Ok(
+        RoundRowModel::get_all_from_tournament(tournament_id, connection)?
+            .into_iter()
+            .filter(|round| &round.get_section() == section)
+            .collect(),
+    )
+}
+
+pub fn get_section_players(
+    tournament_id: &i32,
+    section: &Option<String>,
+    connection: &PgConnection,
+) -> Result<Vec<PlayerRowModel>, ErrorType> {
+    Ok(
+        PlayerRowModel::get_all_from_tournament(tournament_id, connection)?
+            .into_iter()
+            .filter(|player| &player.get_section() == section)
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_ownership {
+        use crate::errors::ErrorType;
+        use crate::response_commands::{
+            get_match_in_round, get_player_in_tournament, get_round_in_tournament,
+        };
+        use crate::utils;
+        use crate::utils::{
+            create_mock_match_from_round, create_mock_player_from_tournament,
+            create_mock_round_from_tournament, create_mock_tournament_with_creator,
+            create_mock_user,
+        };
+
+        #[test]
+        fn test_get_round_in_tournament() {
+            let test_connection = utils::get_test_connection();
+            let user = create_mock_user(&test_connection);
+            let tournament = create_mock_tournament_with_creator(&user.username, &test_connection);
+            let other_tournament =
+                create_mock_tournament_with_creator(&user.username, &test_connection);
+            let round = create_mock_round_from_tournament(&tournament.id, &test_connection);
+
+            let result = get_round_in_tournament(&round.id, &tournament.id, &test_connection);
+            assert_eq!(result.unwrap(), round);
+            let result = get_round_in_tournament(&round.id, &other_tournament.id, &test_connection);
+            assert_eq!(
+                result,
+                Err(ErrorType::NotFound(
+                    String::from("Round"),
+                    round.id.to_string()
+                ))
+            );
+        }
+
+        #[test]
+        fn test_get_match_in_round() {
+            let test_connection = utils::get_test_connection();
+            let user = create_mock_user(&test_connection);
+            let tournament = create_mock_tournament_with_creator(&user.username, &test_connection);
+            let other_tournament =
+                create_mock_tournament_with_creator(&user.username, &test_connection);
+            let round = create_mock_round_from_tournament(&tournament.id, &test_connection);
+            let other_round = create_mock_round_from_tournament(&tournament.id, &test_connection);
+            let game_match =
+                create_mock_match_from_round(&tournament.id, &round.id, &test_connection);
+
+            let result =
+                get_match_in_round(&game_match.id, &round.id, &tournament.id, &test_connection);
+            assert_eq!(result.unwrap(), game_match);
+            let result = get_match_in_round(
+                &game_match.id,
+                &other_round.id,
+                &tournament.id,
+                &test_connection,
+            );
+            assert_eq!(
+                result,
+                Err(ErrorType::NotFound(
+                    String::from("Match"),
+                    game_match.id.to_string()
+                ))
+            );
+            let result = get_match_in_round(
+                &game_match.id,
+                &round.id,
+                &other_tournament.id,
+                &test_connection,
+            );
+            assert_eq!(
+                result,
+                Err(ErrorType::NotFound(
+                    String::from("Round"),
+                    round.id.to_string()
+                ))
+            );
+        }
+
+        #[test]
+        fn test_get_player_in_tournament() {
+            let test_connection = utils::get_test_connection();
+            let user = create_mock_user(&test_connection);
+            let tournament = create_mock_tournament_with_creator(&user.username, &test_connection);
+            let other_tournament =
+                create_mock_tournament_with_creator(&user.username, &test_connection);
+            let player = create_mock_player_from_tournament(&tournament.id, &test_connection);
+
+            let result = get_player_in_tournament(&player.id, &tournament.id, &test_connection);
+            assert_eq!(result.unwrap(), player);
+            let result =
+                get_player_in_tournament(&player.id, &other_tournament.id, &test_connection);
+            assert_eq!(
+                result,
+                Err(ErrorType::NotFound(
+                    String::from("Player"),
+                    player.id.to_string()
+                ))
+            );
+        }
+    }
+}
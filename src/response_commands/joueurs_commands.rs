@@ -0,0 +1,64 @@
+use diesel::PgConnection;
+use rocket_contrib::json::JsonValue;
+
+use crate::account::Account;
+use crate::database_models::JoueursSnapshotRowModel;
+use crate::errors::ErrorType;
+use crate::joueurs::JoueursStore;
+
+use super::ResponseCommand;
+
+pub struct GetJoueursSnapshotsCommand {
+    pub jwt: String,
+}
+
+impl ResponseCommand for GetJoueursSnapshotsCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let account = Account::login_from_jwt(&self.jwt, connection)?;
+        if !account.has_admin_access() {
+            return Err(ErrorType::PermissionDenied);
+        }
+
+        let snapshots_meta: Vec<JsonValue> =
+            JoueursSnapshotRowModel::get_all_summaries(connection)?
+                .into_iter()
+                .map(|(id, source, fetched_at)| {
+                    json!({
+                        "id": id,
+                        "source": source,
+                        "fetched_at": fetched_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    })
+                })
+                .collect();
+        Ok(json!({ "joueurs_snapshots": snapshots_meta }))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from("GetJoueursSnapshots")
+    }
+}
+
+pub struct UploadJoueursCommand {
+    pub jwt: String,
+    pub joueurs: String,
+}
+
+impl ResponseCommand for UploadJoueursCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let account = Account::login_from_jwt(&self.jwt, connection)?;
+        if !account.has_superuser_access() {
+            return Err(ErrorType::PermissionDenied);
+        }
+
+        let snapshot = JoueursStore::store(&String::from("upload"), &self.joueurs, connection)?;
+        Ok(json!({
+            "message": "Joueurs has been uploaded.",
+            "joueurs_snapshot_id": snapshot.id,
+            "players_count": snapshot.get_players().len(),
+        }))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from("UploadJoueurs")
+    }
+}
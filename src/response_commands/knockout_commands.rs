@@ -0,0 +1,294 @@
+use std::collections::HashSet;
+
+use diesel::result::Error;
+use diesel::{Connection, PgConnection};
+use itertools::Itertools;
+use rocket_contrib::json::JsonValue;
+use serde_json::{Map, Value};
+
+use crate::account::Account;
+use crate::database_models::{
+    MatchDAO, MatchRowModel, PlayerRowModel, RoundDAO, RoundRowModel, TournamentRowModel,
+};
+use crate::errors::ErrorType;
+use crate::game_match::{GameMatchTransformer, IGameMatch};
+use crate::pairings_generator::KnockoutPairingsGenerator;
+use crate::properties::{RoundType, TournamentStatus, TournamentType};
+use crate::tournament_manager::create_result_keeper_with_config;
+
+use super::{
+    check_tournament_status, create_standings_config, get_player_in_tournament,
+    is_allowed_to_manage_tournament, ResponseCommand,
+};
+
+fn get_knockout_stage(round: &RoundRowModel) -> Option<usize> {
+    round
+        .meta_data
+        .get("knockout_stage")
+        .and_then(|knockout_stage| knockout_stage.as_u64())
+        .map(|knockout_stage| knockout_stage as usize)
+}
+
+pub fn create_knockout_pairings_generator(
+    tournament_model: &TournamentRowModel,
+    connection: &PgConnection,
+) -> Result<KnockoutPairingsGenerator, ErrorType> {
+    let knockout_round_ids: HashSet<i32> =
+        RoundRowModel::get_all_from_tournament(&tournament_model.id, connection)?
+            .iter()
+            .filter(|round| get_knockout_stage(round).is_some())
+            .map(|round| round.id)
+            .collect();
+    let past_matches: Vec<Box<dyn IGameMatch>> =
+        MatchRowModel::get_all_from_tournament(&tournament_model.id, connection)?
+            .iter()
+            .filter(|game_match| knockout_round_ids.contains(&game_match.round_id))
+            .map(|game_match| GameMatchTransformer::transform_to_game_match(game_match))
+            .collect();
+    Ok(KnockoutPairingsGenerator::new(
+        tournament_model.get_knockout_seeds(),
+        past_matches,
+        tournament_model.get_knockout_best_of(),
+        tournament_model.has_knockout_third_place_match(),
+    ))
+}
+
+// Creates the next game of every tie that is waiting for one, each in the round of its stage.
+pub fn advance_knockout_bracket(
+    tournament_model: &TournamentRowModel,
+    connection: &PgConnection,
+) -> Result<(), ErrorType> {
+    let pairings_generator = create_knockout_pairings_generator(tournament_model, connection)?;
+    let mut rounds = RoundRowModel::get_all_from_tournament(&tournament_model.id, connection)?;
+
+    for (stage, stage_ties) in &pairings_generator
+        .get_pending_ties()
+        .into_iter()
+        .group_by(|tie| tie.stage)
+    {
+        let existing_round = rounds
+            .iter()
+            .find(|round| get_knockout_stage(round) == Some(stage));
+        let round_id = match existing_round {
+            Some(round) => round.id,
+            None => {
+                let mut meta_data = Map::new();
+                meta_data.insert(String::from("knockout_stage"), Value::from(stage));
+                let round = RoundRowModel::create(
+                    &tournament_model.id,
+                    &pairings_generator.get_stage_name(&stage),
+                    RoundType::Automatic,
+                    meta_data,
+                    connection,
+                )?;
+                let round_id = round.id;
+                rounds.push(round);
+                round_id
+            }
+        };
+
+        let games: Vec<Box<dyn IGameMatch>> = stage_ties
+            .map(|tie| pairings_generator.create_next_game(&tie, &round_id))
+            .collect();
+        MatchRowModel::bulk_create_from(&games, connection)?;
+    }
+    Ok(())
+}
+
+pub struct SeedKnockoutTournamentCommand {
+    pub jwt: String,
+    pub tournament_id: i32,
+    pub player_ids: Option<Vec<i32>>,
+    pub source_tournament_id: Option<i32>,
+    pub player_count: Option<i32>,
+}
+
+impl SeedKnockoutTournamentCommand {
+    fn validate_player_ids(
+        &self,
+        player_ids: &Vec<i32>,
+        connection: &PgConnection,
+    ) -> Result<(), ErrorType> {
+        for player_id in player_ids {
+            get_player_in_tournament(player_id, &self.tournament_id, connection)?;
+        }
+        if player_ids.iter().unique().count() != player_ids.len() {
+            return Err(ErrorType::BadRequestError(String::from(
+                "A player can only be seeded once",
+            )));
+        }
+        Ok(())
+    }
+
+    fn get_source_standings(
+        &self,
+        source_tournament_id: &i32,
+        connection: &PgConnection,
+    ) -> Result<(Vec<PlayerRowModel>, Vec<i32>), ErrorType> {
+        let source_tournament = TournamentRowModel::get(source_tournament_id, connection)?;
+        let source_players =
+            PlayerRowModel::get_all_from_tournament(source_tournament_id, connection)?;
+        let source_matches: Vec<Box<dyn IGameMatch>> =
+            MatchRowModel::get_all_from_tournament(source_tournament_id, connection)?
+                .iter()
+                .map(|game_match| GameMatchTransformer::transform_to_game_match(game_match))
+                .collect();
+        let standings_config = create_standings_config(&source_tournament, &source_players);
+        let standings =
+            create_result_keeper_with_config(&source_matches, &standings_config).get_standings();
+        Ok((source_players, standings))
+    }
+
+    // Seeds follow the final standings of the source tournament. Players who are not in this
+    // tournament yet are copied over.
+    fn get_seeds_from_source_tournament(
+        &self,
+        source_tournament_id: &i32,
+        connection: &PgConnection,
+    ) -> Result<Vec<i32>, ErrorType> {
+        let (source_players, standings) =
+            self.get_source_standings(source_tournament_id, connection)?;
+
+        let player_count = self
+            .player_count
+            .map_or(standings.len(), |player_count| player_count as usize);
+        let players = PlayerRowModel::get_all_from_tournament(&self.tournament_id, connection)?;
+
+        let mut seeds = Vec::new();
+        for source_player_id in standings.iter().take(player_count) {
+            let source_player = match source_players
+                .iter()
+                .find(|source_player| &source_player.id == source_player_id)
+            {
+                Some(source_player) => source_player,
+                None => continue,
+            };
+            let existing_player = players
+                .iter()
+                .find(|player| player.joueurs_id == source_player.joueurs_id);
+            let player_id = match existing_player {
+                Some(player) => player.id,
+                None => {
+                    PlayerRowModel::create(
+                        &self.tournament_id,
+                        &source_player.to_player(),
+                        Map::new(),
+                        connection,
+                    )?
+                    .id
+                }
+            };
+            seeds.push(player_id);
+        }
+        Ok(seeds)
+    }
+
+    fn is_seed_source_valid(&self) -> bool {
+        match (&self.player_ids, &self.source_tournament_id) {
+            (Some(player_ids), None) => player_ids.len() >= 2,
+            (None, Some(_)) => self
+                .player_count
+                .map_or(true, |player_count| player_count >= 2),
+            _ => false,
+        }
+    }
+
+    fn seed_bracket(
+        &self,
+        tournament_model: &mut TournamentRowModel,
+        connection: &PgConnection,
+    ) -> Result<(), ErrorType> {
+        let seeds = match (&self.player_ids, &self.source_tournament_id) {
+            (Some(player_ids), _) => player_ids.clone(),
+            (None, Some(source_tournament_id)) => {
+                self.get_seeds_from_source_tournament(source_tournament_id, connection)?
+            }
+            (None, None) => vec![],
+        };
+        if seeds.len() < 2 {
+            return Err(ErrorType::BadRequestError(String::from(
+                "A knockout bracket needs at least 2 players",
+            )));
+        }
+
+        tournament_model.set_knockout_seeds(&seeds);
+        let updated_tournament_model = tournament_model.update(connection)?;
+        advance_knockout_bracket(&updated_tournament_model, connection)
+    }
+}
+
+impl ResponseCommand for SeedKnockoutTournamentCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let account = Account::login_from_jwt(&self.jwt, connection)?;
+        let mut tournament_model = TournamentRowModel::get(&self.tournament_id, connection)?;
+
+        if tournament_model.tournament_type != TournamentType::Knockout.to_string() {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Seeding is only available for Knockout tournaments",
+            )));
+        }
+
+        let is_allowed_to_manage =
+            is_allowed_to_manage_tournament(&account, &tournament_model, connection)?;
+        if !is_allowed_to_manage {
+            return Err(ErrorType::PermissionDenied);
+        }
+        check_tournament_status(&tournament_model, &[TournamentStatus::InProgress])?;
+
+        let rounds = RoundRowModel::get_all_from_tournament(&self.tournament_id, connection)?;
+        if !rounds.is_empty() {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Knockout bracket has already started",
+            )));
+        }
+
+        if !self.is_seed_source_valid() {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Seeds need either at least 2 players or a source tournament",
+            )));
+        }
+        if let Some(player_ids) = &self.player_ids {
+            self.validate_player_ids(player_ids, connection)?;
+        }
+        if let Some(source_tournament_id) = &self.source_tournament_id {
+            let source_status =
+                TournamentRowModel::get(source_tournament_id, connection)?.get_status();
+            if ![TournamentStatus::Finished, TournamentStatus::Archived].contains(&source_status) {
+                return Err(ErrorType::BadRequestError(String::from(
+                    "Source tournament must be finished before seeding",
+                )));
+            }
+            let (_, standings) = self.get_source_standings(source_tournament_id, connection)?;
+            if standings.len() < 2 {
+                return Err(ErrorType::BadRequestError(String::from(
+                    "Source tournament needs at least 2 players in its standings",
+                )));
+            }
+        }
+
+        let mut seed_error = None;
+        if let Err(_) = connection.transaction::<(), Error, _>(|| {
+            match self.seed_bracket(&mut tournament_model, connection) {
+                Ok(()) => Ok(()),
+                Err(e) => {
+                    seed_error = Some(e);
+                    Err(Error::RollbackTransaction)
+                }
+            }?;
+            Ok(())
+        }) {
+            return Err(seed_error.unwrap_or(ErrorType::UnknownError(String::from(
+                "Error from seeding knockout bracket",
+            ))));
+        }
+
+        Ok(json!({"message": "Knockout bracket is seeded."}))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!(
+            "SeedKnockoutTournament for {}",
+            &self.tournament_id
+        ))
+    }
+}
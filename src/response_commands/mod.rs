@@ -1,14 +1,32 @@
-pub use command_trait::ResponseCommand;
+pub use command_trait::{FileContent, FileResponseCommand, ResponseCommand};
 pub use general_commands::{CurrentUserCommand, LoginCommand};
-pub use helpers::is_allowed_to_manage_tournament;
+pub use helpers::{
+    check_tournament_status, create_rating_calculator, create_standings_config, get_match_in_round,
+    get_player_in_tournament, get_round_in_tournament, get_section_players, get_section_rounds,
+    is_allowed_to_manage_tournament, validate_section,
+};
+pub use joueurs_commands::{GetJoueursSnapshotsCommand, UploadJoueursCommand};
+pub use knockout_commands::{
+    advance_knockout_bracket, create_knockout_pairings_generator, SeedKnockoutTournamentCommand,
+};
 pub use player_commands::{
-    AddTournamentPlayerCommand, AddTournamentPlayerNewCommand, DeleteTournamentPlayerCommand,
+    AddLateTournamentPlayerCommand, AddTournamentPlayerCommand, AddTournamentPlayerNewCommand,
+    CancelTournamentPlayerByeCommand, DeleteTournamentPlayerCommand, GetPlayerGamesCommand,
     GetTournamentJoueursPlayersCommand, GetTournamentPlayersCommand,
+    RefreshTournamentJoueursCommand, RequestTournamentPlayerByeCommand,
+    UpdateTournamentPlayerCommand, WithdrawTournamentPlayerCommand,
 };
 pub use round_match_commands::{
-    CreateAutomaticRoundCommand, CreateManualNormalRoundCommand, CreateManualSpecialRoundCommand,
-    DeleteRoundCommand, GetRoundCommand, GetRoundMatchesCommand, GetStandingsCommand,
-    GetTournamentRoundsCommand, UpdateMatchCommand, UpdateRoundCommand,
+    CommitAutomaticRoundCommand, CreateAutomaticRoundCommand, CreateManualNormalRoundCommand,
+    CreateManualSpecialRoundCommand, DeleteRoundCommand, GetRoundCommand, GetRoundMatchesCommand,
+    GetRoundRobinScheduleCommand, GetStandingsCommand, GetTournamentRoundsCommand,
+    PreviewAutomaticRoundCommand, UpdateMatchCommand, UpdateMatchTranscriptCommand,
+    UpdateRoundCommand,
+};
+pub use team_commands::{
+    generate_team_pairings, generate_team_standings_meta, validate_team_boards,
+    AddTournamentTeamCommand, DeleteTournamentTeamCommand, GetTeamStandingsCommand,
+    GetTournamentTeamsCommand, UpdateTournamentTeamCommand,
 };
 pub use tournament_admin_commands::{
     AddAdminCommand, GetAllAdminsCommand, GetAllManagedTournamentsCommand,
@@ -17,15 +35,22 @@ pub use tournament_admin_commands::{
 pub use tournament_commands::{
     CreateTournamentCommand, DeleteTournamentCommand, GetAllCreatedTournamentsCommand,
     GetAllTournamentsCommand, GetTournamentCommand, UpdateTournamentCommand,
+    UpdateTournamentStatusCommand,
+};
+pub use tournament_summary_commands::{
+    ExportCrosstableCsvCommand, ExportCrosstableHtmlCommand, ExportWofResultsCommand,
+    GetCrosstableCommand, GetTournamentRatingChangesCommand, GetTournamentSummaryCommand,
 };
-pub use tournament_summary_commands::GetTournamentSummaryCommand;
 pub use user_commands::{CreateUserCommand, GetUserCommand, UpdateUserCommand};
 
 mod command_trait;
 mod general_commands;
 mod helpers;
+mod joueurs_commands;
+mod knockout_commands;
 mod player_commands;
 mod round_match_commands;
+mod team_commands;
 mod tournament_admin_commands;
 mod tournament_commands;
 mod tournament_summary_commands;
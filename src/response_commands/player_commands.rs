@@ -1,15 +1,37 @@
-use diesel::PgConnection;
+use diesel::result::Error;
+use diesel::{Connection, PgConnection};
+use itertools::Itertools;
 use rocket_contrib::json::JsonValue;
 use serde_json::{Map, Value};
 
 use crate::account::Account;
-use crate::database_models::{PlayerRowModel, RoundDAO, RoundRowModel, TournamentRowModel};
+use crate::database_models::{
+    JoueursSnapshotRowModel, MatchDAO, MatchRowModel, PlayerRowModel, RoundDAO, RoundRowModel,
+    TeamRowModel, TournamentRowModel,
+};
 use crate::errors::ErrorType;
-use crate::meta_generator::generate_players_meta;
-use crate::tournament_manager::Player;
+use crate::game_match::{GameMatchCreator, IGameMatch};
+use crate::joueurs::{generate_player_change_meta, is_player_changed, JoueursDiff, JoueursStore};
+use crate::meta_generator::{generate_matches_meta, generate_players_meta};
+use crate::properties::{TournamentStatus, TournamentType};
+use crate::tournament_manager::{Player, RequestedBye};
 use crate::utils::generate_random_string;
 
-use super::{is_allowed_to_manage_tournament, ResponseCommand};
+use super::{
+    check_tournament_status, get_player_in_tournament, get_section_rounds,
+    is_allowed_to_manage_tournament, validate_section, ResponseCommand,
+};
+
+fn is_board_player(
+    player_id: &i32,
+    tournament_id: &i32,
+    connection: &PgConnection,
+) -> Result<bool, ErrorType> {
+    let is_board_player = TeamRowModel::get_all_from_tournament(tournament_id, connection)?
+        .iter()
+        .any(|team| team.get_board_player_ids().contains(player_id));
+    Ok(is_board_player)
+}
 
 pub struct GetTournamentPlayersCommand {
     pub tournament_id: i32,
@@ -37,7 +59,7 @@ pub struct GetTournamentJoueursPlayersCommand {
 impl ResponseCommand for GetTournamentJoueursPlayersCommand {
     fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
         let tournament_model = TournamentRowModel::get(&self.tournament_id, connection)?;
-        let joueurs_players = tournament_model.get_players_from_joueurs()?;
+        let joueurs_players = tournament_model.get_players_from_joueurs(connection)?;
         let joueurs_players_meta: Vec<Map<String, Value>> = joueurs_players
             .iter()
             .map(|player| player.to_serdemap())
@@ -56,6 +78,162 @@ impl ResponseCommand for GetTournamentJoueursPlayersCommand {
     }
 }
 
+pub struct RefreshTournamentJoueursCommand {
+    pub jwt: String,
+    pub tournament_id: i32,
+    pub joueurs_snapshot_id: Option<i32>,
+}
+
+impl RefreshTournamentJoueursCommand {
+    fn get_registered_player_updates(
+        &self,
+        joueurs_snapshot: &JoueursSnapshotRowModel,
+        connection: &PgConnection,
+    ) -> Result<Vec<(PlayerRowModel, Player)>, ErrorType> {
+        let mut joueurs_players = joueurs_snapshot.get_players();
+        let registered_players =
+            PlayerRowModel::get_all_from_tournament(&self.tournament_id, connection)?;
+        Ok(registered_players
+            .into_iter()
+            .filter_map(|registered_player| {
+                let position = joueurs_players
+                    .iter()
+                    .position(|player| player.joueurs_id == registered_player.joueurs_id)?;
+                let joueurs_player = joueurs_players.swap_remove(position);
+                if is_player_changed(&registered_player.to_player(), &joueurs_player) {
+                    Some((registered_player, joueurs_player))
+                } else {
+                    None
+                }
+            })
+            .collect())
+    }
+}
+
+impl ResponseCommand for RefreshTournamentJoueursCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let account = Account::login_from_jwt(&self.jwt, connection)?;
+        let mut tournament_model = TournamentRowModel::get(&self.tournament_id, connection)?;
+
+        let is_allowed_to_manage =
+            is_allowed_to_manage_tournament(&account, &tournament_model, connection)?;
+        if !is_allowed_to_manage {
+            return Err(ErrorType::PermissionDenied);
+        }
+
+        check_tournament_status(
+            &tournament_model,
+            &[TournamentStatus::Draft, TournamentStatus::RegistrationOpen],
+        )?;
+
+        let joueurs_snapshot = match &self.joueurs_snapshot_id {
+            Some(joueurs_snapshot_id) => {
+                JoueursSnapshotRowModel::get(joueurs_snapshot_id, connection)?
+            }
+            None => JoueursStore::refresh_snapshot(connection)?,
+        };
+        // A stale preview must not roll the players back to an older list.
+        let current_joueurs_snapshot =
+            JoueursSnapshotRowModel::get(&tournament_model.joueurs_snapshot_id, connection)?;
+        if (&joueurs_snapshot.fetched_at, &joueurs_snapshot.id)
+            < (
+                &current_joueurs_snapshot.fetched_at,
+                &current_joueurs_snapshot.id,
+            )
+        {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Joueurs snapshot is older than the one used by the tournament",
+            )));
+        }
+        let registered_player_updates =
+            self.get_registered_player_updates(&joueurs_snapshot, connection)?;
+        let registered_players_meta: Vec<Value> = registered_player_updates
+            .iter()
+            .map(|(registered_player, joueurs_player)| {
+                generate_player_change_meta(&registered_player.to_player(), joueurs_player)
+            })
+            .collect();
+
+        if self.joueurs_snapshot_id.is_none() {
+            let diff = JoueursDiff::between(
+                tournament_model.get_players_from_joueurs(connection)?,
+                joueurs_snapshot.get_players(),
+            );
+            return Ok(json!({
+                "tournament_id": &self.tournament_id,
+                "joueurs_snapshot_id": joueurs_snapshot.id,
+                "diff": diff.to_serdemap(),
+                "registered_players": registered_players_meta,
+            }));
+        }
+
+        tournament_model.joueurs_snapshot_id = joueurs_snapshot.id.clone();
+        if let Err(_) = connection.transaction::<(), Error, _>(|| {
+            tournament_model
+                .update(connection)
+                .map_err(|_| Error::RollbackTransaction)?;
+            for (registered_player, joueurs_player) in registered_player_updates.into_iter() {
+                let mut updated_player = registered_player;
+                updated_player.first_name = joueurs_player.first_name;
+                updated_player.last_name = joueurs_player.last_name;
+                updated_player.rating = joueurs_player.rating;
+                updated_player
+                    .update(connection)
+                    .map_err(|_| Error::RollbackTransaction)?;
+            }
+            Ok(())
+        }) {
+            return Err(ErrorType::DatabaseError);
+        }
+
+        Ok(json!({
+            "message": "Tournament joueurs has been refreshed.",
+            "joueurs_snapshot_id": joueurs_snapshot.id,
+            "registered_players": registered_players_meta,
+        }))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!(
+            "RefreshTournamentJoueurs for {}",
+            &self.tournament_id
+        ))
+    }
+}
+
+pub struct GetPlayerGamesCommand {
+    pub tournament_id: i32,
+    pub player_id: i32,
+}
+
+impl ResponseCommand for GetPlayerGamesCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        get_player_in_tournament(&self.player_id, &self.tournament_id, connection)?;
+
+        let matches = MatchRowModel::get_all_from_tournament(&self.tournament_id, connection)?;
+        let player_matches = matches
+            .into_iter()
+            .filter(|game_match| {
+                game_match.black_player_id == self.player_id
+                    || game_match.white_player_id == self.player_id
+            })
+            .sorted_by_key(|game_match| (game_match.round_id, game_match.id))
+            .collect();
+        Ok(json!({
+            "tournament_id": &self.tournament_id,
+            "player_id": &self.player_id,
+            "games": generate_matches_meta(player_matches),
+        }))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!(
+            "GetPlayerGames for {} in tournament {}",
+            &self.player_id, &self.tournament_id
+        ))
+    }
+}
+
 pub struct AddTournamentPlayerCommand {
     pub jwt: String,
     pub tournament_id: i32,
@@ -73,15 +251,12 @@ impl ResponseCommand for AddTournamentPlayerCommand {
             return Err(ErrorType::PermissionDenied);
         }
 
-        let tournament_rounds =
-            RoundRowModel::get_all_from_tournament(&self.tournament_id, connection)?;
-        if tournament_rounds.len() > 0 {
-            return Err(ErrorType::BadRequestError(String::from(
-                "You cannot add a player in an ongoing tournament",
-            )));
-        }
+        check_tournament_status(
+            &tournament_model,
+            &[TournamentStatus::Draft, TournamentStatus::RegistrationOpen],
+        )?;
 
-        let player = tournament_model.get_player_with_joueurs_id(&self.joueurs_id)?;
+        let player = tournament_model.get_player_with_joueurs_id(&self.joueurs_id, connection)?;
         PlayerRowModel::create(&self.tournament_id, &player, Map::new(), connection)?;
         Ok(json!({"message": "Player added to tournament."}))
     }
@@ -135,13 +310,10 @@ impl ResponseCommand for AddTournamentPlayerNewCommand {
             return Err(ErrorType::PermissionDenied);
         }
 
-        let tournament_rounds =
-            RoundRowModel::get_all_from_tournament(&self.tournament_id, connection)?;
-        if tournament_rounds.len() > 0 {
-            return Err(ErrorType::BadRequestError(String::from(
-                "You cannot add a player in an ongoing tournament",
-            )));
-        }
+        check_tournament_status(
+            &tournament_model,
+            &[TournamentStatus::Draft, TournamentStatus::RegistrationOpen],
+        )?;
 
         self.try_create_player(connection, 3)?;
         Ok(json!({"message": "Player (new) added to tournament"}))
@@ -156,6 +328,336 @@ impl ResponseCommand for AddTournamentPlayerNewCommand {
     }
 }
 
+pub struct AddLateTournamentPlayerCommand {
+    pub jwt: String,
+    pub tournament_id: i32,
+    pub joueurs_id: String,
+    pub section: Option<String>,
+    pub bye_major_score: f64,
+}
+
+impl AddLateTournamentPlayerCommand {
+    // The late player gets a bye with the configured score for every round of their section
+    // played so far.
+    fn add_late_player(
+        &self,
+        tournament_model: &TournamentRowModel,
+        connection: &PgConnection,
+    ) -> Result<(), ErrorType> {
+        let player = tournament_model.get_player_with_joueurs_id(&self.joueurs_id, connection)?;
+        let mut player_model =
+            PlayerRowModel::create(&self.tournament_id, &player, Map::new(), connection)?;
+        if self.section.is_some() {
+            player_model.set_section(&self.section);
+            player_model = player_model.update(connection)?;
+        }
+        let disc_count = match self.bye_major_score > 0.0 {
+            true => 32.0,
+            false => 0.0,
+        };
+        let missed_byes: Vec<Box<dyn IGameMatch>> =
+            get_section_rounds(&self.tournament_id, &self.section, connection)?
+                .iter()
+                .map(|round| {
+                    GameMatchCreator::create_new_bye_match_with_score(
+                        &round.id,
+                        &player_model.id,
+                        &self.bye_major_score,
+                        &disc_count,
+                        &Value::from(Map::new()),
+                    )
+                })
+                .collect();
+        MatchRowModel::bulk_create_from(&missed_byes, connection)?;
+        Ok(())
+    }
+}
+
+impl ResponseCommand for AddLateTournamentPlayerCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let account = Account::login_from_jwt(&self.jwt, connection)?;
+        let tournament_model = TournamentRowModel::get(&self.tournament_id, connection)?;
+
+        if tournament_model.tournament_type != TournamentType::SwissPairing.to_string() {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Late entry is only available for Swiss Pairing tournaments",
+            )));
+        }
+
+        let is_allowed_to_manage =
+            is_allowed_to_manage_tournament(&account, &tournament_model, connection)?;
+        if !is_allowed_to_manage {
+            return Err(ErrorType::PermissionDenied);
+        }
+        check_tournament_status(&tournament_model, &[TournamentStatus::InProgress])?;
+
+        if self.bye_major_score != 0.0 && self.bye_major_score != 0.5 {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Late entry byes are worth either 0 or 0.5 points",
+            )));
+        }
+        validate_section(&tournament_model, &self.section)?;
+
+        let mut late_player_error = None;
+        if let Err(_) = connection.transaction::<(), Error, _>(|| {
+            match self.add_late_player(&tournament_model, connection) {
+                Ok(()) => Ok(()),
+                Err(e) => {
+                    late_player_error = Some(e);
+                    Err(Error::RollbackTransaction)
+                }
+            }?;
+            Ok(())
+        }) {
+            return Err(
+                late_player_error.unwrap_or(ErrorType::UnknownError(String::from(
+                    "Error from adding late player",
+                ))),
+            );
+        }
+
+        Ok(json!({"message": "Late player added to tournament."}))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!(
+            "AddLateTournamentPlayer with joueurs id {} for tournament id {}",
+            &self.joueurs_id, &self.tournament_id
+        ))
+    }
+}
+
+pub struct WithdrawTournamentPlayerCommand {
+    pub jwt: String,
+    pub tournament_id: i32,
+    pub player_id: i32,
+}
+
+impl ResponseCommand for WithdrawTournamentPlayerCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let account = Account::login_from_jwt(&self.jwt, connection)?;
+        let tournament_model = TournamentRowModel::get(&self.tournament_id, connection)?;
+
+        let is_allowed_to_manage =
+            is_allowed_to_manage_tournament(&account, &tournament_model, connection)?;
+        if !is_allowed_to_manage {
+            return Err(ErrorType::PermissionDenied);
+        }
+        check_tournament_status(&tournament_model, &[TournamentStatus::InProgress])?;
+
+        let mut player_model =
+            get_player_in_tournament(&self.player_id, &self.tournament_id, connection)?;
+        if player_model.is_withdrawn() {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Player has already withdrawn",
+            )));
+        }
+        // Team pairings always fill every board, so board players cannot leave the schedule.
+        if is_board_player(&self.player_id, &self.tournament_id, connection)? {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Player is playing for a team and cannot withdraw",
+            )));
+        }
+        player_model.set_withdrawn(&true);
+        player_model.update(connection)?;
+
+        Ok(json!({"message": "Player withdrawn"}))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!(
+            "WithdrawTournamentPlayer with player id {} for tournament id {}",
+            &self.player_id, &self.tournament_id,
+        ))
+    }
+}
+
+pub struct RequestTournamentPlayerByeCommand {
+    pub jwt: String,
+    pub tournament_id: i32,
+    pub player_id: i32,
+    pub requested_bye: RequestedBye,
+}
+
+impl ResponseCommand for RequestTournamentPlayerByeCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let account = Account::login_from_jwt(&self.jwt, connection)?;
+        let tournament_model = TournamentRowModel::get(&self.tournament_id, connection)?;
+
+        let is_allowed_to_manage =
+            is_allowed_to_manage_tournament(&account, &tournament_model, connection)?;
+        if !is_allowed_to_manage {
+            return Err(ErrorType::PermissionDenied);
+        }
+        check_tournament_status(
+            &tournament_model,
+            &[
+                TournamentStatus::Draft,
+                TournamentStatus::RegistrationOpen,
+                TournamentStatus::InProgress,
+            ],
+        )?;
+
+        if !self.requested_bye.is_valid() {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Invalid requested bye, major score must be 0, 0.5 or 1 and disc count 0 to 64",
+            )));
+        }
+        let mut player_model =
+            get_player_in_tournament(&self.player_id, &self.tournament_id, connection)?;
+        if is_board_player(&self.player_id, &self.tournament_id, connection)? {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Player is playing for a team and cannot request a bye",
+            )));
+        }
+        let rounds =
+            get_section_rounds(&self.tournament_id, &player_model.get_section(), connection)?;
+        if self.requested_bye.round_number <= rounds.len() as i32 {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Byes can only be requested for upcoming rounds",
+            )));
+        }
+
+        let mut requested_byes: Vec<RequestedBye> = player_model
+            .get_requested_byes()
+            .into_iter()
+            .filter(|requested_bye| requested_bye.round_number != self.requested_bye.round_number)
+            .collect();
+        requested_byes.push(self.requested_bye.clone());
+        requested_byes.sort_by_key(|requested_bye| requested_bye.round_number);
+        player_model.set_requested_byes(&requested_byes);
+        player_model.update(connection)?;
+
+        Ok(json!({"message": "Bye requested"}))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!(
+            "RequestTournamentPlayerBye for round {} with player id {} for tournament id {}",
+            &self.requested_bye.round_number, &self.player_id, &self.tournament_id,
+        ))
+    }
+}
+
+pub struct CancelTournamentPlayerByeCommand {
+    pub jwt: String,
+    pub tournament_id: i32,
+    pub player_id: i32,
+    pub round_number: i32,
+}
+
+impl ResponseCommand for CancelTournamentPlayerByeCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let account = Account::login_from_jwt(&self.jwt, connection)?;
+        let tournament_model = TournamentRowModel::get(&self.tournament_id, connection)?;
+
+        let is_allowed_to_manage =
+            is_allowed_to_manage_tournament(&account, &tournament_model, connection)?;
+        if !is_allowed_to_manage {
+            return Err(ErrorType::PermissionDenied);
+        }
+        check_tournament_status(
+            &tournament_model,
+            &[
+                TournamentStatus::Draft,
+                TournamentStatus::RegistrationOpen,
+                TournamentStatus::InProgress,
+            ],
+        )?;
+
+        let mut player_model =
+            get_player_in_tournament(&self.player_id, &self.tournament_id, connection)?;
+        if player_model.get_requested_bye(&self.round_number).is_none() {
+            return Err(ErrorType::NotFound(
+                String::from("Requested bye for round"),
+                self.round_number.to_string(),
+            ));
+        }
+        let rounds =
+            get_section_rounds(&self.tournament_id, &player_model.get_section(), connection)?;
+        if self.round_number <= rounds.len() as i32 {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Byes can only be cancelled for upcoming rounds",
+            )));
+        }
+        let requested_byes: Vec<RequestedBye> = player_model
+            .get_requested_byes()
+            .into_iter()
+            .filter(|requested_bye| requested_bye.round_number != self.round_number)
+            .collect();
+        player_model.set_requested_byes(&requested_byes);
+        player_model.update(connection)?;
+
+        Ok(json!({"message": "Requested bye cancelled"}))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!(
+            "CancelTournamentPlayerBye for round {} with player id {} for tournament id {}",
+            &self.round_number, &self.player_id, &self.tournament_id,
+        ))
+    }
+}
+
+pub struct UpdateTournamentPlayerCommand {
+    pub jwt: String,
+    pub tournament_id: i32,
+    pub player_id: i32,
+    pub updated_team: Option<String>,
+    pub updated_section: Option<String>,
+}
+
+impl ResponseCommand for UpdateTournamentPlayerCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let account = Account::login_from_jwt(&self.jwt, connection)?;
+        let tournament_model = TournamentRowModel::get(&self.tournament_id, connection)?;
+
+        let is_allowed_to_manage =
+            is_allowed_to_manage_tournament(&account, &tournament_model, connection)?;
+        if !is_allowed_to_manage {
+            return Err(ErrorType::PermissionDenied);
+        }
+
+        check_tournament_status(
+            &tournament_model,
+            &[
+                TournamentStatus::Draft,
+                TournamentStatus::RegistrationOpen,
+                TournamentStatus::InProgress,
+            ],
+        )?;
+
+        let mut player_model =
+            get_player_in_tournament(&self.player_id, &self.tournament_id, connection)?;
+        let updated_team = self
+            .updated_team
+            .as_ref()
+            .map(|team| String::from(team.trim()))
+            .filter(|team| !team.is_empty());
+        player_model.set_team(&updated_team);
+        if self.updated_section.is_some() && self.updated_section != player_model.get_section() {
+            validate_section(&tournament_model, &self.updated_section)?;
+            let rounds = RoundRowModel::get_all_from_tournament(&self.tournament_id, connection)?;
+            if !rounds.is_empty() {
+                return Err(ErrorType::BadRequestError(String::from(
+                    "Player section cannot be changed once rounds are created",
+                )));
+            }
+            player_model.set_section(&self.updated_section);
+        }
+        player_model.update(connection)?;
+
+        Ok(json!({"message": "Player updated"}))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!(
+            "UpdateTournamentPlayer with player id {} for tournament id {}",
+            &self.player_id, &self.tournament_id,
+        ))
+    }
+}
+
 pub struct DeleteTournamentPlayerCommand {
     pub jwt: String,
     pub tournament_id: i32,
@@ -173,15 +675,18 @@ impl ResponseCommand for DeleteTournamentPlayerCommand {
             return Err(ErrorType::PermissionDenied);
         }
 
-        let tournament_rounds =
-            RoundRowModel::get_all_from_tournament(&self.tournament_id, connection)?;
-        if tournament_rounds.len() > 0 {
+        check_tournament_status(
+            &tournament_model,
+            &[TournamentStatus::Draft, TournamentStatus::RegistrationOpen],
+        )?;
+
+        let player_model =
+            get_player_in_tournament(&self.player_id, &self.tournament_id, connection)?;
+        if is_board_player(&self.player_id, &self.tournament_id, connection)? {
             return Err(ErrorType::BadRequestError(String::from(
-                "You cannot delete a player in an ongoing tournament",
+                "Player is playing for a team, remove the player from the team first",
             )));
         }
-
-        let player_model = PlayerRowModel::get(&self.player_id, connection)?;
         player_model.delete(connection)?;
 
         Ok(json!({"message": "Player deleted"}))
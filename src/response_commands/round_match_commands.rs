@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::iter::FromIterator;
 
 use diesel::result::Error;
@@ -9,19 +9,43 @@ use serde_json::{Map, Value};
 
 use crate::account::Account;
 use crate::database_models::{
-    MatchDAO, MatchRowModel, PlayerRowModel, RoundDAO, RoundRowModel, TournamentRowModel,
+    MatchDAO, MatchRowModel, RoundDAO, RoundRowModel, TeamRowModel, TournamentRowModel,
 };
 use crate::errors::ErrorType;
-use crate::game_match::{GameMatchCreator, GameMatchTransformer, IGameMatch};
+use crate::game_match::{
+    normalize_disc_counts, GameMatchCreator, GameMatchTransformer, IGameMatch,
+};
 use crate::meta_generator::{
     generate_matches_meta, generate_rounds_meta, generate_standings_meta,
-    RoundDetailsMetaGenerator, RoundMetaGenerator,
+    DefaultPairingPreviewMetaGenerator, PairingPreviewMetaGenerator, RoundDetailsMetaGenerator,
+    RoundMetaGenerator,
+};
+use crate::othello::{normalize_transcript, replay_transcript};
+use crate::pairings_generator::{
+    generate_pairings_preview, get_requested_byes_by_player_id, PairingGenerator,
+    PairingsGeneratorCreator, RRPairingsGenerator,
+};
+use crate::properties::{
+    MatchResultType, RoundType, SpecialConditionScore, TournamentStatus, TournamentType,
+};
+use crate::tournament_manager::{
+    create_result_keeper, create_result_keeper_with_config, IResultKeeper,
 };
-use crate::pairings_generator::PairingsGeneratorCreator;
-use crate::properties::{RoundType, TournamentType};
-use crate::tournament_manager::create_result_keeper;
 
-use super::{is_allowed_to_manage_tournament, ResponseCommand};
+use super::{
+    advance_knockout_bracket, check_tournament_status, create_standings_config,
+    generate_team_pairings, get_match_in_round, get_round_in_tournament, get_section_players,
+    get_section_rounds, is_allowed_to_manage_tournament, validate_section, validate_team_boards,
+    ResponseCommand,
+};
+
+fn create_section_meta_data(section: &Option<String>) -> Map<String, Value> {
+    let mut meta_data = Map::new();
+    if let Some(section) = section {
+        meta_data.insert(String::from("section"), Value::from(section.clone()));
+    }
+    meta_data
+}
 
 pub struct GetTournamentRoundsCommand {
     pub tournament_id: i32,
@@ -43,12 +67,13 @@ impl ResponseCommand for GetTournamentRoundsCommand {
 }
 
 pub struct GetRoundCommand {
+    pub tournament_id: i32,
     pub round_id: i32,
 }
 
 impl ResponseCommand for GetRoundCommand {
     fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
-        let round = RoundRowModel::get(&self.round_id, connection)?;
+        let round = get_round_in_tournament(&self.round_id, &self.tournament_id, connection)?;
         let tournament_id = round.tournament_id.clone();
         let matches = MatchRowModel::get_all_from_round(&self.round_id, connection)?;
 
@@ -81,8 +106,11 @@ pub struct GetStandingsCommand {
 impl ResponseCommand for GetStandingsCommand {
     fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
         let tournament_model = TournamentRowModel::get(&self.tournament_id, connection)?;
+        let limit_round =
+            get_round_in_tournament(&self.round_id_limit, &self.tournament_id, connection)?;
+        let section = limit_round.get_section();
         let round_ids: HashSet<i32> = HashSet::from_iter(
-            RoundRowModel::get_all_from_tournament(&tournament_model.id, connection)?
+            get_section_rounds(&tournament_model.id, &section, connection)?
                 .into_iter()
                 .filter(|round| {
                     let is_normal_round = round.round_type == RoundType::ManualNormal.to_i32()
@@ -100,7 +128,9 @@ impl ResponseCommand for GetStandingsCommand {
             .filter(|game_match| round_ids.contains(&game_match.round_id))
             .map(|game_match| GameMatchTransformer::transform_to_game_match(&game_match))
             .collect();
-        let result_keeper = create_result_keeper(&filtered_matches);
+        let players = get_section_players(&tournament_model.id, &section, connection)?;
+        let standings_config = create_standings_config(&tournament_model, &players);
+        let result_keeper = create_result_keeper_with_config(&filtered_matches, &standings_config);
         let standings = result_keeper.get_detailed_standings();
         let standings_meta = generate_standings_meta(standings);
         Ok(json!({"tournament_id": self.tournament_id, "standings": standings_meta}))
@@ -111,9 +141,98 @@ impl ResponseCommand for GetStandingsCommand {
     }
 }
 
+fn is_normal_match_data_valid(
+    tournament_id: &i32,
+    section: &Option<String>,
+    match_data: &Vec<(i32, i32)>,
+    bye_match_data: &Vec<i32>,
+    connection: &PgConnection,
+) -> bool {
+    let players = get_section_players(tournament_id, section, connection).unwrap_or(Vec::new());
+    let round_count = get_section_rounds(tournament_id, section, connection)
+        .unwrap_or(Vec::new())
+        .len();
+    let requested_byes = get_requested_byes_by_player_id(&players, &(round_count as i32 + 1));
+
+    // Withdrawn players are not paired anymore, players with a requested bye must get a bye.
+    let mut player_ids = HashSet::new();
+    players
+        .iter()
+        .filter(|player_model| !player_model.is_withdrawn())
+        .for_each(|player_model| {
+            player_ids.insert(player_model.id.clone());
+        });
+
+    let bye_player_not_in_db = bye_match_data.iter().find(|id| !player_ids.contains(id));
+
+    let player_not_in_db = match_data.iter().find(|match_datum| {
+        !(player_ids.contains(&match_datum.0) && player_ids.contains(&match_datum.1))
+    });
+
+    let has_all_requested_byes = requested_byes
+        .keys()
+        .all(|player_id| bye_match_data.contains(player_id));
+
+    let no_of_players = player_ids.len() - requested_byes.len();
+    player_not_in_db.is_none()
+        && bye_player_not_in_db.is_none()
+        && has_all_requested_byes
+        && bye_match_data.len() == requested_byes.len() + no_of_players % 2
+        && match_data.len() == no_of_players / 2
+}
+
+fn create_normal_round_with_pairings(
+    tournament_id: &i32,
+    section: &Option<String>,
+    name: &String,
+    round_type: RoundType,
+    match_data: &Vec<(i32, i32)>,
+    bye_match_data: &Vec<i32>,
+    connection: &PgConnection,
+) -> Result<(), ErrorType> {
+    let players = get_section_players(tournament_id, section, connection)?;
+    let round_count = get_section_rounds(tournament_id, section, connection)?.len();
+    let requested_byes = get_requested_byes_by_player_id(&players, &(round_count as i32 + 1));
+
+    let round = RoundRowModel::create(
+        tournament_id,
+        name,
+        round_type,
+        create_section_meta_data(section),
+        connection,
+    )?;
+    let mut pairings: Vec<Box<dyn IGameMatch>> = match_data
+        .iter()
+        .map(|match_datum| {
+            GameMatchCreator::create_new_match(
+                &round.id,
+                &match_datum.0,
+                &match_datum.1,
+                &Value::from(Map::new()),
+            )
+        })
+        .collect();
+    let bye_pairings: Vec<Box<dyn IGameMatch>> = bye_match_data
+        .iter()
+        .map(|player_id| match requested_byes.get(player_id) {
+            Some(requested_bye) => requested_bye.create_bye_match(&round.id, player_id),
+            None => GameMatchCreator::create_new_bye_match(
+                &round.id,
+                player_id,
+                &Value::from(Map::new()),
+            ),
+        })
+        .collect();
+
+    pairings.extend(bye_pairings);
+    MatchRowModel::bulk_create_from(&pairings, connection)?;
+    Ok(())
+}
+
 pub struct CreateManualNormalRoundCommand {
     pub jwt: String,
     pub tournament_id: i32,
+    pub section: Option<String>,
     pub name: String,
     pub match_data: Vec<(i32, i32)>,
     pub bye_match_data: Vec<i32>,
@@ -121,70 +240,25 @@ pub struct CreateManualNormalRoundCommand {
 
 impl CreateManualNormalRoundCommand {
     fn is_match_data_valid(&self, connection: &PgConnection) -> bool {
-        let mut player_ids = HashSet::new();
-        PlayerRowModel::get_all_from_tournament(&self.tournament_id, connection)
-            .unwrap_or(Vec::new())
-            .iter()
-            .for_each(|player_model| {
-                player_ids.insert(player_model.id.clone());
-            });
-
-        let bye_player_not_in_db = self
-            .bye_match_data
-            .iter()
-            .find(|id| !player_ids.contains(id));
-
-        let player_not_in_db = self.match_data.iter().find(|match_datum| {
-            !(player_ids.contains(&match_datum.0) && player_ids.contains(&match_datum.1))
-        });
-
-        let no_of_players = player_ids.len();
-        if no_of_players % 2 == 1 {
-            return player_not_in_db.is_none()
-                && self.bye_match_data.len() == 1
-                && self.match_data.len() == no_of_players / 2;
-        }
-        player_not_in_db.is_none()
-            && bye_player_not_in_db.is_none()
-            && self.bye_match_data.len() == 0
-            && self.match_data.len() == no_of_players / 2
+        is_normal_match_data_valid(
+            &self.tournament_id,
+            &self.section,
+            &self.match_data,
+            &self.bye_match_data,
+            connection,
+        )
     }
 
     fn create_new_pairings(&self, connection: &PgConnection) -> Result<(), ErrorType> {
-        let round = RoundRowModel::create(
+        create_normal_round_with_pairings(
             &self.tournament_id,
+            &self.section,
             &self.name,
             RoundType::ManualNormal,
-            Map::new(),
+            &self.match_data,
+            &self.bye_match_data,
             connection,
-        )?;
-        let mut pairings: Vec<Box<dyn IGameMatch>> = self
-            .match_data
-            .iter()
-            .map(|match_datum| {
-                GameMatchCreator::create_new_match(
-                    &round.id,
-                    &match_datum.0,
-                    &match_datum.1,
-                    &Value::from(Map::new()),
-                )
-            })
-            .collect();
-        let bye_pairings: Vec<Box<dyn IGameMatch>> = self
-            .bye_match_data
-            .iter()
-            .map(|player_id| {
-                GameMatchCreator::create_new_bye_match(
-                    &round.id,
-                    player_id,
-                    &Value::from(Map::new()),
-                )
-            })
-            .collect();
-
-        pairings.extend(bye_pairings);
-        MatchRowModel::bulk_create_from(&pairings, connection)?;
-        Ok(())
+        )
     }
 }
 
@@ -198,12 +272,24 @@ impl ResponseCommand for CreateManualNormalRoundCommand {
                 "Manual pairing is unavailable for Round Robin tournaments",
             )));
         }
+        if tournament_model.tournament_type == TournamentType::Team.to_string() {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Manual pairing is unavailable for Team tournaments",
+            )));
+        }
+        if tournament_model.tournament_type == TournamentType::Knockout.to_string() {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Manual pairing is unavailable for Knockout tournaments",
+            )));
+        }
 
         let is_allowed_to_manage =
             is_allowed_to_manage_tournament(&account, &tournament_model, connection)?;
         if !is_allowed_to_manage {
             return Err(ErrorType::PermissionDenied);
         }
+        check_tournament_status(&tournament_model, &[TournamentStatus::InProgress])?;
+        validate_section(&tournament_model, &self.section)?;
 
         if !self.is_match_data_valid(connection) {
             return Err(ErrorType::BadRequestError(String::from(
@@ -237,6 +323,7 @@ impl ResponseCommand for CreateManualNormalRoundCommand {
 pub struct CreateManualSpecialRoundCommand {
     pub jwt: String,
     pub tournament_id: i32,
+    pub section: Option<String>,
     pub name: String,
     pub match_data: Vec<(i32, i32)>,
     pub bye_match_data: Vec<i32>,
@@ -245,7 +332,7 @@ pub struct CreateManualSpecialRoundCommand {
 impl CreateManualSpecialRoundCommand {
     fn is_match_data_valid(&self, connection: &PgConnection) -> bool {
         let mut player_ids = HashSet::new();
-        PlayerRowModel::get_all_from_tournament(&self.tournament_id, connection)
+        get_section_players(&self.tournament_id, &self.section, connection)
             .unwrap_or(Vec::new())
             .iter()
             .for_each(|player_model| {
@@ -268,7 +355,7 @@ impl CreateManualSpecialRoundCommand {
             &self.tournament_id,
             &self.name,
             RoundType::ManualSpecial,
-            Map::new(),
+            create_section_meta_data(&self.section),
             connection,
         )?;
         let mut pairings: Vec<Box<dyn IGameMatch>> = self
@@ -311,6 +398,8 @@ impl ResponseCommand for CreateManualSpecialRoundCommand {
         if !is_allowed_to_manage {
             return Err(ErrorType::PermissionDenied);
         }
+        check_tournament_status(&tournament_model, &[TournamentStatus::InProgress])?;
+        validate_section(&tournament_model, &self.section)?;
 
         if !self.is_match_data_valid(connection) {
             return Err(ErrorType::BadRequestError(String::from(
@@ -341,9 +430,77 @@ impl ResponseCommand for CreateManualSpecialRoundCommand {
     }
 }
 
+// Returns the generator together with a separate copy of the past results, since the generator
+// takes ownership of its own copy.
+fn create_automatic_pairings_generator(
+    tournament_model: &TournamentRowModel,
+    section: &Option<String>,
+    connection: &PgConnection,
+) -> Result<(Box<dyn PairingGenerator>, Box<dyn IResultKeeper>), ErrorType> {
+    if tournament_model.tournament_type == TournamentType::Knockout.to_string() {
+        return Err(ErrorType::BadRequestError(String::from(
+            "Knockout rounds are generated from the bracket",
+        )));
+    }
+    if tournament_model.tournament_type == TournamentType::Team.to_string() {
+        return Err(ErrorType::BadRequestError(String::from(
+            "Team rounds are generated from team pairings",
+        )));
+    }
+
+    let rounds = get_section_rounds(&tournament_model.id, section, connection)?;
+    let pairing_constraints =
+        tournament_model.get_active_pairing_constraints(&(rounds.len() as i32 + 1));
+    let automatic_round_ids: HashSet<i32> = HashSet::from_iter(
+        rounds
+            .iter()
+            .filter(|round| round.round_type == RoundType::Automatic.to_i32())
+            .map(|round| round.id),
+    );
+    let normal_round_ids: HashSet<i32> = HashSet::from_iter(
+        rounds
+            .iter()
+            .filter(|round| round.round_type == RoundType::ManualNormal.to_i32())
+            .map(|round| round.id),
+    );
+
+    let previous_matches =
+        MatchRowModel::get_all_from_tournament(&tournament_model.id, connection)?;
+    let previous_normal_matches: Vec<Box<dyn IGameMatch>> = previous_matches
+        .into_iter()
+        .filter(|game_match| {
+            if tournament_model.tournament_type == TournamentType::RoundRobin.to_string() {
+                return automatic_round_ids.contains(&game_match.round_id);
+            }
+            if tournament_model.tournament_type == TournamentType::SwissPairing.to_string() {
+                return automatic_round_ids.contains(&game_match.round_id)
+                    || normal_round_ids.contains(&game_match.round_id);
+            }
+            false
+        })
+        .map(|game_match| GameMatchTransformer::transform_to_game_match(&game_match))
+        .collect();
+    let players = get_section_players(&tournament_model.id, section, connection)?;
+    let standings_config = create_standings_config(&tournament_model, &players);
+
+    let pairing_generator = PairingsGeneratorCreator::create_automatic_pairings_generator(
+        TournamentType::from_string(tournament_model.tournament_type.clone()),
+        players,
+        create_result_keeper_with_config(&previous_normal_matches, &standings_config),
+        pairing_constraints,
+        tournament_model.get_round_robin_cycles(),
+        tournament_model.is_accelerated_round(&(rounds.len() as i32 + 1)),
+        rounds.len() as i32 + 1,
+    );
+    let past_results =
+        create_result_keeper_with_config(&previous_normal_matches, &standings_config);
+    Ok((pairing_generator, past_results))
+}
+
 pub struct CreateAutomaticRoundCommand {
     pub jwt: String,
     pub tournament_id: i32,
+    pub section: Option<String>,
     pub name: String,
 }
 
@@ -353,50 +510,27 @@ impl CreateAutomaticRoundCommand {
         tournament_model: &TournamentRowModel,
         connection: &PgConnection,
     ) -> Result<(), ErrorType> {
-        let automatic_round_ids: HashSet<i32> = HashSet::from_iter(
-            RoundRowModel::get_all_from_tournament(&tournament_model.id, connection)?
-                .into_iter()
-                .filter(|round| round.round_type == RoundType::Automatic.to_i32())
-                .map(|round| round.id),
-        );
-        let normal_round_ids: HashSet<i32> = HashSet::from_iter(
-            RoundRowModel::get_all_from_tournament(&tournament_model.id, connection)?
-                .into_iter()
-                .filter(|round| round.round_type == RoundType::ManualNormal.to_i32())
-                .map(|round| round.id),
-        );
-
-        let previous_matches =
-            MatchRowModel::get_all_from_tournament(&tournament_model.id, connection)?;
-        let previous_normal_matches: Vec<Box<dyn IGameMatch>> = previous_matches
-            .into_iter()
-            .filter(|game_match| {
-                if tournament_model.tournament_type == TournamentType::RoundRobin.to_string() {
-                    return automatic_round_ids.contains(&game_match.round_id);
-                }
-                if tournament_model.tournament_type == TournamentType::SwissPairing.to_string() {
-                    return automatic_round_ids.contains(&game_match.round_id)
-                        || normal_round_ids.contains(&game_match.round_id);
-                }
-                false
-            })
-            .map(|game_match| GameMatchTransformer::transform_to_game_match(&game_match))
-            .collect();
-        let result_keeper = create_result_keeper(&previous_normal_matches);
-
-        let players = PlayerRowModel::get_all_from_tournament(&tournament_model.id, connection)?;
+        if tournament_model.tournament_type == TournamentType::Team.to_string() {
+            let round = RoundRowModel::create(
+                &self.tournament_id,
+                &self.name,
+                RoundType::Automatic,
+                Map::new(),
+                connection,
+            )?;
+            let matches = generate_team_pairings(tournament_model, &round.id, connection)?;
+            MatchRowModel::bulk_create_from(&matches, connection)?;
+            return Ok(());
+        }
 
-        let pairing_generator = PairingsGeneratorCreator::create_automatic_pairings_generator(
-            TournamentType::from_string(tournament_model.tournament_type.clone()),
-            players,
-            result_keeper,
-        );
+        let (pairing_generator, _) =
+            create_automatic_pairings_generator(tournament_model, &self.section, connection)?;
 
         let round = RoundRowModel::create(
             &self.tournament_id,
             &self.name,
             RoundType::Automatic,
-            Map::new(),
+            create_section_meta_data(&self.section),
             connection,
         )?;
         let matches = pairing_generator.generate_pairings(&round.id)?;
@@ -415,17 +549,31 @@ impl ResponseCommand for CreateAutomaticRoundCommand {
         if !is_allowed_to_manage {
             return Err(ErrorType::PermissionDenied);
         }
+        check_tournament_status(&tournament_model, &[TournamentStatus::InProgress])?;
+        validate_section(&tournament_model, &self.section)?;
+        if tournament_model.tournament_type == TournamentType::Team.to_string() {
+            validate_team_boards(&TeamRowModel::get_all_from_tournament(
+                &self.tournament_id,
+                connection,
+            )?)?;
+        }
 
+        let mut pairing_error = None;
         if let Err(_) = connection.transaction::<(), Error, _>(|| {
             match self.create_new_automatic_pairings_round(&tournament_model, connection) {
                 Ok(()) => Ok(()),
-                Err(_) => Err(Error::RollbackTransaction),
+                Err(e) => {
+                    pairing_error = Some(e);
+                    Err(Error::RollbackTransaction)
+                }
             }?;
             Ok(())
         }) {
-            return Err(ErrorType::UnknownError(String::from(
-                "Error from generating automatic pairings",
-            )));
+            return Err(
+                pairing_error.unwrap_or(ErrorType::UnknownError(String::from(
+                    "Error from generating automatic pairings",
+                ))),
+            );
         }
 
         Ok(json!({"message": "New round pairings (Automatic) is added to the tournament."}))
@@ -436,6 +584,199 @@ impl ResponseCommand for CreateAutomaticRoundCommand {
     }
 }
 
+pub struct PreviewAutomaticRoundCommand {
+    pub jwt: String,
+    pub tournament_id: i32,
+    pub section: Option<String>,
+}
+
+impl ResponseCommand for PreviewAutomaticRoundCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let account = Account::login_from_jwt(&self.jwt, connection)?;
+        let tournament_model = TournamentRowModel::get(&self.tournament_id, connection)?;
+
+        let is_allowed_to_manage =
+            is_allowed_to_manage_tournament(&account, &tournament_model, connection)?;
+        if !is_allowed_to_manage {
+            return Err(ErrorType::PermissionDenied);
+        }
+        check_tournament_status(&tournament_model, &[TournamentStatus::InProgress])?;
+        validate_section(&tournament_model, &self.section)?;
+
+        let (pairing_generator, past_results) =
+            create_automatic_pairings_generator(&tournament_model, &self.section, connection)?;
+        let pairings = pairing_generator.generate_pairings(&0)?;
+
+        let round_count = get_section_rounds(&self.tournament_id, &self.section, connection)?.len();
+        let pairing_constraints =
+            tournament_model.get_active_pairing_constraints(&(round_count as i32 + 1));
+        let players = get_section_players(&self.tournament_id, &self.section, connection)?;
+        let virtual_points_by_player_id: HashMap<i32, f64> = players
+            .iter()
+            .map(|player| (player.id, pairing_generator.get_virtual_points(&player.id)))
+            .collect();
+        let pairings_preview = generate_pairings_preview(
+            &pairings,
+            &past_results,
+            &players,
+            &pairing_constraints,
+            &virtual_points_by_player_id,
+        );
+
+        let meta_generator = DefaultPairingPreviewMetaGenerator {
+            players_by_id: players.iter().map(|player| (&player.id, player)).collect(),
+        };
+        let pairings_meta: Vec<Map<String, Value>> = pairings_preview
+            .iter()
+            .map(|pairing| meta_generator.generate_meta_for(pairing))
+            .collect();
+        Ok(json!({ "pairings": pairings_meta }))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!("PreviewAutomaticRound for {}", &self.tournament_id))
+    }
+}
+
+pub struct GetRoundRobinScheduleCommand {
+    pub tournament_id: i32,
+    pub section: Option<String>,
+}
+
+impl ResponseCommand for GetRoundRobinScheduleCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let tournament_model = TournamentRowModel::get(&self.tournament_id, connection)?;
+        if tournament_model.tournament_type != TournamentType::RoundRobin.to_string() {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Schedule is only available for Round Robin tournaments",
+            )));
+        }
+
+        validate_section(&tournament_model, &self.section)?;
+
+        let players = get_section_players(&self.tournament_id, &self.section, connection)?;
+        let pairings_generator = RRPairingsGenerator::new_with_cycles(
+            players,
+            create_result_keeper(&vec![]),
+            tournament_model.get_round_robin_cycles(),
+        );
+        let rounds_meta: Vec<JsonValue> = pairings_generator
+            .generate_schedule()
+            .iter()
+            .enumerate()
+            .map(|(round_index, pairings)| {
+                let match_models = pairings
+                    .iter()
+                    .map(|game_match| game_match.extract_data())
+                    .collect();
+                json!({
+                    "round_number": round_index + 1,
+                    "matches": generate_matches_meta(match_models),
+                })
+            })
+            .collect();
+        Ok(json!({ "rounds": rounds_meta }))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!("GetRoundRobinSchedule for {}", &self.tournament_id))
+    }
+}
+
+pub struct CommitAutomaticRoundCommand {
+    pub jwt: String,
+    pub tournament_id: i32,
+    pub section: Option<String>,
+    pub name: String,
+    pub match_data: Vec<(i32, i32)>,
+    pub bye_match_data: Vec<i32>,
+}
+
+impl CommitAutomaticRoundCommand {
+    fn is_match_data_valid(&self, connection: &PgConnection) -> bool {
+        let mut paired_player_ids = HashSet::new();
+        let has_duplicate_player = self
+            .match_data
+            .iter()
+            .flat_map(|match_datum| vec![match_datum.0, match_datum.1])
+            .chain(self.bye_match_data.iter().cloned())
+            .any(|player_id| !paired_player_ids.insert(player_id));
+
+        !has_duplicate_player
+            && is_normal_match_data_valid(
+                &self.tournament_id,
+                &self.section,
+                &self.match_data,
+                &self.bye_match_data,
+                connection,
+            )
+    }
+}
+
+impl ResponseCommand for CommitAutomaticRoundCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let account = Account::login_from_jwt(&self.jwt, connection)?;
+        let tournament_model = TournamentRowModel::get(&self.tournament_id, connection)?;
+
+        if tournament_model.tournament_type == TournamentType::Knockout.to_string() {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Knockout rounds are created from the bracket, not from committed pairings",
+            )));
+        }
+        if tournament_model.tournament_type == TournamentType::Team.to_string() {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Team rounds are paired by team, not from committed player pairings",
+            )));
+        }
+
+        let is_allowed_to_manage =
+            is_allowed_to_manage_tournament(&account, &tournament_model, connection)?;
+        if !is_allowed_to_manage {
+            return Err(ErrorType::PermissionDenied);
+        }
+        check_tournament_status(&tournament_model, &[TournamentStatus::InProgress])?;
+        validate_section(&tournament_model, &self.section)?;
+
+        if !self.is_match_data_valid(connection) {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Invalid match data, some players are not available",
+            )));
+        }
+
+        let mut pairing_error = None;
+        if let Err(_) = connection.transaction::<(), Error, _>(|| {
+            match create_normal_round_with_pairings(
+                &self.tournament_id,
+                &self.section,
+                &self.name,
+                RoundType::Automatic,
+                &self.match_data,
+                &self.bye_match_data,
+                connection,
+            ) {
+                Ok(()) => Ok(()),
+                Err(e) => {
+                    pairing_error = Some(e);
+                    Err(Error::RollbackTransaction)
+                }
+            }?;
+            Ok(())
+        }) {
+            return Err(
+                pairing_error.unwrap_or(ErrorType::UnknownError(String::from(
+                    "Error from committing automatic pairings",
+                ))),
+            );
+        }
+
+        Ok(json!({"message": "New round pairings (Automatic) is added to the tournament."}))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!("CommitAutomaticRound for {}", &self.tournament_id))
+    }
+}
+
 pub struct UpdateRoundCommand {
     pub jwt: String,
     pub tournament_id: i32,
@@ -453,8 +794,9 @@ impl ResponseCommand for UpdateRoundCommand {
         if !is_allowed_to_manage {
             return Err(ErrorType::PermissionDenied);
         }
+        check_tournament_status(&tournament_model, &[TournamentStatus::InProgress])?;
 
-        let mut round = RoundRowModel::get(&self.round_id, connection)?;
+        let mut round = get_round_in_tournament(&self.round_id, &self.tournament_id, connection)?;
         round.name = self.updated_name.clone();
         round.update(connection)?;
 
@@ -485,8 +827,9 @@ impl ResponseCommand for DeleteRoundCommand {
         if !is_allowed_to_manage {
             return Err(ErrorType::PermissionDenied);
         }
+        check_tournament_status(&tournament_model, &[TournamentStatus::InProgress])?;
 
-        let round = RoundRowModel::get(&self.round_id, connection)?;
+        let round = get_round_in_tournament(&self.round_id, &self.tournament_id, connection)?;
         let matches = MatchRowModel::get_all_from_round(&round.id, connection)?;
 
         if let Err(_) = connection.transaction::<(), Error, _>(|| {
@@ -522,11 +865,13 @@ impl ResponseCommand for DeleteRoundCommand {
 }
 
 pub struct GetRoundMatchesCommand {
+    pub tournament_id: i32,
     pub round_id: i32,
 }
 
 impl ResponseCommand for GetRoundMatchesCommand {
     fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        get_round_in_tournament(&self.round_id, &self.tournament_id, connection)?;
         let matches = MatchRowModel::get_all_from_round(&self.round_id, connection)?;
         let matches_meta = generate_matches_meta(
             matches
@@ -548,9 +893,11 @@ impl ResponseCommand for GetRoundMatchesCommand {
 pub struct UpdateMatchCommand {
     pub jwt: String,
     pub tournament_id: i32,
+    pub round_id: i32,
     pub match_id: i32,
     pub black_score: i32,
     pub white_score: i32,
+    pub result_type: String,
 }
 
 impl ResponseCommand for UpdateMatchCommand {
@@ -563,12 +910,78 @@ impl ResponseCommand for UpdateMatchCommand {
         if !is_allowed_to_manage {
             return Err(ErrorType::PermissionDenied);
         }
+        check_tournament_status(&tournament_model, &[TournamentStatus::InProgress])?;
 
-        let mut game_match = MatchRowModel::get(&self.match_id, connection)?;
-        game_match.black_score = self.black_score.clone();
-        game_match.white_score = self.white_score.clone();
+        let mut game_match = get_match_in_round(
+            &self.match_id,
+            &self.round_id,
+            &self.tournament_id,
+            connection,
+        )?;
+        let result_type = MatchResultType::from_string(self.result_type.clone());
+        let is_bye_match = GameMatchTransformer::transform_to_game_match(&game_match)
+            .get_opponent_id(&game_match.black_player_id)
+            .is_none();
+        let is_reset = self.black_score == SpecialConditionScore::NotFinished.to_i32()
+            && self.white_score == SpecialConditionScore::NotFinished.to_i32();
+
+        // A bye only takes the disc count given to the player, as the black score.
+        let (black_score, white_score) = match result_type {
+            _ if is_bye_match => {
+                if self.black_score < 0 || self.black_score > 64 {
+                    return Err(ErrorType::InvalidMatchResult(String::from(
+                        "Bye disc count must be between 0 and 64",
+                    )));
+                }
+                (self.black_score.clone(), self.white_score.clone())
+            }
+            MatchResultType::Normal | MatchResultType::Adjudicated if !is_reset => {
+                normalize_disc_counts(&self.black_score, &self.white_score)?
+            }
+            _ => (self.black_score.clone(), self.white_score.clone()),
+        };
+        let is_normalized = black_score != self.black_score || white_score != self.white_score;
+
+        let updated_match = match is_bye_match {
+            true => Some(GameMatchCreator::create_new_bye_match_with_disc_count(
+                &game_match.round_id,
+                &game_match.black_player_id,
+                &black_score,
+                &game_match.meta_data,
+            )),
+            false => GameMatchCreator::create_new_match_with_result(
+                &game_match.round_id,
+                &game_match.black_player_id,
+                &game_match.white_player_id,
+                &result_type,
+                &black_score,
+                &white_score,
+                &game_match.meta_data,
+            ),
+        };
+        let updated_match = match updated_match {
+            Some(updated_match) => Ok(updated_match),
+            None => Err(ErrorType::BadRequestError(String::from(
+                "Invalid match result type",
+            ))),
+        }?;
+        let updated_match_data = updated_match.extract_data();
+        game_match.black_score = updated_match_data.black_score;
+        game_match.white_score = updated_match_data.white_score;
+        game_match.meta_data = updated_match_data.meta_data;
         game_match.update(connection)?;
 
+        if tournament_model.tournament_type == TournamentType::Knockout.to_string() {
+            advance_knockout_bracket(&tournament_model, connection)?;
+        }
+
+        if is_normalized {
+            return Ok(json!({
+                "message": "Match has been updated with empty squares given to the winner.",
+                "black_score": black_score,
+                "white_score": white_score,
+            }));
+        }
         Ok(json!({"message": "Match has been updated."}))
     }
 
@@ -579,3 +992,72 @@ impl ResponseCommand for UpdateMatchCommand {
         ))
     }
 }
+
+pub struct UpdateMatchTranscriptCommand {
+    pub jwt: String,
+    pub tournament_id: i32,
+    pub round_id: i32,
+    pub match_id: i32,
+    pub transcript: String,
+}
+
+impl ResponseCommand for UpdateMatchTranscriptCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let account = Account::login_from_jwt(&self.jwt, connection)?;
+        let tournament_model = TournamentRowModel::get(&self.tournament_id, connection)?;
+
+        let is_allowed_to_manage =
+            is_allowed_to_manage_tournament(&account, &tournament_model, connection)?;
+        if !is_allowed_to_manage {
+            return Err(ErrorType::PermissionDenied);
+        }
+        check_tournament_status(&tournament_model, &[TournamentStatus::InProgress])?;
+
+        let mut game_match = get_match_in_round(
+            &self.match_id,
+            &self.round_id,
+            &self.tournament_id,
+            connection,
+        )?;
+        let existing_match = GameMatchTransformer::transform_to_game_match(&game_match);
+        if existing_match
+            .get_opponent_id(&game_match.black_player_id)
+            .is_none()
+            || existing_match.is_forfeited()
+        {
+            return Err(ErrorType::InvalidTranscript(String::from(
+                "Match was not played over the board",
+            )));
+        }
+
+        let (transcript_black_score, transcript_white_score) = replay_transcript(&self.transcript)?;
+        let (black_score, white_score) =
+            normalize_disc_counts(&transcript_black_score, &transcript_white_score)?;
+        if game_match.is_finished() {
+            if game_match.black_score != black_score || game_match.white_score != white_score {
+                return Err(ErrorType::InvalidTranscript(format!(
+                    "Transcript result ({}-{}) does not match the recorded score ({}-{})",
+                    black_score, white_score, game_match.black_score, game_match.white_score
+                )));
+            }
+        } else {
+            game_match.black_score = black_score;
+            game_match.white_score = white_score;
+        }
+
+        game_match.set_transcript(&normalize_transcript(&self.transcript));
+        game_match.update(connection)?;
+        Ok(json!({
+            "message": "Match transcript has been recorded.",
+            "black_score": black_score,
+            "white_score": white_score,
+        }))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!(
+            "UpdateMatchTranscript for {} in tournament {}",
+            &self.match_id, &self.tournament_id
+        ))
+    }
+}
@@ -0,0 +1,390 @@
+use std::collections::{HashMap, HashSet};
+
+use diesel::PgConnection;
+use rocket_contrib::json::JsonValue;
+use serde_json::{Map, Value};
+
+use crate::account::Account;
+use crate::database_models::{
+    MatchDAO, MatchRowModel, PlayerRowModel, RoundDAO, RoundRowModel, TeamRowModel,
+    TournamentRowModel,
+};
+use crate::errors::ErrorType;
+use crate::game_match::{GameMatchTransformer, IGameMatch};
+use crate::meta_generator::{
+    DefaultTeamMetaGenerator, DefaultTeamStandingMetaGenerator, TeamMetaGenerator,
+    TeamStandingMetaGenerator,
+};
+use crate::pairings_generator::{
+    create_team_players, expand_team_pairings, PairingsGeneratorCreator,
+};
+use crate::properties::{TournamentStatus, TournamentType};
+use crate::tournament_manager::{
+    create_team_result_keeper, get_team_encounters, get_team_standings,
+};
+
+use super::{check_tournament_status, is_allowed_to_manage_tournament, ResponseCommand};
+
+fn check_team_tournament(tournament_model: &TournamentRowModel) -> Result<(), ErrorType> {
+    if tournament_model.tournament_type != TournamentType::Team.to_string() {
+        return Err(ErrorType::BadRequestError(String::from(
+            "Teams are only available for Team tournaments",
+        )));
+    }
+    Ok(())
+}
+
+fn get_team_in_tournament(
+    team_id: &i32,
+    tournament_id: &i32,
+    connection: &PgConnection,
+) -> Result<TeamRowModel, ErrorType> {
+    let team = TeamRowModel::get(team_id, connection)?;
+    if &team.tournament_id != tournament_id {
+        return Err(ErrorType::NotFound(
+            String::from("Team"),
+            team_id.to_string(),
+        ));
+    }
+    Ok(team)
+}
+
+// Board players must be registered in the tournament and play for one team only.
+fn validate_board_player_ids(
+    tournament_id: &i32,
+    team_id: Option<&i32>,
+    board_player_ids: &Vec<i32>,
+    connection: &PgConnection,
+) -> Result<(), ErrorType> {
+    if board_player_ids.is_empty() {
+        return Err(ErrorType::BadRequestError(String::from(
+            "Team needs at least one board player",
+        )));
+    }
+    let unique_player_ids: HashSet<&i32> = board_player_ids.iter().collect();
+    if unique_player_ids.len() != board_player_ids.len() {
+        return Err(ErrorType::BadRequestError(String::from(
+            "Player cannot play on more than one board",
+        )));
+    }
+
+    let player_ids: HashSet<i32> =
+        PlayerRowModel::get_all_from_tournament(tournament_id, connection)?
+            .iter()
+            .map(|player| player.id)
+            .collect();
+    if let Some(player_id) = board_player_ids
+        .iter()
+        .find(|player_id| !player_ids.contains(player_id))
+    {
+        return Err(ErrorType::NotFound(
+            String::from("Player"),
+            player_id.to_string(),
+        ));
+    }
+
+    let other_teams_player_ids: HashSet<i32> =
+        TeamRowModel::get_all_from_tournament(tournament_id, connection)?
+            .iter()
+            .filter(|team| Some(&team.id) != team_id)
+            .flat_map(|team| team.get_board_player_ids())
+            .collect();
+    if board_player_ids
+        .iter()
+        .any(|player_id| other_teams_player_ids.contains(player_id))
+    {
+        return Err(ErrorType::BadRequestError(String::from(
+            "Player is already playing for another team",
+        )));
+    }
+    Ok(())
+}
+
+pub fn validate_team_boards(teams: &Vec<TeamRowModel>) -> Result<(), ErrorType> {
+    if teams.len() < 2 {
+        return Err(ErrorType::BadRequestError(String::from(
+            "Team tournament needs at least two teams",
+        )));
+    }
+    let board_counts: HashSet<usize> = teams
+        .iter()
+        .map(|team| team.get_board_player_ids().len())
+        .collect();
+    if board_counts.contains(&0) || board_counts.len() != 1 {
+        return Err(ErrorType::BadRequestError(String::from(
+            "Every team must have the same number of boards",
+        )));
+    }
+    Ok(())
+}
+
+// Teams are paired by the configured generator, then every team pairing is expanded into board
+// matches.
+pub fn generate_team_pairings(
+    tournament_model: &TournamentRowModel,
+    round_id: &i32,
+    connection: &PgConnection,
+) -> Result<Vec<Box<dyn IGameMatch>>, ErrorType> {
+    let teams = TeamRowModel::get_all_from_tournament(&tournament_model.id, connection)?;
+    let players = PlayerRowModel::get_all_from_tournament(&tournament_model.id, connection)?;
+    let round_number = RoundRowModel::get_all_from_tournament(&tournament_model.id, connection)?
+        .iter()
+        .filter(|round| &round.id != round_id)
+        .count() as i32
+        + 1;
+    let previous_matches: Vec<Box<dyn IGameMatch>> =
+        MatchRowModel::get_all_from_tournament(&tournament_model.id, connection)?
+            .iter()
+            .map(|game_match| GameMatchTransformer::transform_to_game_match(game_match))
+            .collect();
+    let encounters = get_team_encounters(&previous_matches);
+
+    let pairings_generator = PairingsGeneratorCreator::create_automatic_pairings_generator(
+        tournament_model.get_team_pairing_type(),
+        create_team_players(&teams, &players),
+        create_team_result_keeper(&encounters),
+        vec![],
+        tournament_model.get_round_robin_cycles(),
+        false,
+        round_number,
+    );
+    let team_pairings = pairings_generator.generate_pairings(round_id)?;
+    Ok(expand_team_pairings(&team_pairings, &teams, round_id))
+}
+
+pub struct GetTournamentTeamsCommand {
+    pub tournament_id: i32,
+}
+
+impl ResponseCommand for GetTournamentTeamsCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let teams = TeamRowModel::get_all_from_tournament(&self.tournament_id, connection)?;
+        let players = PlayerRowModel::get_all_from_tournament(&self.tournament_id, connection)?;
+
+        let meta_generator = DefaultTeamMetaGenerator {
+            players_by_id: players.iter().map(|player| (&player.id, player)).collect(),
+        };
+        let teams_meta: Vec<Map<String, Value>> = teams
+            .iter()
+            .map(|team| meta_generator.generate_meta_for(team))
+            .collect();
+        Ok(json!({
+            "tournament_id": &self.tournament_id,
+            "teams": teams_meta,
+        }))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!("GetTournamentTeams for {}", &self.tournament_id))
+    }
+}
+
+pub struct AddTournamentTeamCommand {
+    pub jwt: String,
+    pub tournament_id: i32,
+    pub name: String,
+    pub board_player_ids: Vec<i32>,
+}
+
+impl ResponseCommand for AddTournamentTeamCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let account = Account::login_from_jwt(&self.jwt, connection)?;
+        let tournament_model = TournamentRowModel::get(&self.tournament_id, connection)?;
+
+        let is_allowed_to_manage =
+            is_allowed_to_manage_tournament(&account, &tournament_model, connection)?;
+        if !is_allowed_to_manage {
+            return Err(ErrorType::PermissionDenied);
+        }
+
+        check_team_tournament(&tournament_model)?;
+        check_tournament_status(
+            &tournament_model,
+            &[TournamentStatus::Draft, TournamentStatus::RegistrationOpen],
+        )?;
+
+        let name = String::from(self.name.trim());
+        if name.is_empty() {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Team name cannot be empty",
+            )));
+        }
+        validate_board_player_ids(
+            &self.tournament_id,
+            None,
+            &self.board_player_ids,
+            connection,
+        )?;
+
+        let team = TeamRowModel::create(
+            &self.tournament_id,
+            &name,
+            &self.board_player_ids,
+            connection,
+        )?;
+        Ok(json!({"message": "Team added", "team_id": team.id}))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!(
+            "AddTournamentTeam {} for tournament id {}",
+            &self.name, &self.tournament_id,
+        ))
+    }
+}
+
+pub struct UpdateTournamentTeamCommand {
+    pub jwt: String,
+    pub tournament_id: i32,
+    pub team_id: i32,
+    pub updated_name: Option<String>,
+    pub updated_board_player_ids: Option<Vec<i32>>,
+}
+
+impl ResponseCommand for UpdateTournamentTeamCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let account = Account::login_from_jwt(&self.jwt, connection)?;
+        let tournament_model = TournamentRowModel::get(&self.tournament_id, connection)?;
+
+        let is_allowed_to_manage =
+            is_allowed_to_manage_tournament(&account, &tournament_model, connection)?;
+        if !is_allowed_to_manage {
+            return Err(ErrorType::PermissionDenied);
+        }
+
+        check_team_tournament(&tournament_model)?;
+        check_tournament_status(
+            &tournament_model,
+            &[
+                TournamentStatus::Draft,
+                TournamentStatus::RegistrationOpen,
+                TournamentStatus::InProgress,
+            ],
+        )?;
+
+        let mut team_model =
+            get_team_in_tournament(&self.team_id, &self.tournament_id, connection)?;
+        if let Some(updated_name) = &self.updated_name {
+            let updated_name = String::from(updated_name.trim());
+            if updated_name.is_empty() {
+                return Err(ErrorType::BadRequestError(String::from(
+                    "Team name cannot be empty",
+                )));
+            }
+            team_model.name = updated_name;
+        }
+        // Board order may change between rounds, the board count may not once rounds exist.
+        if let Some(updated_board_player_ids) = &self.updated_board_player_ids {
+            validate_board_player_ids(
+                &self.tournament_id,
+                Some(&self.team_id),
+                updated_board_player_ids,
+                connection,
+            )?;
+            let rounds = RoundRowModel::get_all_from_tournament(&self.tournament_id, connection)?;
+            if !rounds.is_empty()
+                && updated_board_player_ids.len() != team_model.get_board_player_ids().len()
+            {
+                return Err(ErrorType::BadRequestError(String::from(
+                    "Number of boards cannot be changed once rounds are created",
+                )));
+            }
+            team_model.set_board_player_ids(updated_board_player_ids);
+        }
+        team_model.update(connection)?;
+
+        Ok(json!({"message": "Team updated"}))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!(
+            "UpdateTournamentTeam with team id {} for tournament id {}",
+            &self.team_id, &self.tournament_id,
+        ))
+    }
+}
+
+pub struct DeleteTournamentTeamCommand {
+    pub jwt: String,
+    pub tournament_id: i32,
+    pub team_id: i32,
+}
+
+impl ResponseCommand for DeleteTournamentTeamCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let account = Account::login_from_jwt(&self.jwt, connection)?;
+        let tournament_model = TournamentRowModel::get(&self.tournament_id, connection)?;
+
+        let is_allowed_to_manage =
+            is_allowed_to_manage_tournament(&account, &tournament_model, connection)?;
+        if !is_allowed_to_manage {
+            return Err(ErrorType::PermissionDenied);
+        }
+
+        check_team_tournament(&tournament_model)?;
+        check_tournament_status(
+            &tournament_model,
+            &[TournamentStatus::Draft, TournamentStatus::RegistrationOpen],
+        )?;
+
+        let team_model = get_team_in_tournament(&self.team_id, &self.tournament_id, connection)?;
+        team_model.delete(connection)?;
+
+        Ok(json!({"message": "Team deleted"}))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!(
+            "DeleteTournamentTeam with team id {} for tournament id {}",
+            &self.team_id, &self.tournament_id,
+        ))
+    }
+}
+
+pub fn generate_team_standings_meta(
+    tournament_model: &TournamentRowModel,
+    connection: &PgConnection,
+) -> Result<Vec<Map<String, Value>>, ErrorType> {
+    let teams = TeamRowModel::get_all_from_tournament(&tournament_model.id, connection)?;
+    let matches: Vec<Box<dyn IGameMatch>> =
+        MatchRowModel::get_all_from_tournament(&tournament_model.id, connection)?
+            .iter()
+            .map(|game_match| GameMatchTransformer::transform_to_game_match(game_match))
+            .collect();
+    let encounters = get_team_encounters(&matches);
+    let team_ids: Vec<i32> = teams.iter().map(|team| team.id).collect();
+    let standings = get_team_standings(
+        &team_ids,
+        &encounters,
+        &tournament_model.get_team_tie_breakers(),
+    );
+
+    let teams_by_id: HashMap<&i32, &TeamRowModel> =
+        teams.iter().map(|team| (&team.id, team)).collect();
+    let meta_generator = DefaultTeamStandingMetaGenerator { teams_by_id };
+    Ok(standings
+        .iter()
+        .map(|standing| meta_generator.generate_meta_for(standing))
+        .collect())
+}
+
+pub struct GetTeamStandingsCommand {
+    pub tournament_id: i32,
+}
+
+impl ResponseCommand for GetTeamStandingsCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let tournament_model = TournamentRowModel::get(&self.tournament_id, connection)?;
+        check_team_tournament(&tournament_model)?;
+
+        let standings_meta = generate_team_standings_meta(&tournament_model, connection)?;
+        Ok(json!({
+            "tournament_id": &self.tournament_id,
+            "team_standings": standings_meta,
+        }))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!("GetTeamStandings for {}", &self.tournament_id))
+    }
+}
@@ -5,8 +5,9 @@ use crate::account::Account;
 use crate::database_models::{TournamentRowModel, UserRowModel};
 use crate::errors::ErrorType;
 use crate::meta_generator::{generate_tournaments_meta, generate_users_meta};
+use crate::properties::TournamentStatus;
 
-use super::ResponseCommand;
+use super::{check_tournament_status, ResponseCommand};
 
 pub struct GetAllAdminsCommand {
     pub tournament_id: i32,
@@ -75,6 +76,15 @@ impl ResponseCommand for AddAdminCommand {
         if !is_allowed_to_manage_admin(&account, &tournament_model) {
             return Err(ErrorType::PermissionDenied);
         }
+        check_tournament_status(
+            &tournament_model,
+            &[
+                TournamentStatus::Draft,
+                TournamentStatus::RegistrationOpen,
+                TournamentStatus::InProgress,
+                TournamentStatus::Finished,
+            ],
+        )?;
         tournament_model.add_admin(&self.admin_username, connection)?;
         Ok(json!({"message": "Admin added."}))
     }
@@ -101,6 +111,15 @@ impl ResponseCommand for RemoveAdminCommand {
         if !is_allowed_to_manage_admin(&account, &tournament_model) {
             return Err(ErrorType::PermissionDenied);
         }
+        check_tournament_status(
+            &tournament_model,
+            &[
+                TournamentStatus::Draft,
+                TournamentStatus::RegistrationOpen,
+                TournamentStatus::InProgress,
+                TournamentStatus::Finished,
+            ],
+        )?;
         tournament_model.remove_admin(&self.admin_username, connection)?;
         Ok(json!({"message": "Admin removed."}))
     }
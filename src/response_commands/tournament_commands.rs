@@ -1,18 +1,24 @@
 use diesel::PgConnection;
+use itertools::Itertools;
 use rocket_contrib::json::JsonValue;
-use serde_json::Map;
+use serde_json::{Map, Value};
 
 use crate::account::Account;
-use crate::database_models::{TournamentRowModel, UserRowModel};
+use crate::database_models::{
+    PlayerRowModel, RoundDAO, RoundRowModel, TournamentRowModel, UserRowModel,
+};
 use crate::errors::ErrorType;
-use crate::joueurs::{Joueurs, JoueursParser};
+use crate::joueurs::JoueursStore;
 use crate::meta_generator::{
     generate_tournaments_meta, MetaGenerator, TournamentDetailsMetaGenerator,
 };
-use crate::properties::TournamentType;
+use crate::properties::{
+    PairingConstraint, TeamTieBreaker, TieBreaker, TournamentStatus, TournamentType,
+};
+use crate::tournament_manager::get_default_brightwell_constant;
 use crate::utils::string_to_date;
 
-use super::ResponseCommand;
+use super::{check_tournament_status, is_allowed_to_manage_tournament, ResponseCommand};
 
 pub struct GetTournamentCommand {
     pub id: i32,
@@ -87,20 +93,25 @@ impl ResponseCommand for CreateTournamentCommand {
 
         let tournament_type = TournamentType::from_string(self.tournament_type.clone());
 
-        let raw_joueurs = Joueurs::get(3)?;
-        let parsed_joueurs = JoueursParser::parse(&raw_joueurs)?;
+        let joueurs_snapshot = JoueursStore::get_current_snapshot(connection)?;
 
         let start_date = string_to_date(self.start_date.clone())?;
         let end_date = string_to_date(self.end_date.clone())?;
+
+        let mut meta_data = Map::new();
+        meta_data.insert(
+            String::from("brightwell_constant"),
+            Value::from(get_default_brightwell_constant()),
+        );
         TournamentRowModel::create(
             &self.name,
             &self.country,
             &start_date,
             &end_date,
             &account.get_username(),
-            parsed_joueurs,
+            &joueurs_snapshot.id,
             tournament_type,
-            Map::new(),
+            meta_data,
             connection,
         )?;
         Ok(json!({"message": "Tournament created."}))
@@ -118,6 +129,17 @@ pub struct UpdateTournamentCommand {
     pub updated_country: String,
     pub updated_start_date: String,
     pub updated_end_date: String,
+    pub updated_tie_breakers: Option<Vec<String>>,
+    pub updated_brightwell_constant: Option<f64>,
+    pub updated_pairing_constraints: Option<Vec<String>>,
+    pub updated_pairing_constraint_rounds: Option<i32>,
+    pub updated_round_robin_cycles: Option<i32>,
+    pub updated_accelerated_rounds: Option<i32>,
+    pub updated_knockout_best_of: Option<i32>,
+    pub updated_knockout_third_place_match: Option<bool>,
+    pub updated_sections: Option<Vec<String>>,
+    pub updated_team_tie_breakers: Option<Vec<String>>,
+    pub updated_team_pairing_type: Option<String>,
 }
 
 impl UpdateTournamentCommand {
@@ -129,6 +151,94 @@ impl UpdateTournamentCommand {
         let username = current_account.get_username();
         current_account.has_superuser_access() || tournament_model.is_created_by(&username)
     }
+
+    fn parse_tie_breakers(&self, tie_breakers: &Vec<String>) -> Result<Vec<TieBreaker>, ErrorType> {
+        let parsed_tie_breakers: Vec<TieBreaker> = tie_breakers
+            .iter()
+            .map(|tie_breaker| TieBreaker::from_string(tie_breaker.clone()))
+            .collect();
+        if parsed_tie_breakers.contains(&TieBreaker::Unidentified) {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Invalid tie breaker",
+            )));
+        }
+        Ok(parsed_tie_breakers)
+    }
+
+    fn parse_pairing_constraints(
+        &self,
+        pairing_constraints: &Vec<String>,
+    ) -> Result<Vec<PairingConstraint>, ErrorType> {
+        let parsed_pairing_constraints: Vec<PairingConstraint> = pairing_constraints
+            .iter()
+            .map(|pairing_constraint| PairingConstraint::from_string(pairing_constraint.clone()))
+            .collect();
+        if parsed_pairing_constraints.contains(&PairingConstraint::Unidentified) {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Invalid pairing constraint",
+            )));
+        }
+        Ok(parsed_pairing_constraints)
+    }
+
+    fn parse_team_tie_breakers(
+        &self,
+        team_tie_breakers: &Vec<String>,
+    ) -> Result<Vec<TeamTieBreaker>, ErrorType> {
+        let parsed_team_tie_breakers: Vec<TeamTieBreaker> = team_tie_breakers
+            .iter()
+            .map(|team_tie_breaker| TeamTieBreaker::from_string(team_tie_breaker.clone()))
+            .collect();
+        if parsed_team_tie_breakers.contains(&TeamTieBreaker::Unidentified) {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Invalid team tie breaker",
+            )));
+        }
+        Ok(parsed_team_tie_breakers)
+    }
+
+    // Players are split into sections before the first round, so sections are fixed afterwards.
+    fn validate_sections(
+        &self,
+        tournament_model: &TournamentRowModel,
+        sections: &Vec<String>,
+        connection: &PgConnection,
+    ) -> Result<(), ErrorType> {
+        if sections == &tournament_model.get_sections() {
+            return Ok(());
+        }
+        let is_knockout = tournament_model.tournament_type == TournamentType::Knockout.to_string();
+        let is_team = tournament_model.tournament_type == TournamentType::Team.to_string();
+        if !sections.is_empty() && (is_knockout || is_team) {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Sections are unavailable for Knockout and Team tournaments",
+            )));
+        }
+        let has_invalid_name = sections.iter().any(|section| section.trim().is_empty());
+        if has_invalid_name || sections.iter().unique().count() != sections.len() {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Section names must be unique and non-empty",
+            )));
+        }
+
+        let rounds = RoundRowModel::get_all_from_tournament(&tournament_model.id, connection)?;
+        if !rounds.is_empty() {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Sections cannot be changed once rounds are created",
+            )));
+        }
+        let has_removed_section_in_use =
+            PlayerRowModel::get_all_from_tournament(&tournament_model.id, connection)?
+                .iter()
+                .filter_map(|player| player.get_section())
+                .any(|section| !sections.contains(&section));
+        if has_removed_section_in_use {
+            return Err(ErrorType::BadRequestError(String::from(
+                "Sections with players cannot be removed",
+            )));
+        }
+        Ok(())
+    }
 }
 
 impl ResponseCommand for UpdateTournamentCommand {
@@ -139,11 +249,89 @@ impl ResponseCommand for UpdateTournamentCommand {
         if !self.is_able_to_update_tournament(&tournament_model, &account) {
             return Err(ErrorType::PermissionDenied);
         }
+        check_tournament_status(
+            &tournament_model,
+            &[
+                TournamentStatus::Draft,
+                TournamentStatus::RegistrationOpen,
+                TournamentStatus::InProgress,
+            ],
+        )?;
 
         tournament_model.name = self.updated_name.clone();
         tournament_model.country = self.updated_country.clone();
         tournament_model.start_date = string_to_date(self.updated_start_date.clone())?;
         tournament_model.end_date = string_to_date(self.updated_end_date.clone())?;
+        if let Some(tie_breakers) = &self.updated_tie_breakers {
+            let parsed_tie_breakers = self.parse_tie_breakers(tie_breakers)?;
+            tournament_model.set_tie_breakers(&parsed_tie_breakers);
+        }
+        if let Some(brightwell_constant) = &self.updated_brightwell_constant {
+            if !brightwell_constant.is_finite() || brightwell_constant < &0.0 {
+                return Err(ErrorType::BadRequestError(String::from(
+                    "Invalid brightwell constant",
+                )));
+            }
+            tournament_model.set_brightwell_constant(brightwell_constant);
+        }
+        if let Some(pairing_constraints) = &self.updated_pairing_constraints {
+            let parsed_pairing_constraints = self.parse_pairing_constraints(pairing_constraints)?;
+            tournament_model.set_pairing_constraints(&parsed_pairing_constraints);
+        }
+        if let Some(pairing_constraint_rounds) = &self.updated_pairing_constraint_rounds {
+            if pairing_constraint_rounds < &0 {
+                return Err(ErrorType::BadRequestError(String::from(
+                    "Invalid pairing constraint rounds",
+                )));
+            }
+            tournament_model.set_pairing_constraint_rounds(pairing_constraint_rounds);
+        }
+        if let Some(round_robin_cycles) = &self.updated_round_robin_cycles {
+            if round_robin_cycles != &1 && round_robin_cycles != &2 {
+                return Err(ErrorType::BadRequestError(String::from(
+                    "Round robin cycles must be either 1 or 2",
+                )));
+            }
+            tournament_model.set_round_robin_cycles(round_robin_cycles);
+        }
+        if let Some(accelerated_rounds) = &self.updated_accelerated_rounds {
+            if accelerated_rounds < &0 {
+                return Err(ErrorType::BadRequestError(String::from(
+                    "Invalid accelerated rounds",
+                )));
+            }
+            tournament_model.set_accelerated_rounds(accelerated_rounds);
+        }
+        if let Some(knockout_best_of) = &self.updated_knockout_best_of {
+            if knockout_best_of < &1 || knockout_best_of % 2 == 0 {
+                return Err(ErrorType::BadRequestError(String::from(
+                    "Knockout best of must be a positive odd number",
+                )));
+            }
+            tournament_model.set_knockout_best_of(knockout_best_of);
+        }
+        if let Some(knockout_third_place_match) = &self.updated_knockout_third_place_match {
+            tournament_model.set_knockout_third_place_match(knockout_third_place_match);
+        }
+        if let Some(sections) = &self.updated_sections {
+            self.validate_sections(&tournament_model, sections, connection)?;
+            tournament_model.set_sections(sections);
+        }
+        if let Some(team_tie_breakers) = &self.updated_team_tie_breakers {
+            let parsed_team_tie_breakers = self.parse_team_tie_breakers(team_tie_breakers)?;
+            tournament_model.set_team_tie_breakers(&parsed_team_tie_breakers);
+        }
+        if let Some(team_pairing_type) = &self.updated_team_pairing_type {
+            let parsed_team_pairing_type = TournamentType::from_string(team_pairing_type.clone());
+            if parsed_team_pairing_type != TournamentType::SwissPairing
+                && parsed_team_pairing_type != TournamentType::RoundRobin
+            {
+                return Err(ErrorType::BadRequestError(String::from(
+                    "Team pairing type must be either swiss_pairing or round_robin",
+                )));
+            }
+            tournament_model.set_team_pairing_type(&parsed_team_pairing_type);
+        }
         tournament_model.update(connection)?;
         Ok(json!({"message": "Tournament updated."}))
     }
@@ -153,6 +341,59 @@ impl ResponseCommand for UpdateTournamentCommand {
     }
 }
 
+pub struct UpdateTournamentStatusCommand {
+    pub jwt: String,
+    pub id: i32,
+    pub updated_status: TournamentStatus,
+}
+
+impl ResponseCommand for UpdateTournamentStatusCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let account = Account::login_from_jwt(&self.jwt, connection)?;
+        let mut tournament_model = TournamentRowModel::get(&self.id, connection)?;
+
+        let is_allowed_to_manage =
+            is_allowed_to_manage_tournament(&account, &tournament_model, connection)?;
+        if !is_allowed_to_manage {
+            return Err(ErrorType::PermissionDenied);
+        }
+
+        // Tournaments only move forward one status at a time.
+        let current_status = tournament_model.get_status();
+        if self.updated_status.get_previous_status() != Some(current_status) {
+            return Err(ErrorType::InvalidTournamentStatus(
+                current_status.to_string(),
+            ));
+        }
+        if self.updated_status == TournamentStatus::InProgress && tournament_model.has_sections() {
+            let has_player_without_section =
+                PlayerRowModel::get_all_from_tournament(&self.id, connection)?
+                    .iter()
+                    .any(|player| player.get_section().is_none());
+            if has_player_without_section {
+                return Err(ErrorType::BadRequestError(String::from(
+                    "Every player needs a section before the tournament starts",
+                )));
+            }
+        }
+
+        tournament_model.set_status(&self.updated_status);
+        tournament_model.update(connection)?;
+        Ok(json!({
+            "message": "Tournament status updated.",
+            "status": self.updated_status.to_string(),
+        }))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!(
+            "UpdateTournamentStatus to {} for {}",
+            self.updated_status.to_string(),
+            &self.id
+        ))
+    }
+}
+
 pub struct DeleteTournamentCommand {
     pub jwt: String,
     pub id: i32,
@@ -177,6 +418,15 @@ impl ResponseCommand for DeleteTournamentCommand {
         if !self.is_able_to_delete_tournament(&tournament_model, &account) {
             return Err(ErrorType::PermissionDenied);
         }
+        check_tournament_status(
+            &tournament_model,
+            &[
+                TournamentStatus::Draft,
+                TournamentStatus::RegistrationOpen,
+                TournamentStatus::InProgress,
+                TournamentStatus::Finished,
+            ],
+        )?;
 
         tournament_model.delete(connection)?;
         Ok(json!({"message": "Tournament deleted."}))
@@ -1,6 +1,8 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use diesel::PgConnection;
+use itertools::Itertools;
+use rocket::http::ContentType;
 use rocket_contrib::json::JsonValue;
 use serde_json::{Map, Value};
 
@@ -8,15 +10,45 @@ use crate::database_models::{
     MatchDAO, MatchRowModel, PlayerRowModel, RoundDAO, RoundRowModel, TournamentRowModel,
 };
 use crate::errors::ErrorType;
+use crate::exporters::{generate_crosstable_csv, generate_crosstable_html, generate_wof_results};
 use crate::game_match::GameMatchTransformer;
 use crate::meta_generator::{
-    MatchMetaGenerator, MatchSummaryMetaGenerator, RoundMetaGenerator, RoundSummaryMetaGenerator,
-    StandingMetaGenerator, StandingSummaryMetaGenerator, TournamentMetaGenerator,
-    TournamentSummaryMetaGenerator,
+    CrosstableMetaGenerator, CrosstableSummaryMetaGenerator, DefaultKnockoutTieMetaGenerator,
+    DefaultRatingChangeMetaGenerator, KnockoutTieMetaGenerator, MatchMetaGenerator,
+    MatchSummaryMetaGenerator, RatingChangeMetaGenerator, RoundMetaGenerator,
+    RoundSummaryMetaGenerator, StandingMetaGenerator, StandingSummaryMetaGenerator,
+    TournamentMetaGenerator, TournamentSummaryMetaGenerator,
+};
+use crate::properties::TournamentType;
+use crate::tournament_manager::{
+    create_crosstable, create_result_keeper_with_config, CrosstableRow, StandingsConfig,
+};
+
+use super::{
+    create_knockout_pairings_generator, create_rating_calculator, create_standings_config,
+    generate_team_standings_meta, get_section_players, get_section_rounds, validate_section,
+    FileContent, FileResponseCommand, ResponseCommand,
 };
-use crate::tournament_manager::create_result_keeper;
 
-use super::ResponseCommand;
+fn generate_rating_changes_meta(
+    players: &Vec<PlayerRowModel>,
+    players_by_id: &HashMap<&i32, &PlayerRowModel>,
+    game_matches: &Vec<MatchRowModel>,
+) -> Vec<Map<String, Value>> {
+    let rating_change_meta_generator = DefaultRatingChangeMetaGenerator {
+        players_by_id: players_by_id.clone(),
+    };
+
+    let transformed_matches = game_matches
+        .iter()
+        .map(|game_match| GameMatchTransformer::transform_to_game_match(game_match))
+        .collect();
+    create_rating_calculator(players)
+        .calculate_rating_changes(&transformed_matches)
+        .iter()
+        .map(|rating_change| rating_change_meta_generator.generate_meta_for(rating_change))
+        .collect()
+}
 
 pub struct GetTournamentSummaryCommand {
     pub id: i32,
@@ -34,17 +66,19 @@ impl GetTournamentSummaryCommand {
     fn get_standings_summary_meta(
         &self,
         players_by_id: &HashMap<&i32, &PlayerRowModel>,
-        game_matches: &Vec<MatchRowModel>,
+        game_matches: Vec<&MatchRowModel>,
+        standings_config: &StandingsConfig,
     ) -> Vec<Map<String, Value>> {
         let standing_meta_generator = StandingSummaryMetaGenerator {
             players_by_id: players_by_id.clone(),
         };
 
         let transformed_matches = game_matches
-            .iter()
+            .into_iter()
             .map(|game_match| GameMatchTransformer::transform_to_game_match(game_match))
             .collect();
-        let result_keeper = create_result_keeper(&transformed_matches);
+        let result_keeper =
+            create_result_keeper_with_config(&transformed_matches, standings_config);
         let standings = result_keeper.get_detailed_standings();
         standings
             .iter()
@@ -52,6 +86,43 @@ impl GetTournamentSummaryCommand {
             .collect()
     }
 
+    fn get_sections_summary_meta(
+        &self,
+        tournament: &TournamentRowModel,
+        players_by_id: &HashMap<&i32, &PlayerRowModel>,
+        rounds: &Vec<RoundRowModel>,
+        game_matches: &Vec<MatchRowModel>,
+        standings_config: &StandingsConfig,
+    ) -> Vec<Map<String, Value>> {
+        tournament
+            .get_sections()
+            .into_iter()
+            .map(|section| {
+                let section_round_ids: HashSet<i32> = rounds
+                    .iter()
+                    .filter(|round| round.get_section().as_ref() == Some(&section))
+                    .map(|round| round.id)
+                    .collect();
+                let section_matches = game_matches
+                    .iter()
+                    .filter(|game_match| section_round_ids.contains(&game_match.round_id))
+                    .collect();
+
+                let mut section_meta = Map::new();
+                section_meta.insert(String::from("name"), Value::from(section));
+                section_meta.insert(
+                    String::from("standings"),
+                    Value::from(self.get_standings_summary_meta(
+                        players_by_id,
+                        section_matches,
+                        standings_config,
+                    )),
+                );
+                section_meta
+            })
+            .collect()
+    }
+
     fn get_rounds_summary_meta(
         &self,
         players_by_id: &HashMap<&i32, &PlayerRowModel>,
@@ -77,6 +148,41 @@ impl GetTournamentSummaryCommand {
             })
             .collect()
     }
+
+    fn get_bracket_summary_meta(
+        &self,
+        tournament: &TournamentRowModel,
+        players_by_id: &HashMap<&i32, &PlayerRowModel>,
+        connection: &PgConnection,
+    ) -> Result<Value, ErrorType> {
+        if tournament.tournament_type != TournamentType::Knockout.to_string() {
+            return Ok(Value::Null);
+        }
+
+        let tie_meta_generator = DefaultKnockoutTieMetaGenerator {
+            players_by_id: players_by_id.clone(),
+        };
+        let bracket_meta: Vec<Map<String, Value>> =
+            create_knockout_pairings_generator(tournament, connection)?
+                .get_bracket()
+                .iter()
+                .map(|tie| tie_meta_generator.generate_meta_for(tie))
+                .collect();
+        Ok(Value::from(bracket_meta))
+    }
+
+    fn get_team_standings_summary_meta(
+        &self,
+        tournament: &TournamentRowModel,
+        connection: &PgConnection,
+    ) -> Result<Value, ErrorType> {
+        if tournament.tournament_type != TournamentType::Team.to_string() {
+            return Ok(Value::Null);
+        }
+        Ok(Value::from(generate_team_standings_meta(
+            tournament, connection,
+        )?))
+    }
 }
 
 impl ResponseCommand for GetTournamentSummaryCommand {
@@ -91,11 +197,32 @@ impl ResponseCommand for GetTournamentSummaryCommand {
 
         let rounds = RoundRowModel::get_all_from_tournament(&self.id, connection)?;
         let game_matches = MatchRowModel::get_all_from_tournament(&self.id, connection)?;
+        let standings_config = create_standings_config(&tournament, &players);
+        let bracket = self.get_bracket_summary_meta(&tournament, &players_by_id, connection)?;
+        let team_standings = self.get_team_standings_summary_meta(&tournament, connection)?;
 
         Ok(json!({
             "tournament_info": self.get_tournament_info_summary_meta(&tournament),
-            "standings": self.get_standings_summary_meta(&players_by_id, &game_matches),
+            "standings": self.get_standings_summary_meta(
+                &players_by_id,
+                game_matches.iter().collect(),
+                &standings_config
+            ),
+            "sections": self.get_sections_summary_meta(
+                &tournament,
+                &players_by_id,
+                &rounds,
+                &game_matches,
+                &standings_config
+            ),
             "rounds": self.get_rounds_summary_meta(&players_by_id, &rounds, &game_matches),
+            "rating_changes": generate_rating_changes_meta(
+                &players,
+                &players_by_id,
+                &game_matches
+            ),
+            "bracket": bracket,
+            "team_standings": team_standings,
         }))
     }
 
@@ -103,3 +230,161 @@ impl ResponseCommand for GetTournamentSummaryCommand {
         String::from(format!("GetTournamentSummary for {}", &self.id))
     }
 }
+
+pub struct GetTournamentRatingChangesCommand {
+    pub tournament_id: i32,
+}
+
+impl ResponseCommand for GetTournamentRatingChangesCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let players = PlayerRowModel::get_all_from_tournament(&self.tournament_id, connection)?;
+        let players_by_id: HashMap<&i32, &PlayerRowModel> =
+            players.iter().map(|player| (&player.id, player)).collect();
+        let game_matches = MatchRowModel::get_all_from_tournament(&self.tournament_id, connection)?;
+
+        Ok(json!({
+            "tournament_id": &self.tournament_id,
+            "rating_changes": generate_rating_changes_meta(&players, &players_by_id, &game_matches),
+        }))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!(
+            "GetTournamentRatingChanges for {}",
+            &self.tournament_id
+        ))
+    }
+}
+
+pub struct ExportWofResultsCommand {
+    pub tournament_id: i32,
+}
+
+impl FileResponseCommand for ExportWofResultsCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<FileContent, ErrorType> {
+        let tournament = TournamentRowModel::get(&self.tournament_id, connection)?;
+        let players = PlayerRowModel::get_all_from_tournament(&self.tournament_id, connection)?;
+        let rounds = RoundRowModel::get_all_from_tournament(&self.tournament_id, connection)?;
+        let game_matches = MatchRowModel::get_all_from_tournament(&self.tournament_id, connection)?;
+
+        Ok(FileContent {
+            file_name: format!("tournament_{}_wof_results.txt", &self.tournament_id),
+            content_type: ContentType::Plain,
+            content: generate_wof_results(&tournament, &players, &rounds, &game_matches)?,
+        })
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!("ExportWofResults for {}", &self.tournament_id))
+    }
+}
+
+// Returns the section players and rounds together with the crosstable rows, rounds are ordered as
+// they were created.
+fn create_section_crosstable(
+    tournament: &TournamentRowModel,
+    section: &Option<String>,
+    connection: &PgConnection,
+) -> Result<(Vec<PlayerRowModel>, Vec<RoundRowModel>, Vec<CrosstableRow>), ErrorType> {
+    validate_section(tournament, section)?;
+    let players = get_section_players(&tournament.id, section, connection)?;
+    let rounds: Vec<RoundRowModel> = get_section_rounds(&tournament.id, section, connection)?
+        .into_iter()
+        .sorted_by_key(|round| round.id)
+        .collect();
+    let round_ids: Vec<i32> = rounds.iter().map(|round| round.id).collect();
+    let section_round_ids: HashSet<&i32> = round_ids.iter().collect();
+
+    let transformed_matches = MatchRowModel::get_all_from_tournament(&tournament.id, connection)?
+        .iter()
+        .filter(|game_match| section_round_ids.contains(&game_match.round_id))
+        .map(|game_match| GameMatchTransformer::transform_to_game_match(game_match))
+        .collect();
+    let standings_config = create_standings_config(tournament, &players);
+    let result_keeper = create_result_keeper_with_config(&transformed_matches, &standings_config);
+    let player_ids: Vec<i32> = players.iter().map(|player| player.id).collect();
+    let rows = create_crosstable(&result_keeper, &player_ids, &round_ids);
+    Ok((players, rounds, rows))
+}
+
+pub struct GetCrosstableCommand {
+    pub tournament_id: i32,
+    pub section: Option<String>,
+}
+
+impl ResponseCommand for GetCrosstableCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<JsonValue, ErrorType> {
+        let tournament = TournamentRowModel::get(&self.tournament_id, connection)?;
+        let (players, rounds, rows) =
+            create_section_crosstable(&tournament, &self.section, connection)?;
+
+        let round_meta_generator = RoundSummaryMetaGenerator {};
+        let rounds_meta: Vec<Map<String, Value>> = rounds
+            .iter()
+            .map(|round| round_meta_generator.generate_meta_for(round))
+            .collect();
+        let crosstable_meta_generator = CrosstableSummaryMetaGenerator {
+            players_by_id: players.iter().map(|player| (&player.id, player)).collect(),
+        };
+        let crosstable_meta: Vec<Map<String, Value>> = rows
+            .iter()
+            .map(|row| crosstable_meta_generator.generate_meta_for(row))
+            .collect();
+        Ok(json!({
+            "tournament_id": &self.tournament_id,
+            "section": &self.section,
+            "rounds": rounds_meta,
+            "crosstable": crosstable_meta,
+        }))
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!("GetCrosstable for {}", &self.tournament_id))
+    }
+}
+
+pub struct ExportCrosstableHtmlCommand {
+    pub tournament_id: i32,
+    pub section: Option<String>,
+}
+
+impl FileResponseCommand for ExportCrosstableHtmlCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<FileContent, ErrorType> {
+        let tournament = TournamentRowModel::get(&self.tournament_id, connection)?;
+        let (players, rounds, rows) =
+            create_section_crosstable(&tournament, &self.section, connection)?;
+
+        Ok(FileContent {
+            file_name: format!("tournament_{}_crosstable.html", &self.tournament_id),
+            content_type: ContentType::HTML,
+            content: generate_crosstable_html(&tournament, &players, &rounds, &rows),
+        })
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!("ExportCrosstableHtml for {}", &self.tournament_id))
+    }
+}
+
+pub struct ExportCrosstableCsvCommand {
+    pub tournament_id: i32,
+    pub section: Option<String>,
+}
+
+impl FileResponseCommand for ExportCrosstableCsvCommand {
+    fn do_execute(&self, connection: &PgConnection) -> Result<FileContent, ErrorType> {
+        let tournament = TournamentRowModel::get(&self.tournament_id, connection)?;
+        let (players, rounds, rows) =
+            create_section_crosstable(&tournament, &self.section, connection)?;
+
+        Ok(FileContent {
+            file_name: format!("tournament_{}_crosstable.csv", &self.tournament_id),
+            content_type: ContentType::CSV,
+            content: generate_crosstable_csv(&players, &rounds, &rows),
+        })
+    }
+
+    fn get_request_summary(&self) -> String {
+        String::from(format!("ExportCrosstableCsv for {}", &self.tournament_id))
+    }
+}
@@ -1,3 +1,4 @@
+use rocket::response::status::Custom;
 use rocket_contrib::json::{Json, JsonValue};
 use serde::Deserialize;
 
@@ -14,7 +15,7 @@ pub struct UserLoginRequest {
 }
 
 #[post("/login", data = "<request>")]
-pub fn login(request: Json<UserLoginRequest>) -> Json<JsonValue> {
+pub fn login(request: Json<UserLoginRequest>) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
 
     response_commands::LoginCommand {
@@ -25,7 +26,7 @@ pub fn login(request: Json<UserLoginRequest>) -> Json<JsonValue> {
 }
 
 #[get("/profile")]
-pub fn get_current_user_profile(token: Token) -> Json<JsonValue> {
+pub fn get_current_user_profile(token: Token) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::CurrentUserCommand { jwt: token.jwt }.execute(&connection)
 }
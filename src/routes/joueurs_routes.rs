@@ -0,0 +1,33 @@
+use rocket::response::status::Custom;
+use rocket_contrib::json::{Json, JsonValue};
+use serde::Deserialize;
+
+use crate::response_commands;
+use crate::response_commands::ResponseCommand;
+use crate::utils::get_pooled_connection;
+
+use super::Token;
+
+#[get("/")]
+pub fn get_joueurs_snapshots(token: Token) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    response_commands::GetJoueursSnapshotsCommand { jwt: token.jwt }.execute(&connection)
+}
+
+#[derive(Deserialize)]
+pub struct UploadJoueursRequest {
+    joueurs: String,
+}
+
+#[post("/", data = "<request>")]
+pub fn upload_joueurs(
+    token: Token,
+    request: Json<UploadJoueursRequest>,
+) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    response_commands::UploadJoueursCommand {
+        jwt: token.jwt,
+        joueurs: request.joueurs.clone(),
+    }
+    .execute(&connection)
+}
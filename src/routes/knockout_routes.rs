@@ -0,0 +1,33 @@
+use rocket::response::status::Custom;
+use rocket_contrib::json::{Json, JsonValue};
+use serde::Deserialize;
+
+use crate::response_commands;
+use crate::response_commands::ResponseCommand;
+use crate::utils::get_pooled_connection;
+
+use super::Token;
+
+#[derive(Deserialize)]
+pub struct SeedKnockoutRequest {
+    player_ids: Option<Vec<i32>>,
+    source_tournament_id: Option<i32>,
+    player_count: Option<i32>,
+}
+
+#[post("/<tournament_id>/knockout/seed", data = "<request>")]
+pub fn seed_knockout(
+    token: Token,
+    tournament_id: i32,
+    request: Json<SeedKnockoutRequest>,
+) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    response_commands::SeedKnockoutTournamentCommand {
+        jwt: token.jwt,
+        tournament_id,
+        player_ids: request.player_ids.clone(),
+        source_tournament_id: request.source_tournament_id.clone(),
+        player_count: request.player_count.clone(),
+    }
+    .execute(&connection)
+}
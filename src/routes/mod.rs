@@ -1,9 +1,12 @@
 pub mod general_routes;
+pub mod joueurs_routes;
+pub mod knockout_routes;
 pub mod player_routes;
+mod request_guards;
 pub mod round_match_routes;
+pub mod team_routes;
 pub mod tournament_admin_routes;
 pub mod tournament_routes;
 pub mod user_routes;
-mod request_guards;
 
 use request_guards::Token;
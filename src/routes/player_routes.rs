@@ -1,31 +1,67 @@
+use rocket::response::status::Custom;
 use rocket_contrib::json::{Json, JsonValue};
 use serde::Deserialize;
 
 use crate::response_commands;
 use crate::response_commands::ResponseCommand;
+use crate::tournament_manager::RequestedBye;
 use crate::utils::get_pooled_connection;
 
 use super::Token;
 
 #[get("/<id>/players")]
-pub fn get_players(id: i32) -> Json<JsonValue> {
+pub fn get_players(id: i32) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::GetTournamentPlayersCommand { tournament_id: id }.execute(&connection)
 }
 
 #[get("/<id>/joueurs_players")]
-pub fn get_joueurs_players(id: i32) -> Json<JsonValue> {
+pub fn get_joueurs_players(id: i32) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::GetTournamentJoueursPlayersCommand { tournament_id: id }.execute(&connection)
 }
 
+#[derive(Deserialize)]
+pub struct RefreshJoueursRequest {
+    pub joueurs_snapshot_id: Option<i32>,
+}
+
+#[post("/<id>/joueurs_players/refresh", data = "<request>")]
+pub fn refresh_joueurs(
+    token: Token,
+    id: i32,
+    request: Json<RefreshJoueursRequest>,
+) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    response_commands::RefreshTournamentJoueursCommand {
+        jwt: token.jwt,
+        tournament_id: id,
+        joueurs_snapshot_id: request.joueurs_snapshot_id.clone(),
+    }
+    .execute(&connection)
+}
+
+#[get("/<tournament_id>/players/<player_id>/games")]
+pub fn get_player_games(tournament_id: i32, player_id: i32) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    response_commands::GetPlayerGamesCommand {
+        tournament_id,
+        player_id,
+    }
+    .execute(&connection)
+}
+
 #[derive(Deserialize)]
 pub struct AddPlayerRequest {
     pub joueurs_id: String,
 }
 
 #[post("/<id>/players", data = "<request>")]
-pub fn add_player(token: Token, id: i32, request: Json<AddPlayerRequest>) -> Json<JsonValue> {
+pub fn add_player(
+    token: Token,
+    id: i32,
+    request: Json<AddPlayerRequest>,
+) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     let command = response_commands::AddTournamentPlayerCommand {
         jwt: token.jwt,
@@ -47,7 +83,7 @@ pub fn add_player_new(
     token: Token,
     id: i32,
     request: Json<AddPlayerNewRequest>,
-) -> Json<JsonValue> {
+) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     let command = response_commands::AddTournamentPlayerNewCommand {
         jwt: token.jwt,
@@ -59,8 +95,56 @@ pub fn add_player_new(
     command.execute(&connection)
 }
 
+#[derive(Deserialize)]
+pub struct AddLatePlayerRequest {
+    pub joueurs_id: String,
+    pub section: Option<String>,
+    pub bye_major_score: f64,
+}
+
+#[post("/<id>/players/late", data = "<request>")]
+pub fn add_late_player(
+    token: Token,
+    id: i32,
+    request: Json<AddLatePlayerRequest>,
+) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    let command = response_commands::AddLateTournamentPlayerCommand {
+        jwt: token.jwt,
+        tournament_id: id,
+        joueurs_id: request.joueurs_id.clone(),
+        section: request.section.clone(),
+        bye_major_score: request.bye_major_score.clone(),
+    };
+    command.execute(&connection)
+}
+
+#[derive(Deserialize)]
+pub struct UpdatePlayerRequest {
+    team: Option<String>,
+    section: Option<String>,
+}
+
+#[patch("/<tournament_id>/players/<player_id>", data = "<request>")]
+pub fn update_player(
+    token: Token,
+    tournament_id: i32,
+    player_id: i32,
+    request: Json<UpdatePlayerRequest>,
+) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    let command = response_commands::UpdateTournamentPlayerCommand {
+        jwt: token.jwt,
+        tournament_id,
+        player_id,
+        updated_team: request.team.clone(),
+        updated_section: request.section.clone(),
+    };
+    command.execute(&connection)
+}
+
 #[delete("/<tournament_id>/players/<player_id>")]
-pub fn delete_player(token: Token, tournament_id: i32, player_id: i32) -> Json<JsonValue> {
+pub fn delete_player(token: Token, tournament_id: i32, player_id: i32) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     let command = response_commands::DeleteTournamentPlayerCommand {
         jwt: token.jwt,
@@ -69,3 +153,66 @@ pub fn delete_player(token: Token, tournament_id: i32, player_id: i32) -> Json<J
     };
     command.execute(&connection)
 }
+
+#[post("/<tournament_id>/players/<player_id>/withdraw")]
+pub fn withdraw_player(
+    token: Token,
+    tournament_id: i32,
+    player_id: i32,
+) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    let command = response_commands::WithdrawTournamentPlayerCommand {
+        jwt: token.jwt,
+        tournament_id,
+        player_id,
+    };
+    command.execute(&connection)
+}
+
+#[derive(Deserialize)]
+pub struct RequestByeRequest {
+    pub round_number: i32,
+    pub major_score: f64,
+    pub disc_count: i32,
+}
+
+#[post(
+    "/<tournament_id>/players/<player_id>/requested_byes",
+    data = "<request>"
+)]
+pub fn request_player_bye(
+    token: Token,
+    tournament_id: i32,
+    player_id: i32,
+    request: Json<RequestByeRequest>,
+) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    let command = response_commands::RequestTournamentPlayerByeCommand {
+        jwt: token.jwt,
+        tournament_id,
+        player_id,
+        requested_bye: RequestedBye {
+            round_number: request.round_number.clone(),
+            major_score: request.major_score.clone(),
+            disc_count: request.disc_count.clone(),
+        },
+    };
+    command.execute(&connection)
+}
+
+#[delete("/<tournament_id>/players/<player_id>/requested_byes/<round_number>")]
+pub fn cancel_player_bye(
+    token: Token,
+    tournament_id: i32,
+    player_id: i32,
+    round_number: i32,
+) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    let command = response_commands::CancelTournamentPlayerByeCommand {
+        jwt: token.jwt,
+        tournament_id,
+        player_id,
+        round_number,
+    };
+    command.execute(&connection)
+}
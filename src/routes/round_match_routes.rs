@@ -1,3 +1,4 @@
+use rocket::response::status::Custom;
 use rocket_contrib::json::{Json, JsonValue};
 use serde::Deserialize;
 
@@ -8,13 +9,14 @@ use crate::utils::get_pooled_connection;
 use super::Token;
 
 #[get("/<tournament_id>/rounds")]
-pub fn get_tournament_rounds(tournament_id: i32) -> Json<JsonValue> {
+pub fn get_tournament_rounds(tournament_id: i32) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::GetTournamentRoundsCommand { tournament_id }.execute(&connection)
 }
 
 #[derive(Deserialize)]
 pub struct CreateManualNormalRoundRequest {
+    section: Option<String>,
     name: String,
     match_data: Vec<(i32, i32)>,
     bye_match_data: Vec<i32>,
@@ -25,11 +27,12 @@ pub fn create_manual_normal_round(
     token: Token,
     tournament_id: i32,
     request: Json<CreateManualNormalRoundRequest>,
-) -> Json<JsonValue> {
+) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::CreateManualNormalRoundCommand {
         jwt: token.jwt,
         tournament_id,
+        section: request.section.clone(),
         name: request.name.clone(),
         match_data: request.match_data.clone(),
         bye_match_data: request.bye_match_data.clone(),
@@ -39,6 +42,7 @@ pub fn create_manual_normal_round(
 
 #[derive(Deserialize)]
 pub struct CreateManualSpecialRoundRequest {
+    section: Option<String>,
     name: String,
     match_data: Vec<(i32, i32)>,
     bye_match_data: Vec<i32>,
@@ -49,11 +53,12 @@ pub fn create_manual_special_round(
     token: Token,
     tournament_id: i32,
     request: Json<CreateManualSpecialRoundRequest>,
-) -> Json<JsonValue> {
+) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::CreateManualSpecialRoundCommand {
         jwt: token.jwt,
         tournament_id,
+        section: request.section.clone(),
         name: request.name.clone(),
         match_data: request.match_data.clone(),
         bye_match_data: request.bye_match_data.clone(),
@@ -63,6 +68,7 @@ pub fn create_manual_special_round(
 
 #[derive(Deserialize)]
 pub struct CreateAutomaticRoundRequest {
+    section: Option<String>,
     name: String,
 }
 
@@ -71,24 +77,83 @@ pub fn create_automatic_round(
     token: Token,
     tournament_id: i32,
     request: Json<CreateAutomaticRoundRequest>,
-) -> Json<JsonValue> {
+) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::CreateAutomaticRoundCommand {
         jwt: token.jwt,
         tournament_id,
+        section: request.section.clone(),
         name: request.name.clone(),
     }
     .execute(&connection)
 }
 
-#[get("/<_tournament_id>/rounds/<round_id>")]
-pub fn get_round(_tournament_id: i32, round_id: i32) -> Json<JsonValue> {
+#[post("/<tournament_id>/rounds/preview_automatic?<section>")]
+pub fn preview_automatic_round(
+    token: Token,
+    tournament_id: i32,
+    section: Option<String>,
+) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    response_commands::PreviewAutomaticRoundCommand {
+        jwt: token.jwt,
+        tournament_id,
+        section,
+    }
+    .execute(&connection)
+}
+
+#[get("/<tournament_id>/schedule?<section>")]
+pub fn get_round_robin_schedule(
+    tournament_id: i32,
+    section: Option<String>,
+) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    response_commands::GetRoundRobinScheduleCommand {
+        tournament_id,
+        section,
+    }
+    .execute(&connection)
+}
+
+#[derive(Deserialize)]
+pub struct CommitAutomaticRoundRequest {
+    section: Option<String>,
+    name: String,
+    match_data: Vec<(i32, i32)>,
+    bye_match_data: Vec<i32>,
+}
+
+#[post("/<tournament_id>/rounds/commit_automatic", data = "<request>")]
+pub fn commit_automatic_round(
+    token: Token,
+    tournament_id: i32,
+    request: Json<CommitAutomaticRoundRequest>,
+) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    response_commands::CommitAutomaticRoundCommand {
+        jwt: token.jwt,
+        tournament_id,
+        section: request.section.clone(),
+        name: request.name.clone(),
+        match_data: request.match_data.clone(),
+        bye_match_data: request.bye_match_data.clone(),
+    }
+    .execute(&connection)
+}
+
+#[get("/<tournament_id>/rounds/<round_id>")]
+pub fn get_round(tournament_id: i32, round_id: i32) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
-    response_commands::GetRoundCommand { round_id }.execute(&connection)
+    response_commands::GetRoundCommand {
+        tournament_id,
+        round_id,
+    }
+    .execute(&connection)
 }
 
 #[get("/<tournament_id>/rounds/<round_id>/standings")]
-pub fn get_standings(tournament_id: i32, round_id: i32) -> Json<JsonValue> {
+pub fn get_standings(tournament_id: i32, round_id: i32) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::GetStandingsCommand {
         round_id_limit: round_id,
@@ -108,7 +173,7 @@ pub fn update_round(
     tournament_id: i32,
     round_id: i32,
     request: Json<UpdateRoundRequest>,
-) -> Json<JsonValue> {
+) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::UpdateRoundCommand {
         jwt: token.jwt,
@@ -120,7 +185,7 @@ pub fn update_round(
 }
 
 #[delete("/<tournament_id>/rounds/<round_id>")]
-pub fn delete_round(token: Token, tournament_id: i32, round_id: i32) -> Json<JsonValue> {
+pub fn delete_round(token: Token, tournament_id: i32, round_id: i32) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::DeleteRoundCommand {
         jwt: token.jwt,
@@ -130,36 +195,73 @@ pub fn delete_round(token: Token, tournament_id: i32, round_id: i32) -> Json<Jso
     .execute(&connection)
 }
 
-#[get("/<_tournament_id>/rounds/<round_id>/matches")]
-pub fn get_round_matches(_tournament_id: i32, round_id: i32) -> Json<JsonValue> {
+#[get("/<tournament_id>/rounds/<round_id>/matches")]
+pub fn get_round_matches(tournament_id: i32, round_id: i32) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
-    response_commands::GetRoundMatchesCommand { round_id }.execute(&connection)
+    response_commands::GetRoundMatchesCommand {
+        tournament_id,
+        round_id,
+    }
+    .execute(&connection)
 }
 
 #[derive(Deserialize)]
 pub struct UpdateMatchRequest {
     black_score: i32,
     white_score: i32,
+    result_type: Option<String>,
 }
 
 #[patch(
-    "/<tournament_id>/rounds/<_round_id>/matches/<match_id>",
+    "/<tournament_id>/rounds/<round_id>/matches/<match_id>",
     data = "<request>"
 )]
 pub fn update_match(
     token: Token,
     tournament_id: i32,
-    _round_id: i32,
+    round_id: i32,
     match_id: i32,
     request: Json<UpdateMatchRequest>,
-) -> Json<JsonValue> {
+) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::UpdateMatchCommand {
         jwt: token.jwt,
         tournament_id,
+        round_id,
         match_id,
         black_score: request.black_score.clone(),
         white_score: request.white_score.clone(),
+        result_type: request
+            .result_type
+            .clone()
+            .unwrap_or(String::from("normal")),
+    }
+    .execute(&connection)
+}
+
+#[derive(Deserialize)]
+pub struct UpdateMatchTranscriptRequest {
+    transcript: String,
+}
+
+#[put(
+    "/<tournament_id>/rounds/<round_id>/matches/<match_id>/transcript",
+    data = "<request>"
+)]
+pub fn update_match_transcript(
+    token: Token,
+    tournament_id: i32,
+    round_id: i32,
+    match_id: i32,
+    request: Json<UpdateMatchTranscriptRequest>,
+) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    response_commands::UpdateMatchTranscriptCommand {
+        jwt: token.jwt,
+        tournament_id,
+        round_id,
+        match_id,
+        transcript: request.transcript.clone(),
     }
     .execute(&connection)
 }
@@ -0,0 +1,74 @@
+use rocket::response::status::Custom;
+use rocket_contrib::json::{Json, JsonValue};
+use serde::Deserialize;
+
+use crate::response_commands;
+use crate::response_commands::ResponseCommand;
+use crate::utils::get_pooled_connection;
+
+use super::Token;
+
+#[get("/<id>/teams")]
+pub fn get_teams(id: i32) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    response_commands::GetTournamentTeamsCommand { tournament_id: id }.execute(&connection)
+}
+
+#[get("/<id>/team_standings")]
+pub fn get_team_standings(id: i32) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    response_commands::GetTeamStandingsCommand { tournament_id: id }.execute(&connection)
+}
+
+#[derive(Deserialize)]
+pub struct AddTeamRequest {
+    pub name: String,
+    pub board_player_ids: Vec<i32>,
+}
+
+#[post("/<id>/teams", data = "<request>")]
+pub fn add_team(token: Token, id: i32, request: Json<AddTeamRequest>) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    let command = response_commands::AddTournamentTeamCommand {
+        jwt: token.jwt,
+        tournament_id: id,
+        name: request.name.clone(),
+        board_player_ids: request.board_player_ids.clone(),
+    };
+    command.execute(&connection)
+}
+
+#[derive(Deserialize)]
+pub struct UpdateTeamRequest {
+    pub name: Option<String>,
+    pub board_player_ids: Option<Vec<i32>>,
+}
+
+#[patch("/<tournament_id>/teams/<team_id>", data = "<request>")]
+pub fn update_team(
+    token: Token,
+    tournament_id: i32,
+    team_id: i32,
+    request: Json<UpdateTeamRequest>,
+) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    let command = response_commands::UpdateTournamentTeamCommand {
+        jwt: token.jwt,
+        tournament_id,
+        team_id,
+        updated_name: request.name.clone(),
+        updated_board_player_ids: request.board_player_ids.clone(),
+    };
+    command.execute(&connection)
+}
+
+#[delete("/<tournament_id>/teams/<team_id>")]
+pub fn delete_team(token: Token, tournament_id: i32, team_id: i32) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    let command = response_commands::DeleteTournamentTeamCommand {
+        jwt: token.jwt,
+        tournament_id,
+        team_id,
+    };
+    command.execute(&connection)
+}
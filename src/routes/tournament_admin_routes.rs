@@ -1,3 +1,4 @@
+use rocket::response::status::Custom;
 use rocket_contrib::json::{Json, JsonValue};
 use serde::Deserialize;
 
@@ -8,13 +9,13 @@ use crate::utils::get_pooled_connection;
 use super::Token;
 
 #[get("/<id>/admins")]
-pub fn get_tournament_admins(id: i32) -> Json<JsonValue> {
+pub fn get_tournament_admins(id: i32) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::GetAllAdminsCommand { tournament_id: id }.execute(&connection)
 }
 
 #[get("/<id>/potential_admins")]
-pub fn get_tournament_potential_admins(id: i32) -> Json<JsonValue> {
+pub fn get_tournament_potential_admins(id: i32) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::GetPotentialAdminsCommand { tournament_id: id }.execute(&connection)
 }
@@ -25,7 +26,7 @@ pub struct AddAdminRequest {
 }
 
 #[post("/<id>/admins", data = "<request>")]
-pub fn add_admin(token: Token, id: i32, request: Json<AddAdminRequest>) -> Json<JsonValue> {
+pub fn add_admin(token: Token, id: i32, request: Json<AddAdminRequest>) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     let command = response_commands::AddAdminCommand {
         jwt: token.jwt,
@@ -36,7 +37,7 @@ pub fn add_admin(token: Token, id: i32, request: Json<AddAdminRequest>) -> Json<
 }
 
 #[delete("/<id>/admins/<username>")]
-pub fn remove_admin(token: Token, id: i32, username: String) -> Json<JsonValue> {
+pub fn remove_admin(token: Token, id: i32, username: String) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     let command = response_commands::RemoveAdminCommand {
         jwt: token.jwt,
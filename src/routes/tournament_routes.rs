@@ -1,32 +1,34 @@
+use rocket::response::status::Custom;
 use rocket_contrib::json::{Json, JsonValue};
 use serde::Deserialize;
 
+use crate::properties::TournamentStatus;
 use crate::response_commands;
-use crate::response_commands::ResponseCommand;
+use crate::response_commands::{FileContent, FileResponseCommand, ResponseCommand};
 use crate::utils::get_pooled_connection;
 
 use super::Token;
 
 #[get("/")]
-pub fn get_tournaments() -> Json<JsonValue> {
+pub fn get_tournaments() -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::GetAllTournamentsCommand {}.execute(&connection)
 }
 
 #[get("/created_by_me")]
-pub fn get_all_created_tournaments(token: Token) -> Json<JsonValue> {
+pub fn get_all_created_tournaments(token: Token) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::GetAllCreatedTournamentsCommand { jwt: token.jwt }.execute(&connection)
 }
 
 #[get("/managed_by_me")]
-pub fn get_all_managed_tournaments(token: Token) -> Json<JsonValue> {
+pub fn get_all_managed_tournaments(token: Token) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::GetAllManagedTournamentsCommand { jwt: token.jwt }.execute(&connection)
 }
 
 #[get("/<id>")]
-pub fn get_tournament(id: i32) -> Json<JsonValue> {
+pub fn get_tournament(id: i32) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::GetTournamentCommand { id }.execute(&connection)
 }
@@ -44,7 +46,7 @@ pub struct TournamentCreationRequest {
 pub fn create_tournament(
     token: Token,
     request: Json<TournamentCreationRequest>,
-) -> Json<JsonValue> {
+) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::CreateTournamentCommand {
         jwt: token.jwt,
@@ -63,6 +65,17 @@ pub struct TournamentUpdateRequest {
     country: String,
     start_date: String,
     end_date: String,
+    tie_breakers: Option<Vec<String>>,
+    brightwell_constant: Option<f64>,
+    pairing_constraints: Option<Vec<String>>,
+    pairing_constraint_rounds: Option<i32>,
+    round_robin_cycles: Option<i32>,
+    accelerated_rounds: Option<i32>,
+    knockout_best_of: Option<i32>,
+    knockout_third_place_match: Option<bool>,
+    sections: Option<Vec<String>>,
+    team_tie_breakers: Option<Vec<String>>,
+    team_pairing_type: Option<String>,
 }
 
 #[patch("/<id>", data = "<request>")]
@@ -70,7 +83,7 @@ pub fn update_tournament(
     token: Token,
     id: i32,
     request: Json<TournamentUpdateRequest>,
-) -> Json<JsonValue> {
+) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::UpdateTournamentCommand {
         jwt: token.jwt,
@@ -79,18 +92,121 @@ pub fn update_tournament(
         updated_country: request.country.clone(),
         updated_start_date: request.start_date.clone(),
         updated_end_date: request.end_date.clone(),
+        updated_tie_breakers: request.tie_breakers.clone(),
+        updated_brightwell_constant: request.brightwell_constant.clone(),
+        updated_pairing_constraints: request.pairing_constraints.clone(),
+        updated_pairing_constraint_rounds: request.pairing_constraint_rounds.clone(),
+        updated_round_robin_cycles: request.round_robin_cycles.clone(),
+        updated_accelerated_rounds: request.accelerated_rounds.clone(),
+        updated_knockout_best_of: request.knockout_best_of.clone(),
+        updated_knockout_third_place_match: request.knockout_third_place_match.clone(),
+        updated_sections: request.sections.clone(),
+        updated_team_tie_breakers: request.team_tie_breakers.clone(),
+        updated_team_pairing_type: request.team_pairing_type.clone(),
     }
     .execute(&connection)
 }
 
 #[delete("/<id>")]
-pub fn delete_tournament(token: Token, id: i32) -> Json<JsonValue> {
+pub fn delete_tournament(token: Token, id: i32) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::DeleteTournamentCommand { jwt: token.jwt, id }.execute(&connection)
 }
 
+#[post("/<id>/open_registration")]
+pub fn open_tournament_registration(token: Token, id: i32) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    response_commands::UpdateTournamentStatusCommand {
+        jwt: token.jwt,
+        id,
+        updated_status: TournamentStatus::RegistrationOpen,
+    }
+    .execute(&connection)
+}
+
+#[post("/<id>/start")]
+pub fn start_tournament(token: Token, id: i32) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    response_commands::UpdateTournamentStatusCommand {
+        jwt: token.jwt,
+        id,
+        updated_status: TournamentStatus::InProgress,
+    }
+    .execute(&connection)
+}
+
+#[post("/<id>/finish")]
+pub fn finish_tournament(token: Token, id: i32) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    response_commands::UpdateTournamentStatusCommand {
+        jwt: token.jwt,
+        id,
+        updated_status: TournamentStatus::Finished,
+    }
+    .execute(&connection)
+}
+
+#[post("/<id>/archive")]
+pub fn archive_tournament(token: Token, id: i32) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    response_commands::UpdateTournamentStatusCommand {
+        jwt: token.jwt,
+        id,
+        updated_status: TournamentStatus::Archived,
+    }
+    .execute(&connection)
+}
+
 #[get("/<id>/summary")]
-pub fn get_tournament_summary(id: i32) -> Json<JsonValue> {
+pub fn get_tournament_summary(id: i32) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::GetTournamentSummaryCommand { id }.execute(&connection)
 }
+
+#[get("/<id>/rating_changes")]
+pub fn get_tournament_rating_changes(id: i32) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    response_commands::GetTournamentRatingChangesCommand { tournament_id: id }.execute(&connection)
+}
+
+#[get("/<id>/wof_results")]
+pub fn export_wof_results(id: i32) -> Result<FileContent, Custom<Json<JsonValue>>> {
+    let connection = get_pooled_connection();
+    response_commands::ExportWofResultsCommand { tournament_id: id }.execute(&connection)
+}
+
+#[get("/<id>/crosstable?<section>")]
+pub fn get_crosstable(id: i32, section: Option<String>) -> Custom<Json<JsonValue>> {
+    let connection = get_pooled_connection();
+    response_commands::GetCrosstableCommand {
+        tournament_id: id,
+        section,
+    }
+    .execute(&connection)
+}
+
+#[get("/<id>/crosstable/html?<section>")]
+pub fn export_crosstable_html(
+    id: i32,
+    section: Option<String>,
+) -> Result<FileContent, Custom<Json<JsonValue>>> {
+    let connection = get_pooled_connection();
+    response_commands::ExportCrosstableHtmlCommand {
+        tournament_id: id,
+        section,
+    }
+    .execute(&connection)
+}
+
+#[get("/<id>/crosstable/csv?<section>")]
+pub fn export_crosstable_csv(
+    id: i32,
+    section: Option<String>,
+) -> Result<FileContent, Custom<Json<JsonValue>>> {
+    let connection = get_pooled_connection();
+    response_commands::ExportCrosstableCsvCommand {
+        tournament_id: id,
+        section,
+    }
+    .execute(&connection)
+}
@@ -1,3 +1,4 @@
+use rocket::response::status::Custom;
 use rocket_contrib::json::{Json, JsonValue};
 use serde::Deserialize;
 
@@ -8,7 +9,7 @@ use crate::utils::get_pooled_connection;
 use super::Token;
 
 #[get("/<username>")]
-pub fn get_user(username: String) -> Json<JsonValue> {
+pub fn get_user(username: String) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::GetUserCommand { username }.execute(&connection)
 }
@@ -21,7 +22,7 @@ pub struct UserCreationRequest {
 }
 
 #[post("/", data = "<request>")]
-pub fn create_user(token: Token, request: Json<UserCreationRequest>) -> Json<JsonValue> {
+pub fn create_user(token: Token, request: Json<UserCreationRequest>) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::CreateUserCommand {
         jwt: token.jwt,
@@ -43,7 +44,7 @@ pub fn update_user(
     token: Token,
     username: String,
     request: Json<UserUpdateRequest>,
-) -> Json<JsonValue> {
+) -> Custom<Json<JsonValue>> {
     let connection = get_pooled_connection();
     response_commands::UpdateUserCommand {
         jwt: token.jwt,
@@ -1,3 +1,12 @@
+table! {
+    joueurs_snapshots (id) {
+        id -> Int4,
+        source -> Varchar,
+        fetched_at -> Timestamp,
+        players -> Json,
+    }
+}
+
 table! {
     matches (id) {
         id -> Int4,
@@ -33,6 +42,15 @@ table! {
     }
 }
 
+table! {
+    teams (id) {
+        id -> Int4,
+        tournament_id -> Int4,
+        name -> Varchar,
+        meta_data -> Json,
+    }
+}
+
 table! {
     tournaments (id) {
         id -> Int4,
@@ -40,10 +58,11 @@ table! {
         tournament_type -> Varchar,
         country -> Varchar,
         creator -> Varchar,
-        joueurs -> Json,
         meta_data -> Json,
         start_date -> Date,
         end_date -> Date,
+        joueurs_snapshot_id -> Int4,
+        status -> Varchar,
     }
 }
 
@@ -67,14 +86,18 @@ table! {
 joinable!(matches -> rounds (round_id));
 joinable!(players -> tournaments (tournament_id));
 joinable!(rounds -> tournaments (tournament_id));
+joinable!(teams -> tournaments (tournament_id));
+joinable!(tournaments -> joueurs_snapshots (joueurs_snapshot_id));
 joinable!(tournaments -> users (creator));
 joinable!(tournaments_admin -> tournaments (tournament_id));
 joinable!(tournaments_admin -> users (admin_username));
 
 allow_tables_to_appear_in_same_query!(
+    joueurs_snapshots,
     matches,
     players,
     rounds,
+    teams,
     tournaments,
     tournaments_admin,
     users,
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+
+use crate::game_match::IGameMatch;
+use crate::properties::{GameResult, PlayerColor, TieBreaker};
+
+use super::{IResultKeeper, PlayerStanding};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrosstableEntry {
+    pub round_id: i32,
+    pub opponent_id: Option<i32>,
+    pub opponent_rank: Option<usize>,
+    pub color: Option<PlayerColor>,
+    pub disc_count: f64,
+    pub opponent_disc_count: f64,
+    pub result: GameResult,
+    pub is_forfeited: bool,
+    pub running_major_score: f64,
+    pub running_disc_count: f64,
+}
+
+#[derive(Clone)]
+pub struct CrosstableRow {
+    pub rank: usize,
+    pub standing: PlayerStanding,
+    pub entries: Vec<Option<CrosstableEntry>>,
+}
+
+fn get_game_result(game_match: &Box<dyn IGameMatch>, player_id: &i32) -> GameResult {
+    if !game_match.is_finished() {
+        return GameResult::Unfinished;
+    }
+    let opponent_id = match game_match.get_opponent_id(player_id) {
+        Some(opponent_id) => opponent_id,
+        None => return GameResult::Bye,
+    };
+
+    let major_score = game_match.calculate_major_score(player_id);
+    let opponent_major_score = game_match.calculate_major_score(&opponent_id);
+    if major_score > opponent_major_score {
+        GameResult::Win
+    } else if major_score < opponent_major_score || major_score == 0.0 {
+        GameResult::Loss
+    } else {
+        GameResult::Draw
+    }
+}
+
+// Rows follow the final standings, players without any match are placed at the bottom. Every row
+// has one entry per round, in the given round order, left empty for rounds the player did not play.
+pub fn create_crosstable(
+    result_keeper: &Box<dyn IResultKeeper>,
+    player_ids: &Vec<i32>,
+    round_ids: &Vec<i32>,
+) -> Vec<CrosstableRow> {
+    let mut standings = result_keeper.get_detailed_standings();
+    let tie_breakers: Vec<TieBreaker> = match standings.first() {
+        Some(standing) => standing
+            .tie_breaker_scores
+            .iter()
+            .map(|(tie_breaker, _)| tie_breaker.clone())
+            .collect(),
+        None => vec![],
+    };
+    let unmatched_player_ids: Vec<i32> = player_ids
+        .iter()
+        .filter(|player_id| {
+            standings
+                .iter()
+                .all(|standing| &standing.player_id != *player_id)
+        })
+        .cloned()
+        .collect();
+    standings.extend(unmatched_player_ids.into_iter().map(|player_id| {
+        PlayerStanding {
+            player_id,
+            major_score: 0.0,
+            minor_score: 0.0,
+            tie_breaker_scores: tie_breakers
+                .iter()
+                .map(|tie_breaker| (tie_breaker.clone(), 0.0))
+                .collect(),
+            match_history: vec![],
+        }
+    }));
+
+    let ranks_by_player_id: HashMap<i32, usize> = standings
+        .iter()
+        .enumerate()
+        .map(|(index, standing)| (standing.player_id, index + 1))
+        .collect();
+
+    standings
+        .into_iter()
+        .enumerate()
+        .map(|(index, standing)| {
+            let player_id = standing.player_id;
+            let mut running_major_score = 0.0;
+            let mut running_disc_count = 0.0;
+            let entries = round_ids
+                .iter()
+                .map(|round_id| {
+                    let game_match = standing
+                        .match_history
+                        .iter()
+                        .find(|game_match| &game_match.extract_data().round_id == round_id)?;
+                    let opponent_id = game_match.get_opponent_id(&player_id);
+                    let disc_count = game_match.calculate_disc_count(&player_id);
+                    running_major_score += game_match.calculate_major_score(&player_id);
+                    running_disc_count += disc_count;
+                    Some(CrosstableEntry {
+                        round_id: round_id.clone(),
+                        opponent_id,
+                        opponent_rank: opponent_id
+                            .and_then(|opponent_id| ranks_by_player_id.get(&opponent_id))
+                            .cloned(),
+                        color: game_match.get_player_color(&player_id),
+                        disc_count,
+                        opponent_disc_count: opponent_id
+                            .map(|opponent_id| game_match.calculate_disc_count(&opponent_id))
+                            .unwrap_or(0.0),
+                        result: get_game_result(game_match, &player_id),
+                        is_forfeited: game_match.is_forfeited(),
+                        running_major_score,
+                        running_disc_count,
+                    })
+                })
+                .collect();
+            CrosstableRow {
+                rank: index + 1,
+                standing,
+                entries,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_create_crosstable {
+        use serde_json::{Map, Value};
+
+        use crate::game_match::GameMatchCreator;
+        use crate::properties::{GameResult, PlayerColor};
+        use crate::tournament_manager::{create_crosstable, create_result_keeper};
+
+        #[test]
+        fn test_standard() {
+            let game_matches = vec![
+                GameMatchCreator::create_new_finished_match(
+                    &1,
+                    &1,
+                    &2,
+                    &40,
+                    &24,
+                    &Value::from(Map::new()),
+                ),
+                GameMatchCreator::create_new_bye_match(&1, &3, &Value::from(Map::new())),
+                GameMatchCreator::create_new_finished_match(
+                    &2,
+                    &1,
+                    &3,
+                    &30,
+                    &34,
+                    &Value::from(Map::new()),
+                ),
+            ];
+            let result_keeper = create_result_keeper(&game_matches);
+            let crosstable = create_crosstable(&result_keeper, &vec![1, 2, 3], &vec![1, 2]);
+
+            assert_eq!(crosstable.len(), 3);
+            assert_eq!(crosstable[0].rank, 1);
+            assert_eq!(crosstable[0].standing.player_id, 3);
+            let bye_entry = crosstable[0].entries[0].as_ref().unwrap();
+            assert_eq!(bye_entry.result, GameResult::Bye);
+            assert_eq!(bye_entry.opponent_id, None);
+            assert_eq!(bye_entry.opponent_rank, None);
+            assert_eq!(bye_entry.running_major_score, 1.0);
+            let win_entry = crosstable[0].entries[1].as_ref().unwrap();
+            assert_eq!(win_entry.result, GameResult::Win);
+            assert_eq!(win_entry.running_major_score, 2.0);
+
+            assert_eq!(crosstable[1].standing.player_id, 1);
+            let first_entry = crosstable[1].entries[0].as_ref().unwrap();
+            assert_eq!(first_entry.opponent_id, Some(2));
+            assert_eq!(first_entry.opponent_rank, Some(3));
+            assert_eq!(first_entry.color, Some(PlayerColor::Black));
+            assert_eq!(first_entry.disc_count, 40.0);
+            assert_eq!(first_entry.opponent_disc_count, 24.0);
+            assert_eq!(first_entry.result, GameResult::Win);
+            assert_eq!(first_entry.running_major_score, 1.0);
+            assert_eq!(first_entry.running_disc_count, 40.0);
+            let second_entry = crosstable[1].entries[1].as_ref().unwrap();
+            assert_eq!(second_entry.opponent_rank, Some(1));
+            assert_eq!(second_entry.result, GameResult::Loss);
+            assert_eq!(second_entry.running_major_score, 1.0);
+            assert_eq!(second_entry.running_disc_count, 70.0);
+
+            assert_eq!(crosstable[2].standing.player_id, 2);
+            assert_eq!(crosstable[2].entries[1], None);
+        }
+
+        #[test]
+        fn test_player_without_matches() {
+            let game_matches = vec![GameMatchCreator::create_new_finished_match(
+                &1,
+                &1,
+                &2,
+                &40,
+                &24,
+                &Value::from(Map::new()),
+            )];
+            let result_keeper = create_result_keeper(&game_matches);
+            let crosstable = create_crosstable(&result_keeper, &vec![3, 1, 2], &vec![1]);
+
+            assert_eq!(crosstable.len(), 3);
+            assert_eq!(crosstable[2].rank, 3);
+            assert_eq!(crosstable[2].standing.player_id, 3);
+            assert_eq!(crosstable[2].standing.major_score, 0.0);
+            assert_eq!(
+                crosstable[2].standing.tie_breaker_scores.len(),
+                crosstable[0].standing.tie_breaker_scores.len()
+            );
+            assert_eq!(crosstable[2].entries, vec![None]);
+        }
+
+        #[test]
+        fn test_unfinished_and_draw() {
+            let game_matches = vec![
+                GameMatchCreator::create_new_finished_match(
+                    &1,
+                    &1,
+                    &2,
+                    &32,
+                    &32,
+                    &Value::from(Map::new()),
+                ),
+                GameMatchCreator::create_new_match(&2, &2, &1, &Value::from(Map::new())),
+            ];
+            let result_keeper = create_result_keeper(&game_matches);
+            let crosstable = create_crosstable(&result_keeper, &vec![1, 2], &vec![1, 2]);
+
+            let player_1_row = crosstable
+                .iter()
+                .find(|row| row.standing.player_id == 1)
+                .unwrap();
+            let draw_entry = player_1_row.entries[0].as_ref().unwrap();
+            assert_eq!(draw_entry.result, GameResult::Draw);
+            assert_eq!(draw_entry.running_major_score, 0.5);
+            let unfinished_entry = player_1_row.entries[1].as_ref().unwrap();
+            assert_eq!(unfinished_entry.result, GameResult::Unfinished);
+            assert_eq!(unfinished_entry.color, Some(PlayerColor::White));
+            assert_eq!(unfinished_entry.running_major_score, 0.5);
+        }
+    }
+}
@@ -1,5 +1,21 @@
+pub use crosstable::{create_crosstable, CrosstableEntry, CrosstableRow};
 pub use player::Player;
-pub use result_keeper::{create_result_keeper, IResultKeeper, PlayerStanding, ResultKeeper};
+pub use rating_calculator::{
+    get_rating_k_factor, is_unrated_joueurs_id, PlayerRatingChange, RatingCalculator,
+};
+pub use requested_bye::RequestedBye;
+pub use result_keeper::{
+    create_result_keeper, create_result_keeper_with_config, get_default_brightwell_constant,
+    IResultKeeper, PlayerStanding, ResultKeeper, StandingsConfig,
+};
+pub use team_result_keeper::{
+    create_team_result_keeper, get_team_encounters, get_team_standings, TeamEncounter,
+    TeamStanding, AWAY_TEAM_ID_META_KEY, BOARD_META_KEY, HOME_TEAM_ID_META_KEY,
+};
 
+mod crosstable;
 mod player;
+mod rating_calculator;
+mod requested_bye;
 mod result_keeper;
+mod team_result_keeper;
@@ -0,0 +1,214 @@
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::str::FromStr;
+
+use crate::game_match::IGameMatch;
+
+const DEFAULT_RATING_K_FACTOR: f64 = 16.0;
+const UNRATED_JOUEURS_ID_PREFIX: &str = "+";
+
+pub fn get_rating_k_factor() -> f64 {
+    match env::var("RATING_K_FACTOR") {
+        Ok(k_factor) => f64::from_str(&k_factor[..]).unwrap_or(DEFAULT_RATING_K_FACTOR),
+        Err(_) => DEFAULT_RATING_K_FACTOR,
+    }
+}
+
+pub fn is_unrated_joueurs_id(joueurs_id: &String) -> bool {
+    joueurs_id.starts_with(UNRATED_JOUEURS_ID_PREFIX)
+}
+
+pub fn calculate_expected_score(rating: &f64, opponent_rating: &f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((opponent_rating - rating) / 400.0))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PlayerRatingChange {
+    pub player_id: i32,
+    pub is_rated: bool,
+    pub rating: i32,
+    pub games_count: i32,
+    pub expected_score: Option<f64>,
+    pub actual_score: f64,
+    pub projected_rating: Option<i32>,
+}
+
+pub struct RatingCalculator {
+    pub ratings_by_player_id: HashMap<i32, i32>,
+    pub unrated_player_ids: HashSet<i32>,
+    pub k_factor: f64,
+}
+
+impl RatingCalculator {
+    // Only finished games played over the board are rated. Games against unrated players do not
+    // change a rated player's rating, while unrated players are projected at their performance
+    // rating against rated opponents.
+    pub fn calculate_rating_changes(
+        &self,
+        game_matches: &Vec<Box<dyn IGameMatch>>,
+    ) -> Vec<PlayerRatingChange> {
+        let rated_matches: Vec<&Box<dyn IGameMatch>> = game_matches
+            .iter()
+            .filter(|game_match| game_match.is_finished() && !game_match.is_forfeited())
+            .collect();
+
+        let mut player_ids: Vec<&i32> = self.ratings_by_player_id.keys().collect();
+        player_ids.sort();
+        player_ids
+            .into_iter()
+            .map(|player_id| self.calculate_player_rating_change(player_id, &rated_matches))
+            .collect()
+    }
+
+    fn calculate_player_rating_change(
+        &self,
+        player_id: &i32,
+        rated_matches: &Vec<&Box<dyn IGameMatch>>,
+    ) -> PlayerRatingChange {
+        let rating = self.ratings_by_player_id.get(player_id).unwrap().clone();
+        let is_rated = !self.unrated_player_ids.contains(player_id);
+
+        let results: Vec<(f64, f64)> = rated_matches
+            .iter()
+            .filter(|game_match| game_match.is_player_playing(player_id))
+            .filter_map(|game_match| {
+                let opponent_id = game_match.get_opponent_id(player_id)?;
+                if self.unrated_player_ids.contains(&opponent_id) {
+                    return None;
+                }
+                let opponent_rating = self.ratings_by_player_id.get(&opponent_id)?.clone();
+                Some((
+                    opponent_rating as f64,
+                    game_match.calculate_major_score(player_id),
+                ))
+            })
+            .collect();
+        let games_count = results.len() as i32;
+        let actual_score: f64 = results.iter().map(|(_, score)| score).sum();
+
+        if !is_rated {
+            let projected_rating = match games_count {
+                0 => None,
+                _ => {
+                    let average_opponent_rating: f64 = results
+                        .iter()
+                        .map(|(opponent_rating, _)| opponent_rating)
+                        .sum::<f64>()
+                        / games_count as f64;
+                    let losses = games_count as f64 - actual_score;
+                    Some(
+                        (average_opponent_rating
+                            + 400.0 * (actual_score - losses) / games_count as f64)
+                            .round() as i32,
+                    )
+                }
+            };
+            return PlayerRatingChange {
+                player_id: player_id.clone(),
+                is_rated,
+                rating,
+                games_count,
+                expected_score: None,
+                actual_score,
+                projected_rating,
+            };
+        }
+
+        let expected_score: f64 = results
+            .iter()
+            .map(|(opponent_rating, _)| calculate_expected_score(&(rating as f64), opponent_rating))
+            .sum();
+        let projected_rating =
+            (rating as f64 + self.k_factor * (actual_score - expected_score)).round() as i32;
+        PlayerRatingChange {
+            player_id: player_id.clone(),
+            is_rated,
+            rating,
+            games_count,
+            expected_score: Some(expected_score),
+            actual_score,
+            projected_rating: Some(projected_rating),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_rating_calculator {
+        use std::collections::{HashMap, HashSet};
+
+        use serde_json::{Map, Value};
+
+        use crate::game_match::{GameMatchCreator, IGameMatch};
+        use crate::tournament_manager::rating_calculator::{
+            calculate_expected_score, is_unrated_joueurs_id, RatingCalculator,
+        };
+
+        fn create_rating_calculator(
+            ratings: Vec<(i32, i32)>,
+            unrated_player_ids: Vec<i32>,
+        ) -> RatingCalculator {
+            RatingCalculator {
+                ratings_by_player_id: ratings.into_iter().collect::<HashMap<i32, i32>>(),
+                unrated_player_ids: unrated_player_ids.into_iter().collect::<HashSet<i32>>(),
+                k_factor: 16.0,
+            }
+        }
+
+        #[test]
+        fn test_expected_score() {
+            assert_eq!(calculate_expected_score(&1500.0, &1500.0), 0.5);
+            let expected_score = calculate_expected_score(&1900.0, &1500.0);
+            assert_eq!((expected_score * 1000.0).round(), 909.0);
+        }
+
+        #[test]
+        fn test_unrated_joueurs_id() {
+            assert_eq!(is_unrated_joueurs_id(&String::from("+abcd")), true);
+            assert_eq!(is_unrated_joueurs_id(&String::from("280016")), false);
+        }
+
+        #[test]
+        fn test_rated_players() {
+            let rating_calculator = create_rating_calculator(vec![(1, 1500), (2, 1500)], vec![]);
+            let meta_data = Value::from(Map::new());
+            let game_matches: Vec<Box<dyn IGameMatch>> = vec![
+                GameMatchCreator::create_new_finished_match(&1, &1, &2, &40, &24, &meta_data),
+                GameMatchCreator::create_new_finished_match(&2, &2, &1, &32, &32, &meta_data),
+                GameMatchCreator::create_new_forfeit_match(&3, &1, &2, &2, &meta_data),
+            ];
+
+            let rating_changes = rating_calculator.calculate_rating_changes(&game_matches);
+            assert_eq!(rating_changes.len(), 2);
+            assert_eq!(rating_changes[0].player_id, 1);
+            assert_eq!(rating_changes[0].games_count, 2);
+            assert_eq!(rating_changes[0].expected_score, Some(1.0));
+            assert_eq!(rating_changes[0].actual_score, 1.5);
+            assert_eq!(rating_changes[0].projected_rating, Some(1508));
+            assert_eq!(rating_changes[1].projected_rating, Some(1492));
+        }
+
+        #[test]
+        fn test_unrated_player() {
+            let rating_calculator =
+                create_rating_calculator(vec![(1, 1600), (2, 1400), (3, 1200)], vec![3]);
+            let meta_data = Value::from(Map::new());
+            let game_matches: Vec<Box<dyn IGameMatch>> = vec![
+                GameMatchCreator::create_new_finished_match(&1, &3, &1, &40, &24, &meta_data),
+                GameMatchCreator::create_new_finished_match(&2, &2, &3, &40, &24, &meta_data),
+                GameMatchCreator::create_new_finished_match(&3, &1, &2, &40, &24, &meta_data),
+            ];
+
+            let rating_changes = rating_calculator.calculate_rating_changes(&game_matches);
+            assert_eq!(rating_changes[0].games_count, 1);
+            assert_eq!(rating_changes[1].games_count, 1);
+
+            let unrated_rating_change = &rating_changes[2];
+            assert_eq!(unrated_rating_change.is_rated, false);
+            assert_eq!(unrated_rating_change.games_count, 2);
+            assert_eq!(unrated_rating_change.expected_score, None);
+            assert_eq!(unrated_rating_change.actual_score, 1.0);
+            assert_eq!(unrated_rating_change.projected_rating, Some(1500));
+        }
+    }
+}
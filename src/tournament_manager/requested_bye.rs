@@ -0,0 +1,114 @@
+use serde_json::{Map, Value};
+
+use crate::game_match::{GameMatchCreator, IGameMatch};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequestedBye {
+    pub round_number: i32,
+    pub major_score: f64,
+    pub disc_count: i32,
+}
+
+impl RequestedBye {
+    pub fn is_valid(&self) -> bool {
+        let is_major_score_valid = [0.0, 0.5, 1.0].contains(&self.major_score);
+        let is_disc_count_valid = self.disc_count >= 0 && self.disc_count <= 64;
+        self.round_number > 0 && is_major_score_valid && is_disc_count_valid
+    }
+
+    pub fn create_bye_match(&self, round_id: &i32, player_id: &i32) -> Box<dyn IGameMatch> {
+        GameMatchCreator::create_new_bye_match_with_score(
+            round_id,
+            player_id,
+            &self.major_score,
+            &(self.disc_count as f64),
+            &Value::from(Map::new()),
+        )
+    }
+
+    pub fn from_serdemap(requested_bye: &Map<String, Value>) -> Option<RequestedBye> {
+        Some(RequestedBye {
+            round_number: requested_bye.get("round_number")?.as_i64()? as i32,
+            major_score: requested_bye.get("major_score")?.as_f64()?,
+            disc_count: requested_bye.get("disc_count")?.as_i64()? as i32,
+        })
+    }
+
+    pub fn to_serdemap(&self) -> Map<String, Value> {
+        let mut requested_bye = Map::new();
+        requested_bye.insert(
+            String::from("round_number"),
+            Value::from(self.round_number.clone()),
+        );
+        requested_bye.insert(
+            String::from("major_score"),
+            Value::from(self.major_score.clone()),
+        );
+        requested_bye.insert(
+            String::from("disc_count"),
+            Value::from(self.disc_count.clone()),
+        );
+        requested_bye
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_requested_bye {
+        use crate::tournament_manager::RequestedBye;
+
+        #[test]
+        fn test_is_valid() {
+            let requested_bye = RequestedBye {
+                round_number: 3,
+                major_score: 0.5,
+                disc_count: 32,
+            };
+            assert_eq!(requested_bye.is_valid(), true);
+
+            let invalid_major_score = RequestedBye {
+                major_score: 0.75,
+                ..requested_bye.clone()
+            };
+            assert_eq!(invalid_major_score.is_valid(), false);
+
+            let invalid_disc_count = RequestedBye {
+                disc_count: 65,
+                ..requested_bye.clone()
+            };
+            assert_eq!(invalid_disc_count.is_valid(), false);
+
+            let invalid_round_number = RequestedBye {
+                round_number: 0,
+                ..requested_bye.clone()
+            };
+            assert_eq!(invalid_round_number.is_valid(), false);
+        }
+
+        #[test]
+        fn test_from_and_to_serdemap() {
+            let requested_bye = RequestedBye {
+                round_number: 2,
+                major_score: 1.0,
+                disc_count: 40,
+            };
+            assert_eq!(
+                RequestedBye::from_serdemap(&requested_bye.to_serdemap()),
+                Some(requested_bye)
+            );
+        }
+
+        #[test]
+        fn test_create_bye_match() {
+            let requested_bye = RequestedBye {
+                round_number: 2,
+                major_score: 0.5,
+                disc_count: 20,
+            };
+            let bye_match = requested_bye.create_bye_match(&1, &7);
+            assert_eq!(bye_match.is_pairing_bye(), false);
+            assert_eq!(bye_match.calculate_major_score(&7), 0.5);
+            assert_eq!(bye_match.calculate_disc_count(&7), 20.0);
+        }
+    }
+}
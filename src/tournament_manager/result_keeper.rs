@@ -4,17 +4,48 @@ use std::env;
 use std::iter::FromIterator;
 use std::str::FromStr;
 
+use itertools::Itertools;
+
 use crate::game_match::IGameMatch;
-use crate::properties::PlayerColor;
+use crate::properties::{PlayerColor, TieBreaker};
 
 #[derive(Clone)]
 pub struct PlayerStanding {
     pub player_id: i32,
     pub major_score: f64,
     pub minor_score: f64,
+    pub tie_breaker_scores: Vec<(TieBreaker, f64)>,
     pub match_history: Vec<Box<dyn IGameMatch>>,
 }
 
+pub struct StandingsConfig {
+    pub tie_breakers: Vec<TieBreaker>,
+    pub brightwell_constant: f64,
+    pub ratings_by_player_id: HashMap<i32, i32>,
+}
+
+impl StandingsConfig {
+    pub fn default() -> StandingsConfig {
+        StandingsConfig {
+            tie_breakers: vec![TieBreaker::Brightwell],
+            brightwell_constant: get_default_brightwell_constant(),
+            ratings_by_player_id: HashMap::new(),
+        }
+    }
+}
+
+// Following https://www.worldothello.org/about/world-othello-championship/woc-rules
+const DEFAULT_BRIGHTWELL_CONSTANT: f64 = 6.0;
+
+pub fn get_default_brightwell_constant() -> f64 {
+    match env::var("BRIGHTWELL_CONSTANT") {
+        Ok(brightwell_constant) => {
+            f64::from_str(&brightwell_constant[..]).unwrap_or(DEFAULT_BRIGHTWELL_CONSTANT)
+        }
+        Err(_) => DEFAULT_BRIGHTWELL_CONSTANT,
+    }
+}
+
 pub trait IResultKeeper {
     fn has_player_bye(&self, player_id: &i32) -> bool;
     fn has_players_met(&self, player_1_id: &i32, player_2_id: &i32) -> bool;
@@ -22,6 +53,7 @@ pub trait IResultKeeper {
     fn get_detailed_standings(&self) -> Vec<PlayerStanding>;
     fn is_empty(&self) -> bool;
     fn get_color_count(&self, player_id: &i32, color: PlayerColor) -> i32;
+    fn get_color_history(&self, player_id: &i32) -> Vec<PlayerColor>;
 }
 
 pub struct ResultKeeper {
@@ -29,6 +61,7 @@ pub struct ResultKeeper {
     opponents_ids_by_player_id: HashMap<i32, HashSet<i32>>,
     black_color_count_by_player_id: HashMap<i32, i32>,
     white_color_count_by_player_id: HashMap<i32, i32>,
+    color_history_by_player_id: HashMap<i32, Vec<PlayerColor>>,
 }
 
 impl ResultKeeper {}
@@ -49,8 +82,7 @@ impl IResultKeeper for ResultKeeper {
             .match_history
             .iter()
             .find(|game_match| {
-                game_match.is_player_playing(player_id)
-                    && game_match.get_opponent_id(player_id).is_none()
+                game_match.is_player_playing(player_id) && game_match.is_pairing_bye()
             })
             .is_some()
     }
@@ -90,9 +122,23 @@ impl IResultKeeper for ResultKeeper {
         }
         .clone()
     }
+
+    fn get_color_history(&self, player_id: &i32) -> Vec<PlayerColor> {
+        self.color_history_by_player_id
+            .get(player_id)
+            .cloned()
+            .unwrap_or(Vec::new())
+    }
 }
 
 pub fn create_result_keeper(matches: &Vec<Box<dyn IGameMatch>>) -> Box<dyn IResultKeeper> {
+    create_result_keeper_with_config(matches, &StandingsConfig::default())
+}
+
+pub fn create_result_keeper_with_config(
+    matches: &Vec<Box<dyn IGameMatch>>,
+    config: &StandingsConfig,
+) -> Box<dyn IResultKeeper> {
     let mut player_ids: HashSet<i32> = HashSet::new();
 
     matches[..].iter().for_each(|game_match| {
@@ -105,19 +151,21 @@ pub fn create_result_keeper(matches: &Vec<Box<dyn IGameMatch>>) -> Box<dyn IResu
         }
     });
 
-    let sorted_player_standings = get_sorted_player_standings(&player_ids, &matches);
+    let sorted_player_standings = get_sorted_player_standings(&player_ids, &matches, config);
     let opponents_ids_by_player_id = get_opponent_ids_by_player_id(&player_ids, &matches);
 
     let black_color_count_by_player_id =
         get_color_count_by_player_id(&player_ids, &matches, PlayerColor::Black);
     let white_color_count_by_player_id =
         get_color_count_by_player_id(&player_ids, &matches, PlayerColor::White);
+    let color_history_by_player_id = get_color_history_by_player_id(&player_ids, &matches);
 
     Box::from(ResultKeeper {
         sorted_player_standings,
         opponents_ids_by_player_id,
         black_color_count_by_player_id,
         white_color_count_by_player_id,
+        color_history_by_player_id,
     })
 }
 
@@ -129,6 +177,7 @@ fn get_opponent_ids_by_player_id(
         let opponent_ids = HashSet::from_iter(
             matches[..]
                 .iter()
+                .filter(|game_match| !game_match.is_forfeited())
                 .map(|game_match| game_match.get_opponent_id(id))
                 .filter(|result| result.is_some())
                 .map(|result| result.unwrap()),
@@ -140,6 +189,7 @@ fn get_opponent_ids_by_player_id(
 fn get_sorted_player_standings<'a>(
     player_ids: &HashSet<i32>,
     matches: &Vec<Box<dyn IGameMatch>>,
+    config: &StandingsConfig,
 ) -> Vec<PlayerStanding> {
     let major_scores_by_id = get_major_scores_by_player_id(player_ids, matches);
     let mut standings: Vec<PlayerStanding> = player_ids
@@ -151,23 +201,49 @@ fn get_sorted_player_standings<'a>(
                 .map(|game_match| game_match.clone())
                 .collect();
             let major_score = major_scores_by_id.get(id).unwrap_or(&0.0);
-            let minor_score = calculate_minor_score(id, &filtered_matches, &major_scores_by_id);
+            let minor_score = calculate_minor_score(
+                id,
+                &filtered_matches,
+                &major_scores_by_id,
+                &config.brightwell_constant,
+            );
+            let tie_breaker_scores = config
+                .tie_breakers
+                .iter()
+                .map(|tie_breaker| {
+                    let score = match tie_breaker {
+                        TieBreaker::Brightwell => minor_score,
+                        _ => calculate_tie_breaker_score(
+                            tie_breaker,
+                            id,
+                            &filtered_matches,
+                            &major_scores_by_id,
+                            &config.ratings_by_player_id,
+                        ),
+                    };
+                    (tie_breaker.clone(), score)
+                })
+                .collect();
             PlayerStanding {
                 player_id: id.clone(),
                 major_score: major_score.clone(),
                 minor_score,
+                tie_breaker_scores,
                 match_history: filtered_matches,
             }
         })
         .collect();
     standings.sort_by(|a, b| {
-        if a.major_score == b.major_score {
-            if a.minor_score == b.minor_score {
-                return a.player_id.partial_cmp(&b.player_id).unwrap_or(Equal);
+        if a.major_score != b.major_score {
+            return b.major_score.partial_cmp(&a.major_score).unwrap_or(Equal);
+        }
+        let tie_breaker_scores = a.tie_breaker_scores.iter().zip(b.tie_breaker_scores.iter());
+        for ((_, a_score), (_, b_score)) in tie_breaker_scores {
+            if a_score != b_score {
+                return b_score.partial_cmp(a_score).unwrap_or(Equal);
             }
-            return b.minor_score.partial_cmp(&a.minor_score).unwrap_or(Equal);
         }
-        b.major_score.partial_cmp(&a.major_score).unwrap_or(Equal)
+        a.player_id.partial_cmp(&b.player_id).unwrap_or(Equal)
     });
     standings
 }
@@ -194,21 +270,97 @@ fn calculate_minor_score(
     player_id: &i32,
     matches: &Vec<Box<dyn IGameMatch>>,
     major_scores_by_player_ids: &HashMap<i32, f64>,
+    brightwell_constant: &f64,
 ) -> f64 {
-    // Following https://www.worldothello.org/about/world-othello-championship/woc-rules
-    let brightwell_constant = f64::from_str(&env::var("BRIGHTWELL_CONSTANT").unwrap()[..]).unwrap();
     matches
         .iter()
         .map(|game_match| {
             game_match.calculate_minor_score(
                 player_id,
                 major_scores_by_player_ids,
-                &brightwell_constant,
+                brightwell_constant,
             )
         })
         .sum()
 }
 
+fn calculate_tie_breaker_score(
+    tie_breaker: &TieBreaker,
+    player_id: &i32,
+    matches: &Vec<Box<dyn IGameMatch>>,
+    major_scores_by_player_ids: &HashMap<i32, f64>,
+    ratings_by_player_id: &HashMap<i32, i32>,
+) -> f64 {
+    let finished_matches = matches.iter().filter(|game_match| game_match.is_finished());
+    let played_matches = finished_matches
+        .clone()
+        .filter(|game_match| !game_match.is_forfeited());
+    let opponents_major_scores: Vec<f64> = played_matches
+        .clone()
+        .filter_map(|game_match| game_match.get_opponent_id(player_id))
+        .map(|opponent_id| {
+            major_scores_by_player_ids
+                .get(&opponent_id)
+                .unwrap_or(&0.0)
+                .clone()
+        })
+        .collect();
+
+    match tie_breaker {
+        TieBreaker::DiscCount => finished_matches
+            .map(|game_match| game_match.calculate_disc_count(player_id))
+            .sum(),
+        TieBreaker::DiscDifferential => finished_matches
+            .map(|game_match| game_match.calculate_disc_differential(player_id))
+            .sum(),
+        TieBreaker::Buchholz => opponents_major_scores.iter().sum(),
+        TieBreaker::MedianBuchholz => {
+            let buchholz: f64 = opponents_major_scores.iter().sum();
+            if opponents_major_scores.len() <= 2 {
+                return buchholz;
+            }
+            let highest = opponents_major_scores
+                .iter()
+                .cloned()
+                .fold(f64::MIN, f64::max);
+            let lowest = opponents_major_scores
+                .iter()
+                .cloned()
+                .fold(f64::MAX, f64::min);
+            buchholz - highest - lowest
+        }
+        TieBreaker::SonnebornBerger => played_matches
+            .filter_map(|game_match| {
+                let opponent_id = game_match.get_opponent_id(player_id)?;
+                let opponent_major_score =
+                    major_scores_by_player_ids.get(&opponent_id).unwrap_or(&0.0);
+                Some(game_match.calculate_major_score(player_id) * opponent_major_score)
+            })
+            .sum(),
+        TieBreaker::HeadToHead => {
+            // Points scored against the other players sharing the same major score.
+            let major_score = major_scores_by_player_ids.get(player_id).unwrap_or(&0.0);
+            played_matches
+                .filter(|game_match| match game_match.get_opponent_id(player_id) {
+                    Some(opponent_id) => {
+                        major_scores_by_player_ids.get(&opponent_id) == Some(major_score)
+                    }
+                    None => false,
+                })
+                .map(|game_match| game_match.calculate_major_score(player_id))
+                .sum()
+        }
+        TieBreaker::Wins => played_matches
+            .filter(|game_match| {
+                game_match.get_opponent_id(player_id).is_some()
+                    && game_match.calculate_major_score(player_id) == 1.0
+            })
+            .count() as f64,
+        TieBreaker::Rating => f64::from(ratings_by_player_id.get(player_id).unwrap_or(&0).clone()),
+        _ => 0.0,
+    }
+}
+
 fn get_color_count_by_player_id(
     player_ids: &HashSet<i32>,
     matches: &Vec<Box<dyn IGameMatch>>,
@@ -219,6 +371,7 @@ fn get_color_count_by_player_id(
             id.clone(),
             matches
                 .iter()
+                .filter(|game_match| !game_match.is_forfeited())
                 .map(|game_match| game_match.get_player_color(id))
                 .filter(|result| result.is_some() && result.as_ref().unwrap() == &color)
                 .count() as i32,
@@ -226,16 +379,40 @@ fn get_color_count_by_player_id(
     }))
 }
 
+// Rounds are created in order, so sorting by round id gives the order the colours were played in.
+fn get_color_history_by_player_id(
+    player_ids: &HashSet<i32>,
+    matches: &Vec<Box<dyn IGameMatch>>,
+) -> HashMap<i32, Vec<PlayerColor>> {
+    let sorted_matches: Vec<&Box<dyn IGameMatch>> = matches
+        .iter()
+        .filter(|game_match| !game_match.is_forfeited())
+        .sorted_by_key(|game_match| game_match.extract_data().round_id)
+        .collect();
+    HashMap::from_iter(player_ids.iter().map(|id| {
+        (
+            id.clone(),
+            sorted_matches
+                .iter()
+                .filter_map(|game_match| game_match.get_player_color(id))
+                .collect(),
+        )
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     mod test_get_standings {
-        use std::env;
-        use std::str::FromStr;
+        use std::collections::HashMap;
 
         use serde_json::{Map, Value};
 
         use crate::game_match::GameMatchCreator;
-        use crate::tournament_manager::create_result_keeper;
+        use crate::properties::TieBreaker;
+        use crate::tournament_manager::{
+            create_result_keeper, create_result_keeper_with_config,
+            get_default_brightwell_constant, StandingsConfig,
+        };
 
         #[test]
         fn test_standard() {
@@ -260,8 +437,7 @@ mod tests {
             ];
             let result_keeper = create_result_keeper(&game_matches);
             let standings = result_keeper.get_detailed_standings();
-            let brightwell_constant =
-                f64::from_str(&env::var("BRIGHTWELL_CONSTANT").unwrap()[..]).unwrap();
+            let brightwell_constant = get_default_brightwell_constant();
 
             assert_eq!(standings[0].player_id, 3);
             assert_eq!(standings[0].major_score, 2.0);
@@ -298,6 +474,235 @@ mod tests {
                 game_matches[0].extract_data()
             );
         }
+
+        #[test]
+        fn test_tournament_brightwell_constant() {
+            let match_data = vec![
+                (1, 1, 5, 33, 31),
+                (1, 2, 6, 50, 14),
+                (1, 3, 4, 40, 24),
+                (2, 1, 3, 31, 33),
+                (2, 2, 4, 30, 34),
+                (2, 5, 6, 40, 24),
+            ];
+            let game_matches = match_data
+                .iter()
+                .map(|(round_id, black_id, white_id, black_score, white_score)| {
+                    GameMatchCreator::create_new_finished_match(
+                        round_id,
+                        black_id,
+                        white_id,
+                        black_score,
+                        white_score,
+                        &Value::from(Map::new()),
+                    )
+                })
+                .collect();
+
+            let low_constant_config = StandingsConfig {
+                tie_breakers: vec![TieBreaker::Brightwell],
+                brightwell_constant: 1.0,
+                ratings_by_player_id: HashMap::new(),
+            };
+            let result_keeper =
+                create_result_keeper_with_config(&game_matches, &low_constant_config);
+            let standings = result_keeper.get_detailed_standings();
+            assert_eq!(result_keeper.get_standings(), vec![3, 2, 5, 1, 4, 6]);
+            assert_eq!(standings[3].minor_score, 64.0 + 1.0 * 3.0);
+
+            let high_constant_config = StandingsConfig {
+                tie_breakers: vec![TieBreaker::Brightwell],
+                brightwell_constant: 10.0,
+                ratings_by_player_id: HashMap::new(),
+            };
+            let result_keeper =
+                create_result_keeper_with_config(&game_matches, &high_constant_config);
+            let standings = result_keeper.get_detailed_standings();
+            assert_eq!(result_keeper.get_standings(), vec![3, 1, 2, 4, 5, 6]);
+            assert_eq!(standings[1].minor_score, 64.0 + 10.0 * 3.0);
+        }
+    }
+
+    mod test_tie_breakers {
+        use std::collections::HashMap;
+
+        use serde_json::{Map, Value};
+
+        use crate::game_match::{GameMatchCreator, IGameMatch};
+        use crate::properties::TieBreaker;
+        use crate::tournament_manager::{create_result_keeper_with_config, StandingsConfig};
+
+        fn create_game_matches() -> Vec<Box<dyn IGameMatch>> {
+            vec![
+                GameMatchCreator::create_new_finished_match(
+                    &1,
+                    &1,
+                    &2,
+                    &40,
+                    &24,
+                    &Value::from(Map::new()),
+                ),
+                GameMatchCreator::create_new_finished_match(
+                    &1,
+                    &3,
+                    &4,
+                    &33,
+                    &31,
+                    &Value::from(Map::new()),
+                ),
+                GameMatchCreator::create_new_finished_match(
+                    &2,
+                    &1,
+                    &3,
+                    &20,
+                    &44,
+                    &Value::from(Map::new()),
+                ),
+                GameMatchCreator::create_new_finished_match(
+                    &2,
+                    &2,
+                    &4,
+                    &50,
+                    &14,
+                    &Value::from(Map::new()),
+                ),
+            ]
+        }
+
+        #[test]
+        fn test_tie_breaker_scores() {
+            let config = StandingsConfig {
+                tie_breakers: vec![
+                    TieBreaker::Buchholz,
+                    TieBreaker::MedianBuchholz,
+                    TieBreaker::DiscCount,
+                    TieBreaker::DiscDifferential,
+                    TieBreaker::SonnebornBerger,
+                    TieBreaker::HeadToHead,
+                    TieBreaker::Wins,
+                ],
+                brightwell_constant: 6.0,
+                ratings_by_player_id: HashMap::new(),
+            };
+            let result_keeper = create_result_keeper_with_config(&create_game_matches(), &config);
+            let standings = result_keeper.get_detailed_standings();
+
+            assert_eq!(result_keeper.get_standings(), vec![3, 1, 2, 4]);
+            assert_eq!(
+                standings[1].tie_breaker_scores,
+                vec![
+                    (TieBreaker::Buchholz, 3.0),
+                    (TieBreaker::MedianBuchholz, 3.0),
+                    (TieBreaker::DiscCount, 60.0),
+                    (TieBreaker::DiscDifferential, -8.0),
+                    (TieBreaker::SonnebornBerger, 1.0),
+                    (TieBreaker::HeadToHead, 1.0),
+                    (TieBreaker::Wins, 1.0),
+                ]
+            );
+            assert_eq!(
+                standings[2].tie_breaker_scores,
+                vec![
+                    (TieBreaker::Buchholz, 1.0),
+                    (TieBreaker::MedianBuchholz, 1.0),
+                    (TieBreaker::DiscCount, 74.0),
+                    (TieBreaker::DiscDifferential, 20.0),
+                    (TieBreaker::SonnebornBerger, 0.0),
+                    (TieBreaker::HeadToHead, 0.0),
+                    (TieBreaker::Wins, 1.0),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_tie_breaker_order() {
+            let disc_count_config = StandingsConfig {
+                tie_breakers: vec![TieBreaker::DiscCount, TieBreaker::Buchholz],
+                brightwell_constant: 6.0,
+                ratings_by_player_id: HashMap::new(),
+            };
+            let result_keeper =
+                create_result_keeper_with_config(&create_game_matches(), &disc_count_config);
+            assert_eq!(result_keeper.get_standings(), vec![3, 2, 1, 4]);
+
+            let mut ratings_by_player_id = HashMap::new();
+            ratings_by_player_id.insert(1, 1500);
+            ratings_by_player_id.insert(2, 1800);
+            let rating_config = StandingsConfig {
+                tie_breakers: vec![TieBreaker::Rating],
+                brightwell_constant: 6.0,
+                ratings_by_player_id,
+            };
+            let result_keeper =
+                create_result_keeper_with_config(&create_game_matches(), &rating_config);
+            assert_eq!(result_keeper.get_standings(), vec![3, 2, 1, 4]);
+        }
+    }
+
+    mod test_special_results {
+        use serde_json::{Map, Value};
+
+        use crate::game_match::GameMatchCreator;
+        use crate::properties::{MatchResultType, PlayerColor};
+        use crate::tournament_manager::create_result_keeper;
+
+        #[test]
+        fn test_forfeit_results() {
+            let game_matches = vec![
+                GameMatchCreator::create_new_forfeit_match(
+                    &1,
+                    &1,
+                    &2,
+                    &2,
+                    &Value::from(Map::new()),
+                ),
+                GameMatchCreator::create_new_double_forfeit_match(
+                    &1,
+                    &3,
+                    &4,
+                    &Value::from(Map::new()),
+                ),
+            ];
+            let result_keeper = create_result_keeper(&game_matches);
+            let standings = result_keeper.get_detailed_standings();
+
+            assert_eq!(result_keeper.get_standings(), vec![2, 1, 3, 4]);
+            assert_eq!(standings[0].major_score, 1.0);
+            assert_eq!(standings[1].major_score, 0.0);
+            assert_eq!(standings[1].minor_score, 0.0);
+            assert_eq!(standings[2].major_score, 0.0);
+            assert_eq!(standings[3].major_score, 0.0);
+
+            assert_eq!(result_keeper.has_players_met(&1, &2), false);
+            assert_eq!(result_keeper.has_players_met(&3, &4), false);
+            assert_eq!(result_keeper.has_player_bye(&2), false);
+            assert_eq!(result_keeper.get_color_count(&1, PlayerColor::Black), 0);
+        }
+
+        #[test]
+        fn test_adjudicated_result() {
+            let game_match = GameMatchCreator::create_new_match_with_result(
+                &1,
+                &1,
+                &2,
+                &MatchResultType::Adjudicated,
+                &30,
+                &34,
+                &Value::from(Map::new()),
+            )
+            .unwrap();
+            let game_matches = vec![game_match];
+            let result_keeper = create_result_keeper(&game_matches);
+            let standings = result_keeper.get_detailed_standings();
+
+            assert_eq!(result_keeper.get_standings(), vec![2, 1]);
+            assert_eq!(standings[0].major_score, 1.0);
+            assert_eq!(result_keeper.has_players_met(&1, &2), true);
+            assert_eq!(
+                game_matches[0].extract_data().meta_data["adjudicated"],
+                Value::from(true)
+            );
+        }
     }
 
     mod test_has_player_met {
@@ -369,6 +774,33 @@ mod tests {
             assert_eq!(result_keeper.has_player_bye(&2), false);
             assert_eq!(result_keeper.has_player_bye(&3), true);
         }
+
+        #[test]
+        fn test_bye_with_score() {
+            let game_matches = vec![
+                GameMatchCreator::create_new_bye_match_with_score(
+                    &1,
+                    &1,
+                    &0.5,
+                    &32.0,
+                    &Value::from(Map::new()),
+                ),
+                GameMatchCreator::create_new_finished_match(
+                    &2,
+                    &1,
+                    &2,
+                    &40,
+                    &24,
+                    &Value::from(Map::new()),
+                ),
+            ];
+            let result_keeper = create_result_keeper(&game_matches);
+            assert_eq!(result_keeper.has_player_bye(&1), false);
+
+            let standings = result_keeper.get_detailed_standings();
+            assert_eq!(standings[0].player_id, 1);
+            assert_eq!(standings[0].major_score, 1.5);
+        }
     }
 
     mod test_get_color_count {
@@ -409,4 +841,53 @@ mod tests {
             assert_eq!(result_keeper.get_color_count(&3, PlayerColor::White), 1);
         }
     }
+
+    mod test_get_color_history {
+        use serde_json::{Map, Value};
+
+        use crate::game_match::GameMatchCreator;
+        use crate::properties::PlayerColor;
+        use crate::tournament_manager::create_result_keeper;
+
+        #[test]
+        fn test_standard() {
+            let game_matches = vec![
+                GameMatchCreator::create_new_finished_match(
+                    &3,
+                    &2,
+                    &1,
+                    &40,
+                    &24,
+                    &Value::from(Map::new()),
+                ),
+                GameMatchCreator::create_new_forfeit_match(
+                    &2,
+                    &1,
+                    &3,
+                    &1,
+                    &Value::from(Map::new()),
+                ),
+                GameMatchCreator::create_new_finished_match(
+                    &1,
+                    &1,
+                    &2,
+                    &30,
+                    &34,
+                    &Value::from(Map::new()),
+                ),
+            ];
+            let result_keeper = create_result_keeper(&game_matches);
+
+            assert_eq!(
+                result_keeper.get_color_history(&1),
+                vec![PlayerColor::Black, PlayerColor::White]
+            );
+            assert_eq!(
+                result_keeper.get_color_history(&2),
+                vec![PlayerColor::White, PlayerColor::Black]
+            );
+            assert_eq!(result_keeper.get_color_history(&3), vec![]);
+            assert_eq!(result_keeper.get_color_history(&4), vec![]);
+        }
+    }
 }
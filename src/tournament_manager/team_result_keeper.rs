@@ -0,0 +1,511 @@
+use std::cmp::Ordering::Equal;
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+use serde_json::{Map, Value};
+
+use crate::game_match::{GameMatchCreator, IGameMatch};
+use crate::properties::{TeamTieBreaker, TieBreaker};
+
+use super::{create_result_keeper_with_config, IResultKeeper, StandingsConfig};
+
+pub const HOME_TEAM_ID_META_KEY: &str = "home_team_id";
+pub const AWAY_TEAM_ID_META_KEY: &str = "away_team_id";
+pub const BOARD_META_KEY: &str = "board";
+
+const MATCH_POINTS_FOR_WIN: f64 = 2.0;
+const MATCH_POINTS_FOR_DRAW: f64 = 1.0;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TeamEncounter {
+    pub round_id: i32,
+    pub home_team_id: i32,
+    pub away_team_id: Option<i32>,
+    pub home_game_points: f64,
+    pub away_game_points: f64,
+    pub home_disc_count: f64,
+    pub away_disc_count: f64,
+    pub is_finished: bool,
+}
+
+impl TeamEncounter {
+    pub fn is_team_playing(&self, team_id: &i32) -> bool {
+        &self.home_team_id == team_id || self.away_team_id.as_ref() == Some(team_id)
+    }
+
+    pub fn get_opponent_team_id(&self, team_id: &i32) -> Option<i32> {
+        if &self.home_team_id == team_id {
+            return self.away_team_id;
+        }
+        if self.away_team_id.as_ref() == Some(team_id) {
+            return Some(self.home_team_id);
+        }
+        None
+    }
+
+    pub fn calculate_game_points(&self, team_id: &i32) -> f64 {
+        if &self.home_team_id == team_id {
+            return self.home_game_points;
+        }
+        if self.away_team_id.as_ref() == Some(team_id) {
+            return self.away_game_points;
+        }
+        0.0
+    }
+
+    pub fn calculate_disc_count(&self, team_id: &i32) -> f64 {
+        if &self.home_team_id == team_id {
+            return self.home_disc_count;
+        }
+        if self.away_team_id.as_ref() == Some(team_id) {
+            return self.away_disc_count;
+        }
+        0.0
+    }
+
+    pub fn calculate_disc_differential(&self, team_id: &i32) -> f64 {
+        match self.get_opponent_team_id(team_id) {
+            Some(opponent_team_id) => {
+                self.calculate_disc_count(team_id) - self.calculate_disc_count(&opponent_team_id)
+            }
+            None => 0.0,
+        }
+    }
+
+    // A team match is won by the team with more game points, a bye counts as a win.
+    pub fn calculate_match_points(&self, team_id: &i32) -> f64 {
+        if !self.is_finished || !self.is_team_playing(team_id) {
+            return 0.0;
+        }
+        let opponent_team_id = match self.get_opponent_team_id(team_id) {
+            Some(opponent_team_id) => opponent_team_id,
+            None => return MATCH_POINTS_FOR_WIN,
+        };
+        let game_points = self.calculate_game_points(team_id);
+        let opponent_game_points = self.calculate_game_points(&opponent_team_id);
+        if game_points > opponent_game_points {
+            return MATCH_POINTS_FOR_WIN;
+        }
+        if game_points == opponent_game_points {
+            return MATCH_POINTS_FOR_DRAW;
+        }
+        0.0
+    }
+
+    // Team matches are stood in for by a single game between the two teams, so the player
+    // pairing generators can pair teams. Game points are doubled to keep draws as disc counts.
+    fn to_game_match(&self) -> Box<dyn IGameMatch> {
+        let meta_data = Value::from(Map::new());
+        match (self.away_team_id, self.is_finished) {
+            (None, _) => GameMatchCreator::create_new_bye_match(
+                &self.round_id,
+                &self.home_team_id,
+                &meta_data,
+            ),
+            (Some(away_team_id), false) => GameMatchCreator::create_new_match(
+                &self.round_id,
+                &self.home_team_id,
+                &away_team_id,
+                &meta_data,
+            ),
+            (Some(away_team_id), true) => GameMatchCreator::create_new_finished_match(
+                &self.round_id,
+                &self.home_team_id,
+                &away_team_id,
+                &((self.home_game_points * 2.0) as i32),
+                &((self.away_game_points * 2.0) as i32),
+                &meta_data,
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TeamStanding {
+    pub team_id: i32,
+    pub match_points: f64,
+    pub game_points: f64,
+    pub tie_breaker_scores: Vec<(TeamTieBreaker, f64)>,
+}
+
+fn get_meta_i32(game_match: &Box<dyn IGameMatch>, key: &str) -> Option<i32> {
+    game_match
+        .extract_data()
+        .meta_data
+        .get(key)
+        .and_then(|value| value.as_i64())
+        .map(|value| value as i32)
+}
+
+// Board games carry the team match they belong to. The home team plays black on odd boards.
+pub fn get_team_encounters(matches: &Vec<Box<dyn IGameMatch>>) -> Vec<TeamEncounter> {
+    let mut encounters_by_key: HashMap<(i32, i32), TeamEncounter> = HashMap::new();
+    for game_match in matches {
+        let home_team_id = match get_meta_i32(game_match, HOME_TEAM_ID_META_KEY) {
+            Some(home_team_id) => home_team_id,
+            None => continue,
+        };
+        let away_team_id = get_meta_i32(game_match, AWAY_TEAM_ID_META_KEY);
+        let board = get_meta_i32(game_match, BOARD_META_KEY).unwrap_or(1);
+        let round_id = game_match.extract_data().round_id;
+
+        let (black_player_id, white_player_id) = game_match.get_players_id();
+        let (home_player_id, away_player_id) = match (board % 2 == 1, white_player_id) {
+            (_, None) => (black_player_id, None),
+            (true, _) => (black_player_id, white_player_id),
+            (false, _) => (white_player_id, black_player_id),
+        };
+
+        let encounter =
+            encounters_by_key
+                .entry((round_id, home_team_id))
+                .or_insert(TeamEncounter {
+                    round_id,
+                    home_team_id,
+                    away_team_id,
+                    home_game_points: 0.0,
+                    away_game_points: 0.0,
+                    home_disc_count: 0.0,
+                    away_disc_count: 0.0,
+                    is_finished: true,
+                });
+        encounter.is_finished = encounter.is_finished && game_match.is_finished();
+        if let Some(home_player_id) = home_player_id {
+            encounter.home_game_points += game_match.calculate_major_score(&home_player_id);
+            encounter.home_disc_count += game_match.calculate_disc_count(&home_player_id);
+        }
+        if let Some(away_player_id) = away_player_id {
+            encounter.away_game_points += game_match.calculate_major_score(&away_player_id);
+            encounter.away_disc_count += game_match.calculate_disc_count(&away_player_id);
+        }
+    }
+    encounters_by_key
+        .into_iter()
+        .map(|(_, encounter)| encounter)
+        .sorted_by_key(|encounter| (encounter.round_id, encounter.home_team_id))
+        .collect()
+}
+
+pub fn create_team_result_keeper(encounters: &Vec<TeamEncounter>) -> Box<dyn IResultKeeper> {
+    let team_matches: Vec<Box<dyn IGameMatch>> = encounters
+        .iter()
+        .map(|encounter| encounter.to_game_match())
+        .collect();
+    let config = StandingsConfig {
+        tie_breakers: vec![TieBreaker::DiscCount],
+        ..StandingsConfig::default()
+    };
+    create_result_keeper_with_config(&team_matches, &config)
+}
+
+pub fn get_team_standings(
+    team_ids: &Vec<i32>,
+    encounters: &Vec<TeamEncounter>,
+    tie_breakers: &Vec<TeamTieBreaker>,
+) -> Vec<TeamStanding> {
+    let match_points_by_team_id: HashMap<i32, f64> = team_ids
+        .iter()
+        .map(|team_id| {
+            let match_points = encounters
+                .iter()
+                .map(|encounter| encounter.calculate_match_points(team_id))
+                .sum();
+            (team_id.clone(), match_points)
+        })
+        .collect();
+
+    let mut standings: Vec<TeamStanding> = team_ids
+        .iter()
+        .unique()
+        .map(|team_id| {
+            let team_encounters: Vec<&TeamEncounter> = encounters
+                .iter()
+                .filter(|encounter| encounter.is_finished && encounter.is_team_playing(team_id))
+                .collect();
+            let tie_breaker_scores = tie_breakers
+                .iter()
+                .map(|tie_breaker| {
+                    let score = calculate_tie_breaker_score(
+                        tie_breaker,
+                        team_id,
+                        &team_encounters,
+                        &match_points_by_team_id,
+                    );
+                    (tie_breaker.clone(), score)
+                })
+                .collect();
+            TeamStanding {
+                team_id: team_id.clone(),
+                match_points: match_points_by_team_id.get(team_id).unwrap_or(&0.0).clone(),
+                game_points: team_encounters
+                    .iter()
+                    .map(|encounter| encounter.calculate_game_points(team_id))
+                    .sum(),
+                tie_breaker_scores,
+            }
+        })
+        .collect();
+    standings.sort_by(|a, b| {
+        if a.match_points != b.match_points {
+            return b.match_points.partial_cmp(&a.match_points).unwrap_or(Equal);
+        }
+        let tie_breaker_scores = a.tie_breaker_scores.iter().zip(b.tie_breaker_scores.iter());
+        for ((_, a_score), (_, b_score)) in tie_breaker_scores {
+            if a_score != b_score {
+                return b_score.partial_cmp(a_score).unwrap_or(Equal);
+            }
+        }
+        a.team_id.partial_cmp(&b.team_id).unwrap_or(Equal)
+    });
+    standings
+}
+
+fn calculate_tie_breaker_score(
+    tie_breaker: &TeamTieBreaker,
+    team_id: &i32,
+    encounters: &Vec<&TeamEncounter>,
+    match_points_by_team_id: &HashMap<i32, f64>,
+) -> f64 {
+    let opponent_team_ids: Vec<i32> = encounters
+        .iter()
+        .filter_map(|encounter| encounter.get_opponent_team_id(team_id))
+        .collect();
+
+    match tie_breaker {
+        TeamTieBreaker::GamePoints => encounters
+            .iter()
+            .map(|encounter| encounter.calculate_game_points(team_id))
+            .sum(),
+        TeamTieBreaker::DiscCount => encounters
+            .iter()
+            .map(|encounter| encounter.calculate_disc_count(team_id))
+            .sum(),
+        TeamTieBreaker::DiscDifferential => encounters
+            .iter()
+            .map(|encounter| encounter.calculate_disc_differential(team_id))
+            .sum(),
+        TeamTieBreaker::Buchholz => opponent_team_ids
+            .iter()
+            .map(|opponent_team_id| {
+                match_points_by_team_id
+                    .get(opponent_team_id)
+                    .unwrap_or(&0.0)
+                    .clone()
+            })
+            .sum(),
+        TeamTieBreaker::HeadToHead => {
+            // Match points scored against the other teams sharing the same match points.
+            let match_points = match_points_by_team_id.get(team_id).unwrap_or(&0.0);
+            let tied_team_ids: HashSet<&i32> = opponent_team_ids
+                .iter()
+                .filter(|opponent_team_id| {
+                    match_points_by_team_id.get(opponent_team_id) == Some(match_points)
+                })
+                .collect();
+            encounters
+                .iter()
+                .filter(|encounter| match encounter.get_opponent_team_id(team_id) {
+                    Some(opponent_team_id) => tied_team_ids.contains(&opponent_team_id),
+                    None => false,
+                })
+                .map(|encounter| encounter.calculate_match_points(team_id))
+                .sum()
+        }
+        TeamTieBreaker::MatchWins => encounters
+            .iter()
+            .filter(|encounter| {
+                encounter.get_opponent_team_id(team_id).is_some()
+                    && encounter.calculate_match_points(team_id) == MATCH_POINTS_FOR_WIN
+            })
+            .count() as f64,
+        _ => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_get_team_encounters {
+        use serde_json::{Map, Value};
+
+        use crate::game_match::GameMatchCreator;
+        use crate::tournament_manager::get_team_encounters;
+
+        fn create_board_meta_data(
+            home_team_id: i32,
+            away_team_id: Option<i32>,
+            board: i32,
+        ) -> Value {
+            let mut meta_data = Map::new();
+            meta_data.insert(String::from("home_team_id"), Value::from(home_team_id));
+            if let Some(away_team_id) = away_team_id {
+                meta_data.insert(String::from("away_team_id"), Value::from(away_team_id));
+            }
+            meta_data.insert(String::from("board"), Value::from(board));
+            Value::from(meta_data)
+        }
+
+        #[test]
+        fn test_board_order() {
+            let meta_data = |board: i32| create_board_meta_data(10, Some(20), board);
+            // Board 1: home player 1 on black wins, board 2: home player 2 on white loses.
+            let game_matches = vec![
+                GameMatchCreator::create_new_finished_match(&1, &1, &3, &40, &24, &meta_data(1)),
+                GameMatchCreator::create_new_finished_match(&1, &4, &2, &50, &14, &meta_data(2)),
+                GameMatchCreator::create_new_finished_match(&1, &5, &6, &32, &32, &meta_data(3)),
+            ];
+
+            let encounters = get_team_encounters(&game_matches);
+            assert_eq!(encounters.len(), 1);
+            assert_eq!(encounters[0].home_team_id, 10);
+            assert_eq!(encounters[0].away_team_id, Some(20));
+            assert_eq!(encounters[0].home_game_points, 1.5);
+            assert_eq!(encounters[0].away_game_points, 1.5);
+            assert_eq!(encounters[0].home_disc_count, 86.0);
+            assert_eq!(encounters[0].away_disc_count, 106.0);
+            assert_eq!(encounters[0].calculate_match_points(&10), 1.0);
+            assert_eq!(encounters[0].calculate_match_points(&20), 1.0);
+        }
+
+        #[test]
+        fn test_unfinished_and_bye() {
+            let game_matches = vec![
+                GameMatchCreator::create_new_match(
+                    &1,
+                    &1,
+                    &2,
+                    &create_board_meta_data(10, Some(20), 1),
+                ),
+                GameMatchCreator::create_new_bye_match(
+                    &1,
+                    &3,
+                    &create_board_meta_data(30, None, 1),
+                ),
+                GameMatchCreator::create_new_bye_match(&1, &4, &Value::from(Map::new())),
+            ];
+
+            let encounters = get_team_encounters(&game_matches);
+            assert_eq!(encounters.len(), 2);
+            assert_eq!(encounters[0].is_finished, false);
+            assert_eq!(encounters[0].calculate_match_points(&10), 0.0);
+            assert_eq!(encounters[1].away_team_id, None);
+            assert_eq!(encounters[1].home_game_points, 1.0);
+            assert_eq!(encounters[1].calculate_match_points(&30), 2.0);
+        }
+    }
+
+    mod test_get_team_standings {
+        use crate::properties::TeamTieBreaker;
+        use crate::tournament_manager::{get_team_standings, TeamEncounter};
+
+        fn create_encounter(
+            round_id: i32,
+            home_team_id: i32,
+            away_team_id: i32,
+            home_game_points: f64,
+            away_game_points: f64,
+        ) -> TeamEncounter {
+            TeamEncounter {
+                round_id,
+                home_team_id,
+                away_team_id: Some(away_team_id),
+                home_game_points,
+                away_game_points,
+                home_disc_count: home_game_points * 32.0,
+                away_disc_count: away_game_points * 32.0,
+                is_finished: true,
+            }
+        }
+
+        #[test]
+        fn test_match_points_before_game_points() {
+            let encounters = vec![
+                create_encounter(1, 1, 2, 2.0, 1.0),
+                create_encounter(1, 3, 4, 3.0, 0.0),
+                create_encounter(2, 1, 3, 1.5, 1.5),
+                create_encounter(2, 4, 2, 0.0, 3.0),
+            ];
+            let standings = get_team_standings(
+                &vec![1, 2, 3, 4],
+                &encounters,
+                &vec![TeamTieBreaker::GamePoints],
+            );
+
+            assert_eq!(standings[0].team_id, 3);
+            assert_eq!(standings[0].match_points, 3.0);
+            assert_eq!(standings[0].game_points, 4.5);
+            assert_eq!(standings[1].team_id, 1);
+            assert_eq!(standings[1].match_points, 3.0);
+            assert_eq!(standings[1].game_points, 3.5);
+            assert_eq!(standings[2].team_id, 2);
+            assert_eq!(standings[2].match_points, 2.0);
+            assert_eq!(standings[3].team_id, 4);
+            assert_eq!(standings[3].match_points, 0.0);
+        }
+
+        #[test]
+        fn test_tie_breaker_order() {
+            let encounters = vec![
+                create_encounter(1, 1, 2, 2.0, 1.0),
+                create_encounter(1, 3, 4, 2.5, 0.5),
+                create_encounter(2, 2, 3, 2.0, 1.0),
+                create_encounter(2, 4, 1, 1.0, 2.0),
+            ];
+            let team_ids = vec![1, 2, 3, 4];
+
+            let standings =
+                get_team_standings(&team_ids, &encounters, &vec![TeamTieBreaker::GamePoints]);
+            assert_eq!(standings[0].team_id, 1);
+            assert_eq!(standings[1].team_id, 3);
+            assert_eq!(standings[1].tie_breaker_scores[0].1, 3.5);
+            assert_eq!(standings[2].team_id, 2);
+            assert_eq!(standings[2].tie_breaker_scores[0].1, 3.0);
+
+            let standings = get_team_standings(
+                &team_ids,
+                &encounters,
+                &vec![TeamTieBreaker::Buchholz, TeamTieBreaker::GamePoints],
+            );
+            assert_eq!(standings[0].team_id, 1);
+            assert_eq!(standings[1].team_id, 2);
+            assert_eq!(standings[1].tie_breaker_scores[0].1, 6.0);
+            assert_eq!(standings[2].team_id, 3);
+            assert_eq!(standings[2].tie_breaker_scores[0].1, 2.0);
+            assert_eq!(standings[3].team_id, 4);
+        }
+    }
+
+    mod test_create_team_result_keeper {
+        use crate::tournament_manager::{create_team_result_keeper, TeamEncounter};
+
+        #[test]
+        fn test_standard() {
+            let encounters = vec![
+                TeamEncounter {
+                    round_id: 1,
+                    home_team_id: 1,
+                    away_team_id: Some(2),
+                    home_game_points: 1.0,
+                    away_game_points: 2.0,
+                    home_disc_count: 80.0,
+                    away_disc_count: 112.0,
+                    is_finished: true,
+                },
+                TeamEncounter {
+                    round_id: 1,
+                    home_team_id: 3,
+                    away_team_id: None,
+                    home_game_points: 3.0,
+                    away_game_points: 0.0,
+                    home_disc_count: 96.0,
+                    away_disc_count: 0.0,
+                    is_finished: true,
+                },
+            ];
+            let result_keeper = create_team_result_keeper(&encounters);
+
+            assert_eq!(result_keeper.has_players_met(&1, &2), true);
+            assert_eq!(result_keeper.has_player_bye(&3), true);
+            assert_eq!(result_keeper.get_standings()[2], 1);
+        }
+    }
+}
@@ -2,8 +2,8 @@ use diesel::PgConnection;
 use serde_json::Map;
 
 use crate::database_models::{
-    MatchDAO, MatchRowModel, PlayerRowModel, RoundDAO, RoundRowModel, TournamentRowModel,
-    UserRowModel,
+    JoueursSnapshotRowModel, MatchDAO, MatchRowModel, PlayerRowModel, RoundDAO, RoundRowModel,
+    TournamentRowModel, UserRowModel,
 };
 use crate::properties::{RoundType, TournamentType, UserRole};
 use crate::tournament_manager::Player;
@@ -28,24 +28,7 @@ pub fn create_mock_tournament_with_creator(
     username: &String,
     connection: &PgConnection,
 ) -> TournamentRowModel {
-    let name = utils::generate_random_string(20);
-    let country = utils::generate_random_string(10);
-    let joueurs: Vec<Player> = Vec::new();
-    let tournament_type = TournamentType::RoundRobin;
-    let date = utils::create_date_format(2020, 1, 1);
-
-    TournamentRowModel::create(
-        &name,
-        &country,
-        &date,
-        &date,
-        &username,
-        joueurs,
-        tournament_type,
-        Map::new(),
-        connection,
-    )
-    .unwrap()
+    create_mock_tournament_with_creator_and_joueurs(username, vec![], connection)
 }
 
 pub fn create_mock_tournament_with_creator_and_joueurs(
@@ -57,6 +40,8 @@ pub fn create_mock_tournament_with_creator_and_joueurs(
     let country = utils::generate_random_string(10);
     let tournament_type = TournamentType::RoundRobin;
     let date = utils::create_date_format(2020, 1, 1);
+    let joueurs_snapshot =
+        JoueursSnapshotRowModel::create(&String::from("upload"), joueurs, connection).unwrap();
 
     TournamentRowModel::create(
         &name,
@@ -64,7 +49,7 @@ pub fn create_mock_tournament_with_creator_and_joueurs(
         &date,
         &date,
         &creator_username,
-        joueurs,
+        &joueurs_snapshot.id,
         tournament_type,
         Map::new(),
         connection,